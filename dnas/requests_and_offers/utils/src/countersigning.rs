@@ -0,0 +1,177 @@
+use hdk::prelude::*;
+use WasmErrorInner::*;
+
+use crate::link_types::{AdministrationLinkTypes, UsersOrganizationsLinkTypes};
+
+/// The name of the integrity zome defining the users and organizations links.
+const USERS_ORGANIZATIONS_INTEGRITY_ZOME: &str = "users_organizations_integrity";
+
+/// The name of the integrity zome defining the administration links.
+const ADMINISTRATION_INTEGRITY_ZOME: &str = "administration_integrity";
+
+/// How long the participants of a countersigning session have to commit the entry.
+pub const COUNTERSIGNING_SESSION_MILLIS: u64 = 30_000;
+
+/// Builds the preflight request of a session in which every agent commits the entry with the
+/// given hash and type on its own source chain.
+pub fn build_create_preflight_request(
+  entry_hash: EntryHash,
+  entry_type: EntryType,
+  agents: Vec<AgentPubKey>,
+) -> ExternResult<PreflightRequest> {
+  PreflightRequest::try_new(
+    entry_hash,
+    agents.into_iter().map(|agent| (agent, vec![])).collect(),
+    vec![],
+    0,
+    false,
+    session_times_from_millis(COUNTERSIGNING_SESSION_MILLIS)?,
+    ActionBase::Create(CreateBase::new(entry_type)),
+    PreflightBytes(vec![]),
+  )
+  .map_err(|e| wasm_error!(Guest(e.to_string())))
+}
+
+/// Locks the current agent's source chain for the session and returns its signed response.
+pub fn accept_preflight_request(request: PreflightRequest) -> ExternResult<PreflightResponse> {
+  match accept_countersigning_preflight_request(request)? {
+    PreflightRequestAcceptance::Accepted(response) => Ok(response),
+    PreflightRequestAcceptance::UnacceptableFutureStart => Err(wasm_error!(Guest(
+      "The countersigning session starts too far in the future".to_string()
+    ))),
+    PreflightRequestAcceptance::UnacceptableAgentNotFound => Err(wasm_error!(Guest(
+      "The agent is not part of the countersigning session".to_string()
+    ))),
+    PreflightRequestAcceptance::Invalid(reason) => Err(wasm_error!(Guest(reason))),
+  }
+}
+
+/// Commits the countersigned entry on the current agent's source chain once every participant
+/// accepted the preflight request.
+pub fn create_countersigned_entry(
+  entry_def_location: EntryDefLocation,
+  entry_bytes: SerializedBytes,
+  responses: Vec<PreflightResponse>,
+) -> ExternResult<ActionHash> {
+  let session_data = CounterSigningSessionData::try_from_responses(responses, vec![])
+    .map_err(|e| wasm_error!(Guest(e.to_string())))?;
+
+  create(CreateInput::new(
+    entry_def_location,
+    EntryVisibility::Public,
+    Entry::CounterSign(Box::new(session_data), AppEntryBytes(entry_bytes)),
+    ChainTopOrdering::Strict,
+  ))
+}
+
+/// Returns the agents that signed a countersigned entry, or `None` if the entry was not
+/// countersigned.
+pub fn get_countersigning_agents(entry: &Entry) -> Option<Vec<AgentPubKey>> {
+  match entry {
+    Entry::CounterSign(session_data, _) => Some(
+      session_data
+        .preflight_request()
+        .signing_agents
+        .iter()
+        .map(|(agent, _)| agent.clone())
+        .collect(),
+    ),
+    _ => None,
+  }
+}
+
+/// Deserializes an app entry, whether it was countersigned or not.
+pub fn app_entry_from_entry<A>(entry: &Entry) -> ExternResult<Option<A>>
+where
+  A: TryFrom<SerializedBytes, Error = SerializedBytesError>,
+{
+  match entry {
+    Entry::App(bytes) | Entry::CounterSign(_, bytes) => Ok(Some(
+      A::try_from(SerializedBytes::from(bytes.clone())).map_err(|e| wasm_error!(Serialize(e)))?,
+    )),
+    _ => Ok(None),
+  }
+}
+
+/// Deserializes the app entry of a record, whether it was countersigned or not.
+pub fn get_app_entry<A>(record: &Record) -> ExternResult<Option<A>>
+where
+  A: TryFrom<SerializedBytes, Error = SerializedBytesError>,
+{
+  match record.entry().as_option() {
+    Some(entry) => app_entry_from_entry(entry),
+    None => Ok(None),
+  }
+}
+
+/// Returns the agent targeted by a `UserAgents` link of the given user, or `None` if the
+/// action is not such a link created by that agent.
+pub fn get_user_agent_link_target(
+  user: &ActionHash,
+  link_action_hash: ActionHash,
+) -> ExternResult<Option<AgentPubKey>> {
+  let Some(create_link) =
+    get_users_organizations_link(link_action_hash, UsersOrganizationsLinkTypes::UserAgents)?
+  else {
+    return Ok(None);
+  };
+
+  if create_link.base_address != AnyLinkableHash::from(user.clone()) {
+    return Ok(None);
+  }
+
+  Ok(
+    create_link
+      .target_address
+      .into_agent_pub_key()
+      .filter(|agent| *agent == create_link.author),
+  )
+}
+
+/// Returns the link created by the given action if it is a valid link of the given type of the
+/// users_organizations integrity zome.
+pub(crate) fn get_users_organizations_link(
+  link_action_hash: ActionHash,
+  link_type: UsersOrganizationsLinkTypes,
+) -> ExternResult<Option<CreateLink>> {
+  get_integrity_zome_link(
+    link_action_hash,
+    USERS_ORGANIZATIONS_INTEGRITY_ZOME,
+    link_type.into(),
+  )
+}
+
+/// Returns the link created by the given action if it is a valid link of the given type of the
+/// administration integrity zome.
+pub fn get_administration_link(
+  link_action_hash: ActionHash,
+  link_type: AdministrationLinkTypes,
+) -> ExternResult<Option<CreateLink>> {
+  get_integrity_zome_link(
+    link_action_hash,
+    ADMINISTRATION_INTEGRITY_ZOME,
+    link_type.into(),
+  )
+}
+
+fn get_integrity_zome_link(
+  link_action_hash: ActionHash,
+  integrity_zome: &str,
+  link_type: LinkType,
+) -> ExternResult<Option<CreateLink>> {
+  let Action::CreateLink(create_link) = must_get_valid_record(link_action_hash)?.action().clone()
+  else {
+    return Ok(None);
+  };
+
+  let zome_index = dna_info()?
+    .zome_names
+    .iter()
+    .position(|zome_name| zome_name.0 == integrity_zome);
+
+  if zome_index != Some(create_link.zome_index.0 as usize) || create_link.link_type != link_type {
+    return Ok(None);
+  }
+
+  Ok(Some(create_link))
+}
@@ -1,9 +1,15 @@
 use hdk::prelude::*;
 use WasmErrorInner::*;
 
+/// The credit limit used when the DNA properties do not configure one.
+pub const DEFAULT_MUTUAL_CREDIT_LIMIT: u32 = 100;
+
 #[derive(Serialize, Deserialize, SerializedBytes, Debug, Clone)]
 pub struct DnaProperties {
   pub progenitor_pubkey: String,
+  /// How far below zero the mutual credit balance of an agent can go.
+  #[serde(default)]
+  pub mutual_credit_limit: Option<u32>,
 }
 
 impl DnaProperties {
@@ -25,4 +31,12 @@ impl DnaProperties {
       )))
     })
   }
+
+  pub fn get_mutual_credit_limit() -> ExternResult<u32> {
+    Ok(
+      DnaProperties::get()?
+        .mutual_credit_limit
+        .unwrap_or(DEFAULT_MUTUAL_CREDIT_LIMIT),
+    )
+  }
 }
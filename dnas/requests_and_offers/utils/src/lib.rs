@@ -1,5 +1,10 @@
+pub mod countersigning;
 pub mod dna_properties;
 pub mod errors;
+pub mod lifecycle;
+pub mod link_types;
+pub mod on_behalf_of;
+pub mod time_zone;
 pub mod types;
 
 pub use dna_properties::DnaProperties;
@@ -82,6 +87,33 @@ where
   }
 }
 
+pub fn external_remote_call<I, T>(
+  agent: AgentPubKey,
+  fn_name: &str,
+  zome_name: &str,
+  payload: I,
+) -> ExternResult<T>
+where
+  I: Clone + Serialize + Debug,
+  T: Debug + DeserializeOwned,
+{
+  let zome_call_response = call_remote(
+    agent,
+    ZomeName(zome_name.to_owned().into()),
+    FunctionName(fn_name.into()),
+    None,
+    payload.clone(),
+  )?;
+
+  match zome_call_response {
+    ZomeCallResponse::Ok(response) => Ok(response.decode().map_err(|e| wasm_error!(Serialize(e)))?),
+    _ => Err(wasm_error!(Host(format!(
+      "Error while remotely calling the {} function of the {} zome",
+      fn_name, zome_name
+    )))),
+  }
+}
+
 pub fn timetamp_now() -> Timestamp {
   Timestamp::from_micros(chrono::UTC::now().timestamp_subsec_micros() as i64)
 }
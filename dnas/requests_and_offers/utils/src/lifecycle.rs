@@ -0,0 +1,173 @@
+use std::{fmt::Display, str::FromStr};
+
+use hdk::prelude::*;
+
+/// Who is allowed to trigger a given state transition.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TransitionActor {
+  /// Only the author of the original entry.
+  Author,
+  /// Only an agent other than the author of the original entry.
+  Other,
+  /// Any agent allowed to update the entry.
+  Anyone,
+}
+
+/// An allowed move from one state to another.
+#[derive(Debug, Clone, Copy)]
+pub struct Transition<S: 'static> {
+  pub from: S,
+  pub to: S,
+  pub actor: TransitionActor,
+}
+
+/// A declarative lifecycle: the states an entry can be created in and the transitions allowed
+/// between states. Integrity zomes store the state as a string field on their entries and
+/// call these helpers from `validate` to reject illegal moves.
+#[derive(Debug, Clone, Copy)]
+pub struct Lifecycle<S: 'static> {
+  pub initial_states: &'static [S],
+  pub transitions: &'static [Transition<S>],
+}
+
+impl<S> Lifecycle<S>
+where
+  S: PartialEq + Display + FromStr,
+{
+  fn parse_state(state: &str) -> Option<S> {
+    S::from_str(state).ok()
+  }
+
+  /// Checks that a newly created entry starts in one of the initial states.
+  pub fn validate_initial_state(&self, state: &str) -> ExternResult<ValidateCallbackResult> {
+    let Some(state) = Self::parse_state(state) else {
+      return Ok(ValidateCallbackResult::Invalid(format!(
+        "Invalid state: {}",
+        state
+      )));
+    };
+
+    if !self.initial_states.contains(&state) {
+      return Ok(ValidateCallbackResult::Invalid(format!(
+        "An entry cannot be created in the '{}' state",
+        state
+      )));
+    }
+
+    Ok(ValidateCallbackResult::Valid)
+  }
+
+  /// Checks that moving from `from` to `to` is an allowed transition and that the agent
+  /// triggering it may do so. Keeping the same state is always allowed.
+  pub fn validate_transition(
+    &self,
+    from: &str,
+    to: &str,
+    is_author: bool,
+  ) -> ExternResult<ValidateCallbackResult> {
+    let (Some(from), Some(to)) = (Self::parse_state(from), Self::parse_state(to)) else {
+      return Ok(ValidateCallbackResult::Invalid(format!(
+        "Invalid state transition: {} -> {}",
+        from, to
+      )));
+    };
+
+    if from == to {
+      return Ok(ValidateCallbackResult::Valid);
+    }
+
+    let Some(transition) = self
+      .transitions
+      .iter()
+      .find(|transition| transition.from == from && transition.to == to)
+    else {
+      return Ok(ValidateCallbackResult::Invalid(format!(
+        "Cannot go from the '{}' state to the '{}' state",
+        from, to
+      )));
+    };
+
+    if transition.actor == TransitionActor::Author && !is_author {
+      return Ok(ValidateCallbackResult::Invalid(format!(
+        "Only the author can go from the '{}' state to the '{}' state",
+        from, to
+      )));
+    }
+
+    if transition.actor == TransitionActor::Other && is_author {
+      return Ok(ValidateCallbackResult::Invalid(format!(
+        "The author cannot go from the '{}' state to the '{}' state",
+        from, to
+      )));
+    }
+
+    Ok(ValidateCallbackResult::Valid)
+  }
+}
+
+/// The states of a publishable listing such as a request or an offer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ListingState {
+  Open,
+  InProgress,
+  Fulfilled,
+  Closed,
+}
+
+impl Display for ListingState {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Open => write!(f, "open"),
+      Self::InProgress => write!(f, "in progress"),
+      Self::Fulfilled => write!(f, "fulfilled"),
+      Self::Closed => write!(f, "closed"),
+    }
+  }
+}
+
+impl FromStr for ListingState {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "open" => Ok(Self::Open),
+      "in progress" => Ok(Self::InProgress),
+      "fulfilled" => Ok(Self::Fulfilled),
+      "closed" => Ok(Self::Closed),
+      _ => Err(format!("Invalid listing state: {}", s)),
+    }
+  }
+}
+
+/// Open → InProgress → Fulfilled/Closed, with the possibility to reopen an in-progress
+/// listing or to close an open one.
+pub const LISTING_LIFECYCLE: Lifecycle<ListingState> = Lifecycle {
+  initial_states: &[ListingState::Open],
+  transitions: &[
+    Transition {
+      from: ListingState::Open,
+      to: ListingState::InProgress,
+      actor: TransitionActor::Author,
+    },
+    Transition {
+      from: ListingState::Open,
+      to: ListingState::Closed,
+      actor: TransitionActor::Author,
+    },
+    Transition {
+      from: ListingState::InProgress,
+      to: ListingState::Open,
+      actor: TransitionActor::Author,
+    },
+    Transition {
+      from: ListingState::InProgress,
+      to: ListingState::Fulfilled,
+      actor: TransitionActor::Author,
+    },
+    Transition {
+      from: ListingState::InProgress,
+      to: ListingState::Closed,
+      actor: TransitionActor::Author,
+    },
+  ],
+};
@@ -0,0 +1,106 @@
+use hdk::prelude::*;
+
+/// Declares the link types of the users_organizations integrity zome.
+///
+/// The integrity zome declares its `LinkTypes` with this macro and the other zomes use
+/// [`UsersOrganizationsLinkTypes`], so they resolve those link types from the same definition.
+/// Link types are identified by their position: new variants are only appended.
+#[macro_export]
+macro_rules! users_organizations_link_types {
+  ($(#[$meta:meta])* $vis:vis enum $name:ident) => {
+    $(#[$meta])*
+    $vis enum $name {
+      // Users Links
+      UserUpdates,
+      AllUsers,
+      MyUser,
+      UserAgents,
+      UserStatus,
+      UserOrganizations,
+
+      // Organizations Links
+      OrganizationUpdates,
+      AllOrganizations,
+      OrganizationCoordinators,
+      OrganizationMembers,
+      OrganizationStatus,
+
+      // Availability Links
+      UserAvailability,
+      AvailabilityUpdates,
+
+      // Categories Links
+      OrganizationCategories,
+      CategoryOrganizations,
+
+      // Endorsements Links
+      UserEndorsements,
+
+      // Invitations Links
+      OrganizationInvitationUpdates,
+      UserInvitations,
+
+      // Join Requests Links
+      OrganizationJoinRequestUpdates,
+      OrganizationJoinRequests,
+
+      // Roles Links
+      OrganizationRoles,
+      OrganizationRoleUpdates,
+      RoleUsers,
+      UserRoles,
+
+      // Authorization Links
+      OrganizationCoordinatorProofs,
+      OrganizationAuthorityProofs,
+    }
+  };
+}
+
+users_organizations_link_types!(
+  /// The link types of the users_organizations integrity zome, for the zomes that cannot depend
+  /// on it.
+  #[derive(Debug, Clone, Copy, PartialEq)]
+  pub enum UsersOrganizationsLinkTypes
+);
+
+impl From<UsersOrganizationsLinkTypes> for LinkType {
+  fn from(link_type: UsersOrganizationsLinkTypes) -> Self {
+    LinkType(link_type as u8)
+  }
+}
+
+/// Declares the link types of the administration integrity zome.
+///
+/// Works like [`users_organizations_link_types`], with [`AdministrationLinkTypes`] for the other
+/// zomes. New variants are only appended.
+#[macro_export]
+macro_rules! administration_link_types {
+  ($(#[$meta:meta])* $vis:vis enum $name:ident) => {
+    $(#[$meta])*
+    $vis enum $name {
+      AllAdministrators,
+      AgentAdministrators,
+      StatusUpdates,
+      AllStatuses,
+      EntityStatus,
+      AcceptedEntity,
+      ExpiryPath,
+      ExpiringEntity,
+      AllMatchmakers,
+      AgentMatchmakers,
+    }
+  };
+}
+
+administration_link_types!(
+  /// The link types of the administration integrity zome, for the zomes that cannot depend on it.
+  #[derive(Debug, Clone, Copy, PartialEq)]
+  pub enum AdministrationLinkTypes
+);
+
+impl From<AdministrationLinkTypes> for LinkType {
+  fn from(link_type: AdministrationLinkTypes) -> Self {
+    LinkType(link_type as u8)
+  }
+}
@@ -0,0 +1,209 @@
+use hdk::prelude::*;
+use WasmErrorInner::*;
+
+use crate::{
+  countersigning::{get_user_agent_link_target, get_users_organizations_link},
+  errors::UtilsError,
+  external_local_call,
+  link_types::UsersOrganizationsLinkTypes,
+  EntityActionHash,
+};
+
+/// Records that an entry was published by a coordinator acting for an organization.
+///
+/// The link action hashes let integrity zomes check, without querying links, that the author
+/// was a coordinator of the organization when the entry was committed.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct OnBehalfOf {
+  /// The original action hash of the organization.
+  pub organization_original_action_hash: ActionHash,
+  /// The action hash of the `OrganizationCoordinators` link from the organization to the
+  /// author's user.
+  pub coordinator_link: ActionHash,
+  /// The action hash of the `UserAgents` link from the author's user to the author.
+  pub user_agent_link: ActionHash,
+}
+
+/// Builds the provenance of an entry the current agent publishes for an organization, after
+/// checking that the agent is one of its coordinators and that the organization is accepted.
+pub fn build_on_behalf_of(
+  organization_original_action_hash: ActionHash,
+) -> ExternResult<OnBehalfOf> {
+  let is_coordinator: bool = external_local_call(
+    "check_if_agent_is_organization_coordinator",
+    "users_organizations",
+    organization_original_action_hash.clone(),
+  )?;
+  if !is_coordinator {
+    return Err(wasm_error!(Guest(
+      "Only coordinators can act on behalf of an organization".to_string()
+    )));
+  }
+
+  if !is_organization_accepted(&organization_original_action_hash)? {
+    return Err(wasm_error!(Guest(
+      "The organization is not accepted".to_string()
+    )));
+  }
+
+  let my_pubkey = agent_info()?.agent_initial_pubkey;
+  let agent_user_links: Vec<Link> =
+    external_local_call("get_agent_user", "users_organizations", my_pubkey.clone())?;
+  let user_original_action_hash = agent_user_links
+    .first()
+    .and_then(|link| link.target.clone().into_action_hash())
+    .ok_or(UtilsError::ActionHashNotFound("user"))?;
+
+  let coordinators_links: Vec<Link> = external_local_call(
+    "get_organization_coordinators_links",
+    "users_organizations",
+    organization_original_action_hash.clone(),
+  )?;
+  let coordinator_link = coordinators_links
+    .into_iter()
+    .find(|link| link.target.clone().into_action_hash() == Some(user_original_action_hash.clone()))
+    .ok_or(wasm_error!(Guest(
+      "Could not find the coordinator link".to_string()
+    )))?;
+
+  let user_agents_links: Vec<Link> = external_local_call(
+    "get_user_agents_links",
+    "users_organizations",
+    user_original_action_hash,
+  )?;
+  let user_agent_link = user_agents_links
+    .into_iter()
+    .find(|link| link.target.clone().into_agent_pub_key() == Some(my_pubkey.clone()))
+    .ok_or(wasm_error!(Guest(
+      "Could not find the user agent link".to_string()
+    )))?;
+
+  Ok(OnBehalfOf {
+    organization_original_action_hash,
+    coordinator_link: coordinator_link.create_link_hash,
+    user_agent_link: user_agent_link.create_link_hash,
+  })
+}
+
+/// Checks whether the status of an organization is "accepted".
+pub fn is_organization_accepted(
+  organization_original_action_hash: &ActionHash,
+) -> ExternResult<bool> {
+  external_local_call(
+    "check_if_entity_is_accepted",
+    "administration",
+    EntityActionHash {
+      entity_original_action_hash: organization_original_action_hash.clone(),
+      entity: "organizations".to_string(),
+    },
+  )
+}
+
+/// Validates that the author of an entry was linked to the organization through
+/// `OrganizationCoordinators` before the entry was committed, and had not removed that link
+/// since, given the action preceding the entry.
+pub fn validate_on_behalf_of(
+  on_behalf_of: &OnBehalfOf,
+  author: &AgentPubKey,
+  timestamp: Timestamp,
+  prev_action: &ActionHash,
+) -> ExternResult<ValidateCallbackResult> {
+  let coordinator_link = get_users_organizations_link(
+    on_behalf_of.coordinator_link.clone(),
+    UsersOrganizationsLinkTypes::OrganizationCoordinators,
+  )?;
+
+  let coordinator_agent = match coordinator_link
+    .as_ref()
+    .and_then(|link| link.target_address.clone().into_action_hash())
+  {
+    Some(user_original_action_hash) => get_user_agent_link_target(
+      &user_original_action_hash,
+      on_behalf_of.user_agent_link.clone(),
+    )?,
+    None => None,
+  };
+
+  let result = check_on_behalf_of(
+    on_behalf_of,
+    coordinator_link.as_ref(),
+    coordinator_agent.as_ref(),
+    author,
+    timestamp,
+  );
+  if result != ValidateCallbackResult::Valid {
+    return Ok(result);
+  }
+
+  let author_activity = must_get_agent_activity(
+    author.clone(),
+    ChainFilter::new(prev_action.clone()).until(on_behalf_of.coordinator_link.clone()),
+  )?;
+
+  Ok(validate_coordinator_link_is_live(
+    &on_behalf_of.coordinator_link,
+    &author_activity,
+  ))
+}
+
+/// Checks that the author did not delete the coordinator link they cite before publishing for
+/// the organization, as coordinators do when they leave it.
+///
+/// Coordinator links can only be deleted by their author, so the author's chain holds every
+/// delete of the link.
+pub fn validate_coordinator_link_is_live(
+  coordinator_link: &ActionHash,
+  author_activity: &[RegisterAgentActivity],
+) -> ValidateCallbackResult {
+  let is_deleted = author_activity.iter().any(|activity| {
+    matches!(
+      &activity.action.hashed.content,
+      Action::DeleteLink(delete_link) if delete_link.link_add_address == *coordinator_link
+    )
+  });
+  if is_deleted {
+    return ValidateCallbackResult::Invalid(String::from(
+      "The coordinator link was deleted before publishing for the organization",
+    ));
+  }
+
+  ValidateCallbackResult::Valid
+}
+
+/// Checks an `OnBehalfOf` against the coordinator link it cites and the agent targeted by the
+/// cited `UserAgents` link, once both were fetched.
+pub fn check_on_behalf_of(
+  on_behalf_of: &OnBehalfOf,
+  coordinator_link: Option<&CreateLink>,
+  coordinator_agent: Option<&AgentPubKey>,
+  author: &AgentPubKey,
+  timestamp: Timestamp,
+) -> ValidateCallbackResult {
+  let Some(coordinator_link) = coordinator_link else {
+    return ValidateCallbackResult::Invalid(String::from(
+      "The coordinator link must be an OrganizationCoordinators link",
+    ));
+  };
+
+  if coordinator_link.base_address
+    != AnyLinkableHash::from(on_behalf_of.organization_original_action_hash.clone())
+  {
+    return ValidateCallbackResult::Invalid(String::from(
+      "The coordinator link must start from the organization",
+    ));
+  }
+
+  if coordinator_link.timestamp > timestamp {
+    return ValidateCallbackResult::Invalid(String::from(
+      "The author must be a coordinator of the organization when publishing for it",
+    ));
+  }
+
+  if coordinator_agent != Some(author) {
+    return ValidateCallbackResult::Invalid(String::from(
+      "Only a coordinator of the organization can publish for it",
+    ));
+  }
+
+  ValidateCallbackResult::Valid
+}
@@ -0,0 +1,67 @@
+use std::str::FromStr;
+
+use chrono_tz::Tz;
+
+/// Parses a time zone written as an offset from UTC, such as "UTC", "UTC+2", "GMT-05:30" or
+/// "+0100", into a number of minutes.
+pub fn parse_utc_offset(time_zone: &str) -> Option<i64> {
+  let time_zone = time_zone.trim();
+  if time_zone.is_empty() {
+    return None;
+  }
+
+  let offset = time_zone
+    .strip_prefix("UTC")
+    .or_else(|| time_zone.strip_prefix("GMT"))
+    .unwrap_or(time_zone);
+  if offset.is_empty() {
+    return Some(0);
+  }
+
+  let (sign, offset) = if let Some(offset) = offset.strip_prefix('+') {
+    (1, offset)
+  } else if let Some(offset) = offset.strip_prefix('-') {
+    (-1, offset)
+  } else {
+    return None;
+  };
+  if !offset.chars().all(|c| c.is_ascii_digit() || c == ':') {
+    return None;
+  }
+
+  let (hours, minutes) = match offset.split_once(':') {
+    Some((hours, minutes)) => (hours, minutes),
+    None if offset.len() > 2 => offset.split_at(offset.len() - 2),
+    None => (offset, "0"),
+  };
+
+  let hours: i64 = hours.parse().ok()?;
+  let minutes: i64 = minutes.parse().ok()?;
+  if hours > 14 || minutes >= 60 {
+    return None;
+  }
+
+  Some(sign * (hours * 60 + minutes))
+}
+
+/// The time zone of a user's profile: an IANA name such as "Europe/Paris", whose offset
+/// follows daylight saving time, or a fixed offset from UTC such as "UTC+02:00".
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UserTimeZone {
+  Iana(Tz),
+  FixedOffset(i64),
+}
+
+impl FromStr for UserTimeZone {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    if let Some(offset) = parse_utc_offset(s) {
+      return Ok(Self::FixedOffset(offset));
+    }
+
+    Tz::from_str(s.trim())
+      .map(Self::Iana)
+      .map_err(|_| format!("Invalid time zone: {}", s))
+  }
+}
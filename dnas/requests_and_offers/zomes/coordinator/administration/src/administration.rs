@@ -80,6 +80,22 @@ pub fn check_if_agent_is_administrator(input: EntityAgent) -> ExternResult<bool>
   Ok(false)
 }
 
+/// Returns the `AgentAdministrators` link granting the administrator role to an agent, which
+/// category updates cite to prove the role of their author.
+#[hdk_extern]
+pub fn get_agent_administrator_link(input: EntityAgent) -> ExternResult<Option<Link>> {
+  let path_hash = Path::from(format!("{}.administrators", input.entity)).path_entry_hash()?;
+  let agent_administrator_links = get_links(
+    GetLinksInputBuilder::try_new(input.agent_pubkey, LinkTypes::AgentAdministrators)?.build(),
+  )?;
+
+  Ok(
+    agent_administrator_links
+      .into_iter()
+      .find(|link| link.target == path_hash.clone().into()),
+  )
+}
+
 #[hdk_extern]
 pub fn remove_administrator(input: EntityActionHashAgents) -> ExternResult<bool> {
   if !check_if_agent_is_administrator(EntityAgent {
@@ -0,0 +1,210 @@
+use administration_integrity::*;
+use chrono::{Datelike, NaiveDateTime};
+use hdk::prelude::*;
+use status::*;
+use utils::{errors::UtilsError, EntityActionHash, EntityAgent};
+use WasmErrorInner::*;
+
+use crate::{
+  administration::check_if_agent_is_administrator,
+  status::{get_entity_status_link, get_latest_status, update_status, UpdateEntityActionHash},
+};
+
+#[derive(Serialize, Deserialize, SerializedBytes, Debug, Clone)]
+pub struct ExpiryTag {
+  pub entity: String,
+  pub expires_at: Timestamp,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct EntityExpiryInput {
+  pub entity: String,
+  pub entity_original_action_hash: ActionHash,
+  pub expires_at: Timestamp,
+}
+
+/// Returns the (year, month, day) of a timestamp in UTC.
+fn date_of(timestamp: &Timestamp) -> (i32, u32, u32) {
+  let seconds = timestamp.as_micros().div_euclid(1_000_000);
+  let date = NaiveDateTime::from_timestamp(seconds, 0).date();
+  (date.year(), date.month(), date.day())
+}
+
+/// Returns the daily bucket an expiry belongs to, e.g. `expiry.2026.10.18`.
+fn expiry_bucket(timestamp: &Timestamp) -> ExternResult<TypedPath> {
+  let (year, month, day) = date_of(timestamp);
+  Path::from(format!("expiry.{}.{:02}.{:02}", year, month, day)).typed(LinkTypes::ExpiryPath)
+}
+
+/// Reads back the (year, month, day) of a daily bucket path.
+fn bucket_date(bucket: &TypedPath) -> ExternResult<(i32, u32, u32)> {
+  let components = bucket
+    .path
+    .as_ref()
+    .iter()
+    .map(String::try_from)
+    .collect::<Result<Vec<String>, SerializedBytesError>>()
+    .map_err(|e| wasm_error!(Serialize(e)))?;
+
+  let invalid_bucket = || wasm_error!(Guest("Invalid expiry bucket".to_string()));
+  match components.as_slice() {
+    [_, year, month, day] => Ok((
+      year.parse().map_err(|_| invalid_bucket())?,
+      month.parse().map_err(|_| invalid_bucket())?,
+      day.parse().map_err(|_| invalid_bucket())?,
+    )),
+    _ => Err(invalid_bucket()),
+  }
+}
+
+#[hdk_extern]
+pub fn register_entity_expiry(input: EntityExpiryInput) -> ExternResult<bool> {
+  let record = get(
+    input.entity_original_action_hash.clone(),
+    GetOptions::default(),
+  )?
+  .ok_or(wasm_error!(Guest(
+    "Could not find the entity to expire".to_string()
+  )))?;
+
+  let agent_pubkey = agent_info()?.agent_initial_pubkey;
+  if *record.action().author() != agent_pubkey
+    && !check_if_agent_is_administrator(EntityAgent {
+      entity: input.entity.clone(),
+      agent_pubkey,
+    })?
+  {
+    return Err(wasm_error!(Guest(
+      "Only the author of an entity or an administrator can set its expiry".to_string()
+    )));
+  }
+
+  let bucket = expiry_bucket(&input.expires_at)?;
+  bucket.ensure()?;
+
+  let tag = SerializedBytes::try_from(ExpiryTag {
+    entity: input.entity,
+    expires_at: input.expires_at,
+  })
+  .map_err(|e| wasm_error!(Serialize(e)))?;
+
+  create_link(
+    bucket.path_entry_hash()?,
+    input.entity_original_action_hash,
+    LinkTypes::ExpiringEntity,
+    LinkTag::new(tag.bytes().clone()),
+  )?;
+
+  Ok(true)
+}
+
+#[hdk_extern]
+pub fn get_expiring_entities_links(expires_at: Timestamp) -> ExternResult<Vec<Link>> {
+  get_links(
+    GetLinksInputBuilder::try_new(
+      expiry_bucket(&expires_at)?.path_entry_hash()?,
+      LinkTypes::ExpiringEntity,
+    )?
+    .build(),
+  )
+}
+
+/// Walks back the revisions of a Status to find the action that created it.
+fn get_original_status_action_hash(status_action_hash: ActionHash) -> ExternResult<ActionHash> {
+  let mut action_hash = status_action_hash;
+  loop {
+    let record = get(action_hash.clone(), GetOptions::default())?
+      .ok_or(UtilsError::ActionHashNotFound("status"))?;
+    match record.action() {
+      Action::Update(update) => action_hash = update.original_action_address.clone(),
+      _ => return Ok(action_hash),
+    }
+  }
+}
+
+fn expire_entity(input: EntityActionHash) -> ExternResult<bool> {
+  let status_previous_action_hash = get_entity_status_link(input.clone())?
+    .target
+    .into_action_hash()
+    .ok_or(UtilsError::ActionHashNotFound("status"))?;
+
+  // Only accepted entities expire: pending, rejected or suspended ones keep their status
+  let latest_status = get_latest_status(status_previous_action_hash.clone())?;
+  if latest_status.map_or(true, |status| status.status_type != "accepted") {
+    return Ok(false);
+  }
+
+  update_status(UpdateEntityActionHash {
+    entity: input.entity,
+    entity_original_action_hash: input.entity_original_action_hash,
+    status_original_action_hash: get_original_status_action_hash(
+      status_previous_action_hash.clone(),
+    )?,
+    status_previous_action_hash,
+    new_status: Status::expire(),
+  })?;
+
+  Ok(true)
+}
+
+/// Walks every daily bucket up to today, flips the accepted entities whose expiry has passed to
+/// the "expired" status and removes them from their accepted index. Only network
+/// administrators can sweep, as the status updates bypass the checks of `update_entity_status`.
+#[hdk_extern]
+pub fn sweep_expired_entities(_: ()) -> ExternResult<Vec<ActionHash>> {
+  if !check_if_agent_is_administrator(EntityAgent {
+    entity: String::from("network"),
+    agent_pubkey: agent_info()?.agent_initial_pubkey,
+  })? {
+    return Err(wasm_error!(Guest(
+      "Only network administrators can sweep expired entities".to_string()
+    )));
+  }
+
+  let now = sys_time()?;
+  let today = date_of(&now);
+  let mut expired_entities: Vec<ActionHash> = vec![];
+
+  let root = Path::from("expiry").typed(LinkTypes::ExpiryPath)?;
+  for year in root.children_paths()? {
+    for month in year.children_paths()? {
+      for day in month.children_paths()? {
+        if bucket_date(&day)? > today {
+          continue;
+        }
+
+        let links = get_links(
+          GetLinksInputBuilder::try_new(day.path_entry_hash()?, LinkTypes::ExpiringEntity)?.build(),
+        )?;
+
+        for link in links {
+          let tag = ExpiryTag::try_from(SerializedBytes::from(UnsafeBytes::from(
+            link.tag.clone().into_inner(),
+          )))
+          .map_err(|e| wasm_error!(Serialize(e)))?;
+
+          if tag.expires_at > now {
+            continue;
+          }
+
+          let entity_original_action_hash = link
+            .target
+            .clone()
+            .into_action_hash()
+            .ok_or(UtilsError::ActionHashNotFound("entity"))?;
+
+          if expire_entity(EntityActionHash {
+            entity: tag.entity,
+            entity_original_action_hash: entity_original_action_hash.clone(),
+          })? {
+            expired_entities.push(entity_original_action_hash);
+          }
+
+          delete_link(link.create_link_hash)?;
+        }
+      }
+    }
+  }
+
+  Ok(expired_entities)
+}
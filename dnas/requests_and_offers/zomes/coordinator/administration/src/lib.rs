@@ -1,4 +1,6 @@
 pub mod administration;
+pub mod expiry;
+pub mod matchmaker;
 pub mod status;
 
 use hdk::prelude::*;
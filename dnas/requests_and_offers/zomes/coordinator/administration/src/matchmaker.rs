@@ -0,0 +1,127 @@
+use administration_integrity::*;
+use hdk::prelude::*;
+use utils::{EntityActionHash, EntityActionHashAgents, EntityAgent};
+use WasmErrorInner::*;
+
+use crate::administration::check_if_agent_is_administrator;
+
+fn matchmakers_path(entity: &str) -> Path {
+  Path::from(format!("{}.matchmakers", entity))
+}
+
+/// Grants the matchmaker role to a user and its agents. Only administrators can do this.
+#[hdk_extern]
+pub fn add_matchmaker(input: EntityActionHashAgents) -> ExternResult<bool> {
+  if !check_if_agent_is_administrator(EntityAgent {
+    entity: input.entity.clone(),
+    agent_pubkey: agent_info()?.agent_latest_pubkey,
+  })? {
+    return Err(wasm_error!(Guest(
+      "Only administrators can add matchmakers".to_string()
+    )));
+  }
+
+  if check_if_entity_is_matchmaker(EntityActionHash {
+    entity_original_action_hash: input.entity_original_action_hash.clone(),
+    entity: input.entity.clone(),
+  })? {
+    return Err(wasm_error!(Guest("Already a Matchmaker".to_string())));
+  }
+
+  let path = matchmakers_path(&input.entity);
+  create_link(
+    path.path_entry_hash()?,
+    input.entity_original_action_hash.clone(),
+    LinkTypes::AllMatchmakers,
+    (),
+  )?;
+
+  for agent_pubkey in input.agent_pubkeys.clone() {
+    create_link(
+      agent_pubkey.clone(),
+      path.path_entry_hash()?,
+      LinkTypes::AgentMatchmakers,
+      (),
+    )?;
+  }
+
+  Ok(true)
+}
+
+#[hdk_extern]
+pub fn get_all_matchmakers_links(entity: String) -> ExternResult<Vec<Link>> {
+  get_links(
+    GetLinksInputBuilder::try_new(
+      matchmakers_path(&entity).path_entry_hash()?,
+      LinkTypes::AllMatchmakers,
+    )?
+    .build(),
+  )
+}
+
+#[hdk_extern]
+pub fn check_if_entity_is_matchmaker(input: EntityActionHash) -> ExternResult<bool> {
+  let links = get_all_matchmakers_links(input.entity)?;
+  Ok(
+    links
+      .iter()
+      .any(|link| link.target == input.entity_original_action_hash.clone().into()),
+  )
+}
+
+/// Returns the `AgentMatchmakers` link granting the matchmaker role to an agent, which
+/// introductions cite to prove the role of their author.
+#[hdk_extern]
+pub fn get_agent_matchmaker_link(input: EntityAgent) -> ExternResult<Option<Link>> {
+  let path_hash = matchmakers_path(&input.entity).path_entry_hash()?;
+  let agent_matchmaker_links = get_links(
+    GetLinksInputBuilder::try_new(input.agent_pubkey, LinkTypes::AgentMatchmakers)?.build(),
+  )?;
+
+  Ok(
+    agent_matchmaker_links
+      .into_iter()
+      .find(|link| link.target == path_hash.clone().into()),
+  )
+}
+
+#[hdk_extern]
+pub fn check_if_agent_is_matchmaker(input: EntityAgent) -> ExternResult<bool> {
+  Ok(get_agent_matchmaker_link(input)?.is_some())
+}
+
+/// Revokes the matchmaker role of a user and its agents. Only administrators can do this.
+#[hdk_extern]
+pub fn remove_matchmaker(input: EntityActionHashAgents) -> ExternResult<bool> {
+  if !check_if_agent_is_administrator(EntityAgent {
+    entity: input.entity.clone(),
+    agent_pubkey: agent_info()?.agent_latest_pubkey,
+  })? {
+    return Err(wasm_error!(Guest(
+      "Only administrators can remove matchmakers".to_string()
+    )));
+  }
+
+  let matchmaker_link = get_all_matchmakers_links(input.entity.clone())?
+    .into_iter()
+    .find(|link| link.target == input.entity_original_action_hash.clone().into())
+    .ok_or(wasm_error!(Guest(
+      "Could not find the matchmaker link".to_string()
+    )))?;
+
+  delete_link(matchmaker_link.create_link_hash)?;
+
+  let path_hash = matchmakers_path(&input.entity).path_entry_hash()?;
+  for agent_pubkey in input.agent_pubkeys.clone() {
+    let links =
+      get_links(GetLinksInputBuilder::try_new(agent_pubkey, LinkTypes::AgentMatchmakers)?.build())?;
+
+    for link in links {
+      if link.target == path_hash.clone().into() {
+        delete_link(link.create_link_hash)?;
+      }
+    }
+  }
+
+  Ok(true)
+}
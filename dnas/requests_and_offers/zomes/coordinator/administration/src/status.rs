@@ -47,7 +47,7 @@ pub fn create_status(input: EntityActionHash) -> ExternResult<Record> {
 }
 
 #[hdk_extern]
-fn get_entity_status_link(input: EntityActionHash) -> ExternResult<Link> {
+pub fn get_entity_status_link(input: EntityActionHash) -> ExternResult<Link> {
   let links = get_links(
     GetLinksInputBuilder::try_new(
       input.entity_original_action_hash.clone(),
@@ -225,6 +225,12 @@ pub fn update_entity_status(input: UpdateEntityActionHash) -> ExternResult<Recor
     )));
   }
 
+  update_status(input)
+}
+
+/// Updates the Status of an entity and maintains its accepted index, without checking who
+/// triggers the update.
+pub fn update_status(input: UpdateEntityActionHash) -> ExternResult<Record> {
   let action_hash: HoloHash<holo_hash::hash_type::Action> = update_entry(
     input.status_previous_action_hash.clone(),
     input.new_status.clone(),
@@ -390,8 +396,19 @@ pub fn unsuspend_entity(input: UpdateInput) -> ExternResult<bool> {
   Ok(update_entity_status(update_status_input).is_ok())
 }
 
+/// Removes the Status of an entity and its accepted index. The entity itself is left to its
+/// owners: users cannot be deleted and organizations are deleted by their coordinators.
 #[hdk_extern]
 pub fn delete_status(input: EntityActionHash) -> ExternResult<bool> {
+  if !check_if_agent_is_administrator(EntityAgent {
+    agent_pubkey: agent_info()?.agent_initial_pubkey,
+    entity: input.entity.clone(),
+  })? {
+    return Err(wasm_error!(Guest(
+      "Only administrators can delete the Status of an Entity".to_string()
+    )));
+  }
+
   let link = get_entity_status_link(input.clone())?;
 
   delete_accepted_entity_link(EntityActionHash {
@@ -400,7 +417,6 @@ pub fn delete_status(input: EntityActionHash) -> ExternResult<bool> {
   })?;
 
   delete_link(link.create_link_hash)?;
-  delete_entry(input.entity_original_action_hash)?;
 
   Ok(true)
 }
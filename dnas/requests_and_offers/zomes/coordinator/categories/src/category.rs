@@ -0,0 +1,352 @@
+use categories_integrity::*;
+use hdk::prelude::*;
+use utils::errors::UtilsError;
+use WasmErrorInner::*;
+
+use crate::external_calls::{check_if_agent_is_administrator, get_agent_administrator_link};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CategoryInput {
+  pub name: String,
+  /// The original action hash of the parent category, if any.
+  pub parent: Option<ActionHash>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RenameCategoryInput {
+  pub original_action_hash: ActionHash,
+  pub previous_action_hash: ActionHash,
+  pub name: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ArchiveCategoryInput {
+  pub original_action_hash: ActionHash,
+  pub previous_action_hash: ActionHash,
+}
+
+fn check_if_i_am_administrator() -> ExternResult<()> {
+  if !check_if_agent_is_administrator("network", agent_info()?.agent_initial_pubkey)? {
+    return Err(wasm_error!(Guest(
+      "Only administrators can manage categories".to_string()
+    )));
+  }
+
+  Ok(())
+}
+
+fn categories_path() -> Path {
+  Path::from("categories")
+}
+
+/// Returns the path of a category below the `categories` root, such as
+/// "development.testing".
+#[hdk_extern]
+pub fn get_category_path(original_action_hash: ActionHash) -> ExternResult<String> {
+  let category = get_latest_category(original_action_hash)?;
+  let component = category_path_component(&category.name);
+
+  match category.parent {
+    Some(parent) => Ok(format!("{}.{}", get_category_path(parent)?, component)),
+    None => Ok(component),
+  }
+}
+
+fn path_hash(category_path: &str) -> ExternResult<EntryHash> {
+  Path::from(format!("categories.{}", category_path)).path_entry_hash()
+}
+
+/// Returns the link to the category stored at a path such as "development.testing", if any.
+#[hdk_extern]
+pub fn get_category_by_path(category_path: String) -> ExternResult<Option<Link>> {
+  let links = get_links(
+    GetLinksInputBuilder::try_new(path_hash(&category_path)?, LinkTypes::PathCategory)?.build(),
+  )?;
+
+  Ok(links.first().cloned())
+}
+
+fn check_path_is_free(category_path: &str) -> ExternResult<()> {
+  if get_category_by_path(category_path.to_string())?.is_some() {
+    return Err(wasm_error!(Guest(format!(
+      "There is already a category at '{}'",
+      category_path
+    ))));
+  }
+
+  Ok(())
+}
+
+/// Creates a category, at the root of the tree or below a parent category. Only
+/// administrators can do this.
+#[hdk_extern]
+pub fn create_category(input: CategoryInput) -> ExternResult<Record> {
+  check_if_i_am_administrator()?;
+
+  let component = category_path_component(&input.name);
+  let category_path = match input.parent.clone() {
+    Some(parent) => {
+      if get_latest_category(parent.clone())?.archived {
+        return Err(wasm_error!(Guest(
+          "Cannot add a category to an archived category".to_string()
+        )));
+      }
+      format!("{}.{}", get_category_path(parent)?, component)
+    }
+    None => component,
+  };
+  check_path_is_free(&category_path)?;
+
+  let category = Category {
+    name: input.name,
+    parent: input.parent.clone(),
+    archived: false,
+    administrator_link: None,
+  };
+  let category_hash = create_entry(&EntryTypes::Category(category))?;
+  let record = get(category_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(Guest(
+    "Could not find the newly created Category".to_string()
+  )))?;
+
+  create_link(
+    categories_path().path_entry_hash()?,
+    category_hash.clone(),
+    LinkTypes::AllCategories,
+    (),
+  )?;
+
+  let parent_hash: AnyLinkableHash = match input.parent {
+    Some(parent) => parent.into(),
+    None => categories_path().path_entry_hash()?.into(),
+  };
+  create_link(
+    parent_hash,
+    category_hash.clone(),
+    LinkTypes::CategoryChildren,
+    (),
+  )?;
+
+  create_link(
+    path_hash(&category_path)?,
+    category_hash,
+    LinkTypes::PathCategory,
+    (),
+  )?;
+
+  Ok(record)
+}
+
+#[hdk_extern]
+pub fn get_latest_category_record(
+  original_action_hash: ActionHash,
+) -> ExternResult<Option<Record>> {
+  let links = get_links(
+    GetLinksInputBuilder::try_new(original_action_hash.clone(), LinkTypes::CategoryUpdates)?
+      .build(),
+  )?;
+  let latest_link = links
+    .into_iter()
+    .max_by(|link_a, link_b| link_a.timestamp.cmp(&link_b.timestamp));
+  let latest_category_hash = match latest_link {
+    Some(link) => link
+      .target
+      .clone()
+      .into_action_hash()
+      .ok_or(UtilsError::ActionHashNotFound("category"))?,
+    None => original_action_hash.clone(),
+  };
+  get(latest_category_hash, GetOptions::default())
+}
+
+#[hdk_extern]
+pub fn get_latest_category(original_action_hash: ActionHash) -> ExternResult<Category> {
+  let latest_category_record = get_latest_category_record(original_action_hash)?;
+  let latest_category: Category = latest_category_record
+    .ok_or(wasm_error!(Guest(
+      "Could not find the latest Category".to_string()
+    )))?
+    .entry()
+    .to_app_option()
+    .map_err(|e| wasm_error!(Serialize(e)))?
+    .ok_or(wasm_error!(Guest(
+      "Could not find the latest Category".to_string()
+    )))?;
+
+  Ok(latest_category)
+}
+
+/// Checks that a category exists and is not archived, so that entities can be classified in
+/// it.
+#[hdk_extern]
+pub fn check_if_category_is_active(original_action_hash: ActionHash) -> ExternResult<bool> {
+  let Some(record) = get_latest_category_record(original_action_hash)? else {
+    return Ok(false);
+  };
+  let category: Option<Category> = record
+    .entry()
+    .to_app_option()
+    .map_err(|e| wasm_error!(Serialize(e)))?;
+
+  Ok(category.is_some_and(|category| !category.archived))
+}
+
+#[hdk_extern]
+pub fn get_all_categories_links(_: ()) -> ExternResult<Vec<Link>> {
+  get_links(
+    GetLinksInputBuilder::try_new(
+      categories_path().path_entry_hash()?,
+      LinkTypes::AllCategories,
+    )?
+    .build(),
+  )
+}
+
+fn get_category_children_links(parent: Option<ActionHash>) -> ExternResult<Vec<Link>> {
+  let parent_hash: AnyLinkableHash = match parent {
+    Some(parent) => parent.into(),
+    None => categories_path().path_entry_hash()?.into(),
+  };
+
+  get_links(GetLinksInputBuilder::try_new(parent_hash, LinkTypes::CategoryChildren)?.build())
+}
+
+/// Returns the links to the categories that are not archived directly below a category, or at
+/// the root of the tree when no parent is given.
+#[hdk_extern]
+pub fn get_category_children(parent: Option<ActionHash>) -> ExternResult<Vec<Link>> {
+  let mut children = vec![];
+  for link in get_category_children_links(parent)? {
+    let child_hash = link
+      .target
+      .clone()
+      .into_action_hash()
+      .ok_or(UtilsError::ActionHashNotFound("category"))?;
+    if !get_latest_category(child_hash)?.archived {
+      children.push(link);
+    }
+  }
+
+  Ok(children)
+}
+
+fn update_category(
+  original_action_hash: ActionHash,
+  previous_action_hash: ActionHash,
+  category: Category,
+) -> ExternResult<Record> {
+  let administrator_link =
+    get_agent_administrator_link("network", agent_info()?.agent_initial_pubkey)?.ok_or(
+      wasm_error!(Guest(
+        "Only administrators can manage categories".to_string()
+      )),
+    )?;
+  let category = Category {
+    administrator_link: Some(administrator_link.create_link_hash),
+    ..category
+  };
+  let updated_category_hash = update_entry(previous_action_hash, &category)?;
+
+  create_link(
+    original_action_hash,
+    updated_category_hash.clone(),
+    LinkTypes::CategoryUpdates,
+    (),
+  )?;
+
+  let record = get(updated_category_hash, GetOptions::default())?.ok_or(wasm_error!(Guest(
+    "Could not find the newly updated Category".to_string()
+  )))?;
+
+  Ok(record)
+}
+
+/// Moves the `PathCategory` links of a category and of all its descendants from one path to
+/// another.
+fn move_category_paths(
+  original_action_hash: ActionHash,
+  previous_path: &str,
+  new_path: &str,
+) -> ExternResult<()> {
+  if let Some(link) = get_category_by_path(previous_path.to_string())? {
+    delete_link(link.create_link_hash)?;
+  }
+  create_link(
+    path_hash(new_path)?,
+    original_action_hash.clone(),
+    LinkTypes::PathCategory,
+    (),
+  )?;
+
+  for link in get_category_children_links(Some(original_action_hash))? {
+    let child_hash = link
+      .target
+      .into_action_hash()
+      .ok_or(UtilsError::ActionHashNotFound("category"))?;
+    let component = category_path_component(&get_latest_category(child_hash.clone())?.name);
+    move_category_paths(
+      child_hash,
+      &format!("{}.{}", previous_path, component),
+      &format!("{}.{}", new_path, component),
+    )?;
+  }
+
+  Ok(())
+}
+
+/// Renames a category, moving it and its descendants in the path tree. Only administrators
+/// can do this.
+#[hdk_extern]
+pub fn rename_category(input: RenameCategoryInput) -> ExternResult<Record> {
+  check_if_i_am_administrator()?;
+
+  let category = get_latest_category(input.original_action_hash.clone())?;
+  let previous_path = get_category_path(input.original_action_hash.clone())?;
+  let new_path = match category.parent.clone() {
+    Some(parent) => format!(
+      "{}.{}",
+      get_category_path(parent)?,
+      category_path_component(&input.name)
+    ),
+    None => category_path_component(&input.name),
+  };
+  if new_path != previous_path {
+    check_path_is_free(&new_path)?;
+  }
+
+  let record = update_category(
+    input.original_action_hash.clone(),
+    input.previous_action_hash,
+    Category {
+      name: input.name,
+      ..category
+    },
+  )?;
+
+  if new_path != previous_path {
+    move_category_paths(input.original_action_hash, &previous_path, &new_path)?;
+  }
+
+  Ok(record)
+}
+
+/// Archives a category, hiding it from navigation. Only administrators can do this.
+#[hdk_extern]
+pub fn archive_category(input: ArchiveCategoryInput) -> ExternResult<Record> {
+  check_if_i_am_administrator()?;
+
+  let category = get_latest_category(input.original_action_hash.clone())?;
+  if category.archived {
+    return Err(wasm_error!(Guest(
+      "This Category is already archived".to_string()
+    )));
+  }
+
+  update_category(
+    input.original_action_hash,
+    input.previous_action_hash,
+    Category {
+      archived: true,
+      ..category
+    },
+  )
+}
@@ -0,0 +1,41 @@
+use hdk::prelude::*;
+use utils::{external_local_call, EntityAgent};
+
+/// Checks if a given agent is an administrator for a specified entity.
+///
+/// # Arguments
+///
+/// * `entity` - The name of the entity, such as "network".
+/// * `agent_pubkey` - The public key of the agent to check.
+///
+/// # Returns
+///
+/// * `ExternResult<bool>` - Returns `true` if the agent is an administrator, otherwise `false`.
+pub fn check_if_agent_is_administrator(
+  entity: &str,
+  agent_pubkey: AgentPubKey,
+) -> ExternResult<bool> {
+  external_local_call(
+    "check_if_agent_is_administrator",
+    "administration",
+    EntityAgent {
+      entity: entity.to_string(),
+      agent_pubkey,
+    },
+  )
+}
+
+/// Returns the `AgentAdministrators` link of an agent for a specified entity, if any.
+pub fn get_agent_administrator_link(
+  entity: &str,
+  agent_pubkey: AgentPubKey,
+) -> ExternResult<Option<Link>> {
+  external_local_call(
+    "get_agent_administrator_link",
+    "administration",
+    EntityAgent {
+      entity: entity.to_string(),
+      agent_pubkey,
+    },
+  )
+}
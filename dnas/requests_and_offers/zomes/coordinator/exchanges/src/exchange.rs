@@ -0,0 +1,285 @@
+use exchanges_integrity::*;
+use hdk::prelude::*;
+use utils::errors::UtilsError;
+use WasmErrorInner::*;
+
+use crate::external_calls::{get_agent_user, get_user_agents_links};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ExchangeInput {
+  pub provider: ActionHash,
+  pub receiver: ActionHash,
+  pub terms: String,
+  pub reference: Option<ActionHash>,
+}
+
+/// Finds which party of an exchange the current agent acts for, along with the `UserAgents`
+/// link from that party's User profile to the agent.
+fn get_agent_party(
+  provider: ActionHash,
+  receiver: ActionHash,
+) -> ExternResult<(ExchangeParty, ActionHash)> {
+  let agent_pubkey = agent_info()?.agent_initial_pubkey;
+
+  for (party, user) in [
+    (ExchangeParty::Provider, provider),
+    (ExchangeParty::Receiver, receiver),
+  ] {
+    if let Some(link) = get_user_agents_links(user)?
+      .into_iter()
+      .find(|link| link.target.clone().into_agent_pub_key() == Some(agent_pubkey.clone()))
+    {
+      return Ok((party, link.create_link_hash));
+    }
+  }
+
+  Err(wasm_error!(Guest(
+    "Only the provider or the receiver of an Exchange can do this".to_string()
+  )))
+}
+
+#[hdk_extern]
+pub fn create_exchange(input: ExchangeInput) -> ExternResult<Record> {
+  let (party, party_agent_link) = get_agent_party(input.provider.clone(), input.receiver.clone())?;
+
+  let exchange = Exchange {
+    provider: input.provider.clone(),
+    receiver: input.receiver.clone(),
+    terms: input.terms,
+    reference: input.reference.clone(),
+    provider_confirmed: party == ExchangeParty::Provider,
+    receiver_confirmed: party == ExchangeParty::Receiver,
+    provider_completed: false,
+    receiver_completed: false,
+    status: ExchangeStatus::Proposed.to_string(),
+    party_agent_link,
+  };
+
+  let exchange_hash = create_entry(&EntryTypes::Exchange(exchange))?;
+  let record = get(exchange_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(Guest(
+    "Could not find the newly created Exchange".to_string()
+  )))?;
+
+  create_link(
+    input.provider,
+    exchange_hash.clone(),
+    LinkTypes::UserExchanges,
+    (),
+  )?;
+
+  create_link(
+    input.receiver,
+    exchange_hash.clone(),
+    LinkTypes::UserExchanges,
+    (),
+  )?;
+
+  if let Some(reference) = input.reference {
+    create_link(reference, exchange_hash, LinkTypes::ReferenceExchanges, ())?;
+  }
+
+  Ok(record)
+}
+
+#[hdk_extern]
+pub fn get_latest_exchange_record(
+  original_action_hash: ActionHash,
+) -> ExternResult<Option<Record>> {
+  let links = get_links(
+    GetLinksInputBuilder::try_new(original_action_hash.clone(), LinkTypes::ExchangeUpdates)?
+      .build(),
+  )?;
+  let latest_link = links
+    .into_iter()
+    .max_by(|link_a, link_b| link_a.timestamp.cmp(&link_b.timestamp));
+  let latest_exchange_hash = match latest_link {
+    Some(link) => link
+      .target
+      .clone()
+      .into_action_hash()
+      .ok_or(UtilsError::ActionHashNotFound("exchange"))?,
+    None => original_action_hash.clone(),
+  };
+  get(latest_exchange_hash, GetOptions::default())
+}
+
+#[hdk_extern]
+pub fn get_latest_exchange(original_action_hash: ActionHash) -> ExternResult<Exchange> {
+  let latest_exchange_record = get_latest_exchange_record(original_action_hash)?;
+  let latest_exchange: Exchange = latest_exchange_record
+    .ok_or(wasm_error!(Guest(
+      "Could not find the latest Exchange".to_string()
+    )))?
+    .entry()
+    .to_app_option()
+    .map_err(|e| wasm_error!(Serialize(e)))?
+    .ok_or(wasm_error!(Guest(
+      "Could not find the latest Exchange".to_string()
+    )))?;
+
+  Ok(latest_exchange)
+}
+
+#[hdk_extern]
+pub fn get_user_exchanges_links(user_original_action_hash: ActionHash) -> ExternResult<Vec<Link>> {
+  get_links(
+    GetLinksInputBuilder::try_new(user_original_action_hash, LinkTypes::UserExchanges)?.build(),
+  )
+}
+
+#[hdk_extern]
+pub fn get_my_exchanges_links(_: ()) -> ExternResult<Vec<Link>> {
+  let user_links = get_agent_user(agent_info()?.agent_initial_pubkey)?;
+  let Some(user_link) = user_links.first() else {
+    return Ok(vec![]);
+  };
+  let user_original_action_hash = user_link
+    .target
+    .clone()
+    .into_action_hash()
+    .ok_or(UtilsError::ActionHashNotFound("user"))?;
+
+  get_user_exchanges_links(user_original_action_hash)
+}
+
+#[hdk_extern]
+pub fn get_reference_exchanges_links(reference: ActionHash) -> ExternResult<Vec<Link>> {
+  get_links(GetLinksInputBuilder::try_new(reference, LinkTypes::ReferenceExchanges)?.build())
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ExchangeConfirmationInput {
+  pub original_action_hash: ActionHash,
+  pub previous_action_hash: ActionHash,
+}
+
+fn update_exchange(
+  input: ExchangeConfirmationInput,
+  exchange: Exchange,
+  party_agent_link: ActionHash,
+) -> ExternResult<Record> {
+  let exchange = Exchange {
+    status: exchange.expected_status().to_string(),
+    party_agent_link,
+    ..exchange
+  };
+
+  let updated_exchange_hash = update_entry(input.previous_action_hash, &exchange)?;
+
+  create_link(
+    input.original_action_hash,
+    updated_exchange_hash.clone(),
+    LinkTypes::ExchangeUpdates,
+    (),
+  )?;
+
+  let record = get(updated_exchange_hash, GetOptions::default())?.ok_or(wasm_error!(Guest(
+    "Could not find the newly updated Exchange".to_string()
+  )))?;
+
+  Ok(record)
+}
+
+/// Confirms the terms of an exchange on behalf of the current agent's party. The exchange
+/// becomes agreed once both parties confirmed it.
+#[hdk_extern]
+pub fn confirm_exchange(input: ExchangeConfirmationInput) -> ExternResult<Record> {
+  let exchange = get_latest_exchange(input.original_action_hash.clone())?;
+  let (party, party_agent_link) =
+    get_agent_party(exchange.provider.clone(), exchange.receiver.clone())?;
+
+  let already_confirmed = match party {
+    ExchangeParty::Provider => exchange.provider_confirmed,
+    ExchangeParty::Receiver => exchange.receiver_confirmed,
+  };
+  if already_confirmed {
+    return Err(wasm_error!(Guest(
+      "You already confirmed this Exchange".to_string()
+    )));
+  }
+
+  let exchange = match party {
+    ExchangeParty::Provider => Exchange {
+      provider_confirmed: true,
+      ..exchange
+    },
+    ExchangeParty::Receiver => Exchange {
+      receiver_confirmed: true,
+      ..exchange
+    },
+  };
+
+  update_exchange(input, exchange, party_agent_link)
+}
+
+/// Marks an agreed exchange as done on behalf of the current agent's party. The exchange
+/// becomes completed once both parties marked it as done.
+#[hdk_extern]
+pub fn complete_exchange(input: ExchangeConfirmationInput) -> ExternResult<Record> {
+  let exchange = get_latest_exchange(input.original_action_hash.clone())?;
+  let (party, party_agent_link) =
+    get_agent_party(exchange.provider.clone(), exchange.receiver.clone())?;
+
+  if exchange.status == ExchangeStatus::Proposed.to_string() {
+    return Err(wasm_error!(Guest(
+      "An Exchange must be agreed by both parties before being marked as done".to_string()
+    )));
+  }
+
+  let already_completed = match party {
+    ExchangeParty::Provider => exchange.provider_completed,
+    ExchangeParty::Receiver => exchange.receiver_completed,
+  };
+  if already_completed {
+    return Err(wasm_error!(Guest(
+      "You already marked this Exchange as done".to_string()
+    )));
+  }
+
+  let exchange = match party {
+    ExchangeParty::Provider => Exchange {
+      provider_completed: true,
+      ..exchange
+    },
+    ExchangeParty::Receiver => Exchange {
+      receiver_completed: true,
+      ..exchange
+    },
+  };
+
+  update_exchange(input, exchange, party_agent_link)
+}
+
+#[hdk_extern]
+pub fn delete_exchange(exchange_original_action_hash: ActionHash) -> ExternResult<bool> {
+  let original_record = must_get_valid_record(exchange_original_action_hash.clone())?;
+
+  if *original_record.action().author() != agent_info()?.agent_initial_pubkey {
+    return Err(wasm_error!(Guest(
+      "Only the author of an Exchange can delete it".to_string()
+    )));
+  }
+
+  let exchange = get_latest_exchange(exchange_original_action_hash.clone())?;
+  if exchange.status != ExchangeStatus::Proposed.to_string() {
+    return Err(wasm_error!(Guest(
+      "An Exchange cannot be deleted once agreed".to_string()
+    )));
+  }
+
+  let mut links = get_user_exchanges_links(exchange.provider)?;
+  links.extend(get_user_exchanges_links(exchange.receiver)?);
+  if let Some(reference) = exchange.reference {
+    links.extend(get_reference_exchanges_links(reference)?);
+  }
+
+  for link in links {
+    if link.target.clone().into_action_hash() == Some(exchange_original_action_hash.clone()) {
+      delete_link(link.create_link_hash)?;
+    }
+  }
+
+  delete_entry(exchange_original_action_hash)?;
+
+  Ok(true)
+}
@@ -0,0 +1,56 @@
+use hdk::prelude::*;
+use utils::{external_local_call, EntityAgent};
+
+/// Retrieves the links from an agent to its User profile.
+///
+/// # Arguments
+///
+/// * `agent_pubkey` - The public key of the agent.
+///
+/// # Returns
+///
+/// * `ExternResult<Vec<Link>>` - Returns the `MyUser` links of the agent.
+pub fn get_agent_user(agent_pubkey: AgentPubKey) -> ExternResult<Vec<Link>> {
+  external_local_call("get_agent_user", "users_organizations", agent_pubkey)
+}
+
+/// Retrieves the `UserAgents` links from a User profile to its agents.
+///
+/// # Arguments
+///
+/// * `user_original_action_hash` - The original action hash of the user.
+///
+/// # Returns
+///
+/// * `ExternResult<Vec<Link>>` - Returns the `UserAgents` links of the user.
+pub fn get_user_agents_links(user_original_action_hash: ActionHash) -> ExternResult<Vec<Link>> {
+  external_local_call(
+    "get_user_agents_links",
+    "users_organizations",
+    user_original_action_hash,
+  )
+}
+
+/// Retrieves the link granting the matchmaker role for a specified entity to an agent.
+///
+/// # Arguments
+///
+/// * `entity` - The name of the entity, such as "network".
+/// * `agent_pubkey` - The public key of the agent.
+///
+/// # Returns
+///
+/// * `ExternResult<Option<Link>>` - Returns the `AgentMatchmakers` link of the agent, if any.
+pub fn get_agent_matchmaker_link(
+  entity: &str,
+  agent_pubkey: AgentPubKey,
+) -> ExternResult<Option<Link>> {
+  external_local_call(
+    "get_agent_matchmaker_link",
+    "administration",
+    EntityAgent {
+      entity: entity.to_string(),
+      agent_pubkey,
+    },
+  )
+}
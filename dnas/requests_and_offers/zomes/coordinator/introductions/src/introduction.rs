@@ -0,0 +1,241 @@
+use hdk::prelude::*;
+use introductions_integrity::*;
+use utils::errors::UtilsError;
+use WasmErrorInner::*;
+
+use crate::external_calls::{get_agent_matchmaker_link, get_agent_user, get_user_agents_links};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct IntroductionInput {
+  pub first_user: ActionHash,
+  pub second_user: ActionHash,
+  pub note: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct IntroductionDecisionInput {
+  pub original_action_hash: ActionHash,
+  pub previous_action_hash: ActionHash,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum IntroducedUser {
+  First,
+  Second,
+}
+
+fn get_my_user_original_action_hash() -> ExternResult<Option<ActionHash>> {
+  let user_links = get_agent_user(agent_info()?.agent_initial_pubkey)?;
+  Ok(
+    user_links
+      .first()
+      .and_then(|link| link.target.clone().into_action_hash()),
+  )
+}
+
+/// Finds which of the introduced users the current agent acts for, along with the `UserAgents`
+/// link from that user to the agent.
+fn get_introduced_user(introduction: &Introduction) -> ExternResult<(IntroducedUser, ActionHash)> {
+  let agent_pubkey = agent_info()?.agent_initial_pubkey;
+
+  for (introduced_user, user) in [
+    (IntroducedUser::First, &introduction.first_user),
+    (IntroducedUser::Second, &introduction.second_user),
+  ] {
+    let user_agent_link = get_user_agents_links(user.clone())?
+      .into_iter()
+      .find(|link| link.target.clone().into_agent_pub_key() == Some(agent_pubkey.clone()));
+    if let Some(user_agent_link) = user_agent_link {
+      return Ok((introduced_user, user_agent_link.create_link_hash));
+    }
+  }
+
+  Err(wasm_error!(Guest(
+    "Only an introduced user can decide on an Introduction".to_string()
+  )))
+}
+
+/// Introduces two users to each other. Only matchmakers can do this.
+#[hdk_extern]
+pub fn create_introduction(input: IntroductionInput) -> ExternResult<Record> {
+  let matchmaker_link = get_agent_matchmaker_link("network", agent_info()?.agent_initial_pubkey)?
+    .ok_or(wasm_error!(Guest(
+    "Only matchmakers can introduce users".to_string()
+  )))?;
+
+  let matchmaker = get_my_user_original_action_hash()?.ok_or(wasm_error!(Guest(
+    "You must first create a User profile".to_string()
+  )))?;
+
+  let introduction = Introduction {
+    matchmaker: matchmaker.clone(),
+    first_user: input.first_user.clone(),
+    second_user: input.second_user.clone(),
+    note: input.note,
+    first_user_decision: IntroductionDecision::Pending.to_string(),
+    second_user_decision: IntroductionDecision::Pending.to_string(),
+    matchmaker_link: matchmaker_link.create_link_hash,
+    first_user_agent_link: None,
+    second_user_agent_link: None,
+  };
+
+  let introduction_hash = create_entry(&EntryTypes::Introduction(introduction))?;
+  let record = get(introduction_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(Guest(
+    "Could not find the newly created Introduction".to_string()
+  )))?;
+
+  for user in [matchmaker, input.first_user, input.second_user] {
+    create_link(
+      user,
+      introduction_hash.clone(),
+      LinkTypes::UserIntroductions,
+      (),
+    )?;
+  }
+
+  Ok(record)
+}
+
+#[hdk_extern]
+pub fn get_latest_introduction_record(
+  original_action_hash: ActionHash,
+) -> ExternResult<Option<Record>> {
+  let links = get_links(
+    GetLinksInputBuilder::try_new(original_action_hash.clone(), LinkTypes::IntroductionUpdates)?
+      .build(),
+  )?;
+  let latest_link = links
+    .into_iter()
+    .max_by(|link_a, link_b| link_a.timestamp.cmp(&link_b.timestamp));
+  let latest_introduction_hash = match latest_link {
+    Some(link) => link
+      .target
+      .clone()
+      .into_action_hash()
+      .ok_or(UtilsError::ActionHashNotFound("introduction"))?,
+    None => original_action_hash.clone(),
+  };
+  get(latest_introduction_hash, GetOptions::default())
+}
+
+/// Returns the latest version of an introduction, including the decision of each user.
+#[hdk_extern]
+pub fn get_latest_introduction(original_action_hash: ActionHash) -> ExternResult<Introduction> {
+  let latest_introduction_record = get_latest_introduction_record(original_action_hash)?;
+  let latest_introduction: Introduction = latest_introduction_record
+    .ok_or(wasm_error!(Guest(
+      "Could not find the latest Introduction".to_string()
+    )))?
+    .entry()
+    .to_app_option()
+    .map_err(|e| wasm_error!(Serialize(e)))?
+    .ok_or(wasm_error!(Guest(
+      "Could not find the latest Introduction".to_string()
+    )))?;
+
+  Ok(latest_introduction)
+}
+
+/// Returns the links to the introductions a user proposed as a matchmaker or was introduced in.
+#[hdk_extern]
+pub fn get_user_introductions_links(
+  user_original_action_hash: ActionHash,
+) -> ExternResult<Vec<Link>> {
+  get_links(
+    GetLinksInputBuilder::try_new(user_original_action_hash, LinkTypes::UserIntroductions)?.build(),
+  )
+}
+
+#[hdk_extern]
+pub fn get_my_introductions_links(_: ()) -> ExternResult<Vec<Link>> {
+  let Some(user_original_action_hash) = get_my_user_original_action_hash()? else {
+    return Ok(vec![]);
+  };
+
+  get_user_introductions_links(user_original_action_hash)
+}
+
+fn decide_on_introduction(
+  input: IntroductionDecisionInput,
+  decision: IntroductionDecision,
+) -> ExternResult<Record> {
+  let introduction = get_latest_introduction(input.original_action_hash.clone())?;
+  let (introduced_user, user_agent_link) = get_introduced_user(&introduction)?;
+
+  let current_decision = match introduced_user {
+    IntroducedUser::First => &introduction.first_user_decision,
+    IntroducedUser::Second => &introduction.second_user_decision,
+  };
+  if *current_decision != IntroductionDecision::Pending.to_string() {
+    return Err(wasm_error!(Guest(
+      "You already decided on this Introduction".to_string()
+    )));
+  }
+
+  let introduction = match introduced_user {
+    IntroducedUser::First => Introduction {
+      first_user_decision: decision.to_string(),
+      first_user_agent_link: Some(user_agent_link),
+      ..introduction
+    },
+    IntroducedUser::Second => Introduction {
+      second_user_decision: decision.to_string(),
+      second_user_agent_link: Some(user_agent_link),
+      ..introduction
+    },
+  };
+
+  let updated_introduction_hash = update_entry(input.previous_action_hash, &introduction)?;
+
+  create_link(
+    input.original_action_hash,
+    updated_introduction_hash.clone(),
+    LinkTypes::IntroductionUpdates,
+    (),
+  )?;
+
+  let record = get(updated_introduction_hash, GetOptions::default())?.ok_or(wasm_error!(Guest(
+    "Could not find the newly updated Introduction".to_string()
+  )))?;
+
+  Ok(record)
+}
+
+#[hdk_extern]
+pub fn accept_introduction(input: IntroductionDecisionInput) -> ExternResult<Record> {
+  decide_on_introduction(input, IntroductionDecision::Accepted)
+}
+
+#[hdk_extern]
+pub fn decline_introduction(input: IntroductionDecisionInput) -> ExternResult<Record> {
+  decide_on_introduction(input, IntroductionDecision::Declined)
+}
+
+#[hdk_extern]
+pub fn delete_introduction(introduction_original_action_hash: ActionHash) -> ExternResult<bool> {
+  let original_record = must_get_valid_record(introduction_original_action_hash.clone())?;
+
+  if *original_record.action().author() != agent_info()?.agent_initial_pubkey {
+    return Err(wasm_error!(Guest(
+      "Only the matchmaker of an Introduction can delete it".to_string()
+    )));
+  }
+
+  let introduction = get_latest_introduction(introduction_original_action_hash.clone())?;
+
+  for user in [
+    introduction.matchmaker,
+    introduction.first_user,
+    introduction.second_user,
+  ] {
+    for link in get_user_introductions_links(user)? {
+      if link.target.clone().into_action_hash() == Some(introduction_original_action_hash.clone()) {
+        delete_link(link.create_link_hash)?;
+      }
+    }
+  }
+
+  delete_entry(introduction_original_action_hash)?;
+
+  Ok(true)
+}
@@ -0,0 +1,32 @@
+use hdk::prelude::*;
+use utils::external_local_call;
+
+/// Retrieves the links from an agent to its User profile.
+///
+/// # Arguments
+///
+/// * `agent_pubkey` - The public key of the agent.
+///
+/// # Returns
+///
+/// * `ExternResult<Vec<Link>>` - Returns the `MyUser` links of the agent.
+pub fn get_agent_user(agent_pubkey: AgentPubKey) -> ExternResult<Vec<Link>> {
+  external_local_call("get_agent_user", "users_organizations", agent_pubkey)
+}
+
+/// Retrieves the links from a User profile to the agents acting on its behalf.
+///
+/// # Arguments
+///
+/// * `user_original_action_hash` - The original action hash of the user.
+///
+/// # Returns
+///
+/// * `ExternResult<Vec<Link>>` - Returns the `UserAgents` links of the user.
+pub fn get_user_agents_links(user_original_action_hash: ActionHash) -> ExternResult<Vec<Link>> {
+  external_local_call(
+    "get_user_agents_links",
+    "users_organizations",
+    user_original_action_hash,
+  )
+}
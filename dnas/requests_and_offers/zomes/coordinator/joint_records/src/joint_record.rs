@@ -0,0 +1,213 @@
+use hdk::prelude::*;
+use joint_records_integrity::*;
+use utils::{
+  countersigning::{
+    accept_preflight_request, build_create_preflight_request, create_countersigned_entry,
+    get_app_entry,
+  },
+  external_remote_call,
+};
+use WasmErrorInner::*;
+
+use crate::external_calls::{get_agent_user, get_user_agents_links};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct JointRecordInput {
+  pub other_user: ActionHash,
+  pub content: String,
+  pub reference: Option<ActionHash>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct JointRecordProposal {
+  pub joint_record: JointRecord,
+  pub preflight_request: PreflightRequest,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CommitJointRecordInput {
+  pub joint_record: JointRecord,
+  pub responses: Vec<PreflightResponse>,
+}
+
+fn get_my_user_hash() -> ExternResult<ActionHash> {
+  get_agent_user(agent_info()?.agent_initial_pubkey)?
+    .first()
+    .and_then(|link| link.target.clone().into_action_hash())
+    .ok_or(wasm_error!(Guest(
+      "You must first create a User profile".to_string()
+    )))
+}
+
+/// Finds the `UserAgents` link of a user, optionally pointing to a given agent.
+fn get_user_agent_link(
+  user_original_action_hash: ActionHash,
+  agent_pubkey: Option<AgentPubKey>,
+) -> ExternResult<Link> {
+  get_user_agents_links(user_original_action_hash)?
+    .into_iter()
+    .find(|link| agent_pubkey.is_none() || link.target.clone().into_agent_pub_key() == agent_pubkey)
+    .ok_or(wasm_error!(Guest(
+      "Could not find an agent for this User".to_string()
+    )))
+}
+
+fn joint_record_entry_type() -> ExternResult<EntryType> {
+  let ScopedEntryDefIndex {
+    zome_index,
+    zome_type,
+  } = UnitEntryTypes::JointRecord.try_into()?;
+
+  Ok(EntryType::App(AppEntryDef::new(
+    zome_type,
+    zome_index,
+    EntryVisibility::Public,
+  )))
+}
+
+/// Proposes a joint record to another user and commits it on both source chains in a single
+/// countersigning session.
+#[hdk_extern]
+pub fn create_joint_record(input: JointRecordInput) -> ExternResult<Record> {
+  let my_pubkey = agent_info()?.agent_initial_pubkey;
+  let my_user_hash = get_my_user_hash()?;
+  let my_agent_link = get_user_agent_link(my_user_hash.clone(), Some(my_pubkey.clone()))?;
+  let other_agent_link = get_user_agent_link(input.other_user.clone(), None)?;
+  let other_pubkey = other_agent_link
+    .target
+    .clone()
+    .into_agent_pub_key()
+    .ok_or(wasm_error!(Guest(
+      "Could not find an agent for this User".to_string()
+    )))?;
+
+  let joint_record = JointRecord {
+    first_user: my_user_hash,
+    first_user_agent_link: my_agent_link.create_link_hash,
+    second_user: input.other_user,
+    second_user_agent_link: other_agent_link.create_link_hash,
+    content: input.content,
+    reference: input.reference,
+  };
+
+  let preflight_request = build_create_preflight_request(
+    hash_entry(&joint_record)?,
+    joint_record_entry_type()?,
+    vec![my_pubkey, other_pubkey.clone()],
+  )?;
+
+  let my_response = accept_preflight_request(preflight_request.clone())?;
+  let other_response: PreflightResponse = external_remote_call(
+    other_pubkey.clone(),
+    "accept_joint_record_preflight",
+    "joint_records",
+    JointRecordProposal {
+      joint_record: joint_record.clone(),
+      preflight_request,
+    },
+  )?;
+
+  let input = CommitJointRecordInput {
+    joint_record,
+    responses: vec![my_response, other_response],
+  };
+
+  let joint_record_hash = commit_joint_record(input.clone())?;
+  let _: ActionHash =
+    external_remote_call(other_pubkey, "commit_joint_record", "joint_records", input)?;
+
+  let record = get(joint_record_hash, GetOptions::default())?.ok_or(wasm_error!(Guest(
+    "Could not find the newly created JointRecord".to_string()
+  )))?;
+
+  Ok(record)
+}
+
+/// Called remotely by the proposer: accepts the session if the current agent is the second
+/// user of the proposed joint record.
+#[hdk_extern]
+pub fn accept_joint_record_preflight(
+  proposal: JointRecordProposal,
+) -> ExternResult<PreflightResponse> {
+  if hash_entry(&proposal.joint_record)? != proposal.preflight_request.app_entry_hash {
+    return Err(wasm_error!(Guest(
+      "The preflight request does not match the proposed JointRecord".to_string()
+    )));
+  }
+
+  if get_my_user_hash()? != proposal.joint_record.second_user {
+    return Err(wasm_error!(Guest(
+      "This JointRecord is not addressed to you".to_string()
+    )));
+  }
+
+  accept_preflight_request(proposal.preflight_request)
+}
+
+/// Commits the joint record on the current agent's source chain once both agents accepted the
+/// session.
+#[hdk_extern]
+pub fn commit_joint_record(input: CommitJointRecordInput) -> ExternResult<ActionHash> {
+  let ScopedEntryDefIndex {
+    zome_index,
+    zome_type,
+  } = UnitEntryTypes::JointRecord.try_into()?;
+
+  create_countersigned_entry(
+    EntryDefLocation::app(zome_index, zome_type),
+    SerializedBytes::try_from(input.joint_record).map_err(|e| wasm_error!(Serialize(e)))?,
+    input.responses,
+  )
+}
+
+#[hdk_extern]
+pub fn get_joint_record(joint_record_hash: ActionHash) -> ExternResult<Option<JointRecord>> {
+  let Some(record) = get(joint_record_hash, GetOptions::default())? else {
+    return Ok(None);
+  };
+
+  get_app_entry(&record)
+}
+
+/// Adds a joint record to the index of the current agent's user, once the countersigning
+/// session completed.
+#[hdk_extern]
+pub fn index_joint_record(joint_record_hash: ActionHash) -> ExternResult<bool> {
+  let joint_record = get_joint_record(joint_record_hash.clone())?.ok_or(wasm_error!(Guest(
+    "Could not find the JointRecord".to_string()
+  )))?;
+
+  let my_user_hash = get_my_user_hash()?;
+  if my_user_hash != joint_record.first_user && my_user_hash != joint_record.second_user {
+    return Err(wasm_error!(Guest(
+      "Only the users of a JointRecord can index it".to_string()
+    )));
+  }
+
+  create_link(
+    my_user_hash,
+    joint_record_hash,
+    LinkTypes::UserJointRecords,
+    (),
+  )?;
+
+  Ok(true)
+}
+
+#[hdk_extern]
+pub fn get_user_joint_records_links(
+  user_original_action_hash: ActionHash,
+) -> ExternResult<Vec<Link>> {
+  get_links(
+    GetLinksInputBuilder::try_new(user_original_action_hash, LinkTypes::UserJointRecords)?.build(),
+  )
+}
+
+#[hdk_extern]
+pub fn get_my_joint_records(_: ()) -> ExternResult<Vec<Record>> {
+  query(
+    ChainQueryFilter::new()
+      .entry_type(joint_record_entry_type()?)
+      .include_entries(true),
+  )
+}
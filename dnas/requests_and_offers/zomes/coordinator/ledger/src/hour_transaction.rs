@@ -0,0 +1,330 @@
+use std::collections::HashSet;
+
+use hdk::prelude::*;
+use ledger_integrity::*;
+use utils::{
+  countersigning::{
+    accept_preflight_request, build_create_preflight_request, create_countersigned_entry,
+    get_app_entry,
+  },
+  errors::UtilsError,
+  external_remote_call,
+};
+use WasmErrorInner::*;
+
+use crate::external_calls::{get_agent_user, get_user_agents_links};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RecordHoursInput {
+  /// The user who received the hours given by the current agent's user.
+  pub receiver: ActionHash,
+  pub minutes: u32,
+  pub description: String,
+  pub reference: Option<ActionHash>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct HourTransactionProposal {
+  pub hour_transaction: HourTransaction,
+  pub preflight_request: PreflightRequest,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CommitHourTransactionInput {
+  pub hour_transaction: HourTransaction,
+  pub responses: Vec<PreflightResponse>,
+}
+
+fn get_my_user_hash() -> ExternResult<ActionHash> {
+  get_agent_user(agent_info()?.agent_initial_pubkey)?
+    .first()
+    .and_then(|link| link.target.clone().into_action_hash())
+    .ok_or(wasm_error!(Guest(
+      "You must first create a User profile".to_string()
+    )))
+}
+
+/// Finds the `UserAgents` link of a user, optionally pointing to a given agent.
+fn get_user_agent_link(
+  user_original_action_hash: ActionHash,
+  agent_pubkey: Option<AgentPubKey>,
+) -> ExternResult<Link> {
+  get_user_agents_links(user_original_action_hash)?
+    .into_iter()
+    .find(|link| agent_pubkey.is_none() || link.target.clone().into_agent_pub_key() == agent_pubkey)
+    .ok_or(wasm_error!(Guest(
+      "Could not find an agent for this User".to_string()
+    )))
+}
+
+fn hour_transaction_entry_type() -> ExternResult<EntryType> {
+  let ScopedEntryDefIndex {
+    zome_index,
+    zome_type,
+  } = UnitEntryTypes::HourTransaction.try_into()?;
+
+  Ok(EntryType::App(AppEntryDef::new(
+    zome_type,
+    zome_index,
+    EntryVisibility::Public,
+  )))
+}
+
+/// Records hours given by the current agent's user to another user: the giver is credited and
+/// the receiver debited in a single transaction countersigned by both.
+#[hdk_extern]
+pub fn record_hours(input: RecordHoursInput) -> ExternResult<Record> {
+  let my_pubkey = agent_info()?.agent_initial_pubkey;
+  let my_user_hash = get_my_user_hash()?;
+  let my_agent_link = get_user_agent_link(my_user_hash.clone(), Some(my_pubkey.clone()))?;
+  let receiver_agent_link = get_user_agent_link(input.receiver.clone(), None)?;
+  let receiver_pubkey = receiver_agent_link
+    .target
+    .clone()
+    .into_agent_pub_key()
+    .ok_or(wasm_error!(Guest(
+      "Could not find an agent for this User".to_string()
+    )))?;
+
+  let hour_transaction = HourTransaction {
+    lines: vec![
+      LedgerLine {
+        user: my_user_hash,
+        user_agent_link: my_agent_link.create_link_hash,
+        kind: LedgerLineKind::Credit.to_string(),
+        minutes: input.minutes,
+      },
+      LedgerLine {
+        user: input.receiver,
+        user_agent_link: receiver_agent_link.create_link_hash,
+        kind: LedgerLineKind::Debit.to_string(),
+        minutes: input.minutes,
+      },
+    ],
+    description: input.description,
+    reference: input.reference,
+  };
+
+  let preflight_request = build_create_preflight_request(
+    hash_entry(&hour_transaction)?,
+    hour_transaction_entry_type()?,
+    vec![my_pubkey, receiver_pubkey.clone()],
+  )?;
+
+  let my_response = accept_preflight_request(preflight_request.clone())?;
+  let receiver_response: PreflightResponse = external_remote_call(
+    receiver_pubkey.clone(),
+    "accept_hour_transaction_preflight",
+    "ledger",
+    HourTransactionProposal {
+      hour_transaction: hour_transaction.clone(),
+      preflight_request,
+    },
+  )?;
+
+  let input = CommitHourTransactionInput {
+    hour_transaction,
+    responses: vec![my_response, receiver_response],
+  };
+
+  let hour_transaction_hash = commit_hour_transaction(input.clone())?;
+  let _: ActionHash =
+    external_remote_call(receiver_pubkey, "commit_hour_transaction", "ledger", input)?;
+
+  let record = get(hour_transaction_hash, GetOptions::default())?.ok_or(wasm_error!(Guest(
+    "Could not find the newly created HourTransaction".to_string()
+  )))?;
+
+  Ok(record)
+}
+
+/// Called remotely by the giver: accepts the session if the current agent's user is the one
+/// debited by the proposed transaction.
+#[hdk_extern]
+pub fn accept_hour_transaction_preflight(
+  proposal: HourTransactionProposal,
+) -> ExternResult<PreflightResponse> {
+  if hash_entry(&proposal.hour_transaction)? != proposal.preflight_request.app_entry_hash {
+    return Err(wasm_error!(Guest(
+      "The preflight request does not match the proposed HourTransaction".to_string()
+    )));
+  }
+
+  let my_user_hash = get_my_user_hash()?;
+  let is_debited = proposal
+    .hour_transaction
+    .lines
+    .iter()
+    .any(|line| line.user == my_user_hash && line.kind == LedgerLineKind::Debit.to_string());
+  if !is_debited {
+    return Err(wasm_error!(Guest(
+      "This HourTransaction is not addressed to you".to_string()
+    )));
+  }
+
+  accept_preflight_request(proposal.preflight_request)
+}
+
+/// Commits the transaction on the current agent's source chain once both agents accepted the
+/// session.
+#[hdk_extern]
+pub fn commit_hour_transaction(input: CommitHourTransactionInput) -> ExternResult<ActionHash> {
+  let ScopedEntryDefIndex {
+    zome_index,
+    zome_type,
+  } = UnitEntryTypes::HourTransaction.try_into()?;
+
+  create_countersigned_entry(
+    EntryDefLocation::app(zome_index, zome_type),
+    SerializedBytes::try_from(input.hour_transaction).map_err(|e| wasm_error!(Serialize(e)))?,
+    input.responses,
+  )
+}
+
+#[hdk_extern]
+pub fn get_hour_transaction(
+  hour_transaction_hash: ActionHash,
+) -> ExternResult<Option<HourTransaction>> {
+  let Some(record) = get(hour_transaction_hash, GetOptions::default())? else {
+    return Ok(None);
+  };
+
+  get_app_entry(&record)
+}
+
+/// Returns the transactions committed on the current agent's source chain.
+#[hdk_extern]
+pub fn get_my_hour_transactions(_: ()) -> ExternResult<Vec<Record>> {
+  query(
+    ChainQueryFilter::new()
+      .entry_type(hour_transaction_entry_type()?)
+      .include_entries(true),
+  )
+}
+
+/// Returns the entry hashes of the transactions already in a user's ledger. Each party commits
+/// its own action for the same countersigned entry, so transactions are told apart by entry.
+fn get_user_hour_transactions_entry_hashes(
+  user_original_action_hash: ActionHash,
+) -> ExternResult<HashSet<EntryHash>> {
+  let mut entry_hashes = HashSet::new();
+  for link in get_user_hour_transactions_links(user_original_action_hash)? {
+    let hour_transaction_hash = link
+      .target
+      .into_action_hash()
+      .ok_or(UtilsError::ActionHashNotFound("hour transaction"))?;
+    if let Some(record) = get(hour_transaction_hash, GetOptions::default())? {
+      if let Some(entry_hash) = record.action().entry_hash() {
+        entry_hashes.insert(entry_hash.clone());
+      }
+    }
+  }
+
+  Ok(entry_hashes)
+}
+
+/// Adds a transaction to the ledgers of both of its users, once the countersigning session
+/// completed. Either party can index it with its own action.
+#[hdk_extern]
+pub fn index_hour_transaction(hour_transaction_hash: ActionHash) -> ExternResult<bool> {
+  let record = get(hour_transaction_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
+    Guest("Could not find the HourTransaction".to_string())
+  ))?;
+  let hour_transaction: HourTransaction = get_app_entry(&record)?.ok_or(wasm_error!(Guest(
+    "Could not find the HourTransaction".to_string()
+  )))?;
+  // The hash of the countersigned entry, which the actions of both parties share
+  let entry_hash = record
+    .action()
+    .entry_hash()
+    .ok_or(wasm_error!(Guest(
+      "The HourTransaction action has no entry".to_string()
+    )))?
+    .clone();
+
+  let my_user_hash = get_my_user_hash()?;
+  if !hour_transaction
+    .lines
+    .iter()
+    .any(|line| line.user == my_user_hash)
+  {
+    return Err(wasm_error!(Guest(
+      "Only the users of an HourTransaction can index it".to_string()
+    )));
+  }
+
+  for line in hour_transaction.lines {
+    if get_user_hour_transactions_entry_hashes(line.user.clone())?.contains(&entry_hash) {
+      continue;
+    }
+
+    create_link(
+      line.user,
+      hour_transaction_hash.clone(),
+      LinkTypes::UserHourTransactions,
+      (),
+    )?;
+  }
+
+  Ok(true)
+}
+
+#[hdk_extern]
+pub fn get_user_hour_transactions_links(
+  user_original_action_hash: ActionHash,
+) -> ExternResult<Vec<Link>> {
+  get_links(
+    GetLinksInputBuilder::try_new(user_original_action_hash, LinkTypes::UserHourTransactions)?
+      .build(),
+  )
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UserLedger {
+  /// Minutes given minus minutes received.
+  pub balance_minutes: i64,
+  /// The transactions of the user, oldest first.
+  pub transactions: Vec<Record>,
+}
+
+#[hdk_extern]
+pub fn get_user_ledger(user_original_action_hash: ActionHash) -> ExternResult<UserLedger> {
+  let mut links = get_user_hour_transactions_links(user_original_action_hash.clone())?;
+  links.sort_by_key(|link| link.timestamp);
+
+  let mut balance_minutes = 0;
+  let mut transactions: Vec<Record> = vec![];
+  let mut entry_hashes: HashSet<EntryHash> = HashSet::new();
+  for link in links {
+    let hour_transaction_hash = link
+      .target
+      .into_action_hash()
+      .ok_or(UtilsError::ActionHashNotFound("hour transaction"))?;
+    let Some(record) = get(hour_transaction_hash, GetOptions::default())? else {
+      continue;
+    };
+    // Both parties may have indexed their own action for the same transaction
+    let Some(entry_hash) = record.action().entry_hash() else {
+      continue;
+    };
+    if !entry_hashes.insert(entry_hash.clone()) {
+      continue;
+    }
+    let Some(hour_transaction) = get_app_entry::<HourTransaction>(&record)? else {
+      continue;
+    };
+
+    balance_minutes += hour_transaction
+      .lines
+      .iter()
+      .filter(|line| line.user == user_original_action_hash)
+      .map(LedgerLine::signed_minutes)
+      .sum::<i64>();
+    transactions.push(record);
+  }
+
+  Ok(UserLedger {
+    balance_minutes,
+    transactions,
+  })
+}
@@ -0,0 +1,249 @@
+use hdk::prelude::*;
+use mutual_credit_integrity::*;
+use utils::{
+  countersigning::{
+    accept_preflight_request, build_create_preflight_request, create_countersigned_entry,
+    get_app_entry,
+  },
+  errors::UtilsError,
+  external_remote_call, DnaProperties,
+};
+use WasmErrorInner::*;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SpendCreditsInput {
+  pub recipient: AgentPubKey,
+  pub amount: u32,
+  pub description: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CreditTransactionProposal {
+  pub credit_transaction: CreditTransaction,
+  pub preflight_request: PreflightRequest,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CommitCreditTransactionInput {
+  pub credit_transaction: CreditTransaction,
+  pub responses: Vec<PreflightResponse>,
+}
+
+fn credit_transaction_entry_type() -> ExternResult<EntryType> {
+  let ScopedEntryDefIndex {
+    zome_index,
+    zome_type,
+  } = UnitEntryTypes::CreditTransaction.try_into()?;
+
+  Ok(EntryType::App(AppEntryDef::new(
+    zome_type,
+    zome_index,
+    EntryVisibility::Public,
+  )))
+}
+
+#[hdk_extern]
+pub fn get_credit_limit(_: ()) -> ExternResult<u32> {
+  DnaProperties::get_mutual_credit_limit()
+}
+
+#[hdk_extern]
+pub fn get_my_credit_transactions(_: ()) -> ExternResult<Vec<Record>> {
+  query(
+    ChainQueryFilter::new()
+      .entry_type(credit_transaction_entry_type()?)
+      .include_entries(true),
+  )
+}
+
+/// Returns the balance of the current agent after its latest transaction.
+#[hdk_extern]
+pub fn get_my_balance(_: ()) -> ExternResult<i64> {
+  let Some(record) = get_my_credit_transactions(())?.pop() else {
+    return Ok(0);
+  };
+  let Some(credit_transaction) = get_app_entry::<CreditTransaction>(&record)? else {
+    return Ok(0);
+  };
+
+  Ok(
+    credit_transaction
+      .balance_of(&agent_info()?.agent_initial_pubkey)
+      .unwrap_or(0),
+  )
+}
+
+/// Spends credits to another agent: both running balances are computed from each agent's
+/// latest transaction and the transaction is committed to both chains in a single
+/// countersigning session.
+#[hdk_extern]
+pub fn spend_credits(input: SpendCreditsInput) -> ExternResult<Record> {
+  let my_pubkey = agent_info()?.agent_initial_pubkey;
+  let spender_balance = get_my_balance(())? - input.amount as i64;
+  if spender_balance < -(DnaProperties::get_mutual_credit_limit()? as i64) {
+    return Err(wasm_error!(Guest(
+      "This transaction would push your balance below the credit limit".to_string()
+    )));
+  }
+
+  let recipient_balance: i64 = external_remote_call(
+    input.recipient.clone(),
+    "get_my_balance",
+    "mutual_credit",
+    (),
+  )?;
+
+  let credit_transaction = CreditTransaction {
+    spender: my_pubkey.clone(),
+    recipient: input.recipient.clone(),
+    amount: input.amount,
+    spender_balance,
+    recipient_balance: recipient_balance + input.amount as i64,
+    description: input.description,
+  };
+
+  let preflight_request = build_create_preflight_request(
+    hash_entry(&credit_transaction)?,
+    credit_transaction_entry_type()?,
+    vec![my_pubkey, input.recipient.clone()],
+  )?;
+
+  let my_response = accept_preflight_request(preflight_request.clone())?;
+  let recipient_response: PreflightResponse = external_remote_call(
+    input.recipient.clone(),
+    "accept_credit_transaction_preflight",
+    "mutual_credit",
+    CreditTransactionProposal {
+      credit_transaction: credit_transaction.clone(),
+      preflight_request,
+    },
+  )?;
+
+  let commit_input = CommitCreditTransactionInput {
+    credit_transaction,
+    responses: vec![my_response, recipient_response],
+  };
+
+  let credit_transaction_hash = commit_credit_transaction(commit_input.clone())?;
+  let _: ActionHash = external_remote_call(
+    input.recipient,
+    "commit_credit_transaction",
+    "mutual_credit",
+    commit_input,
+  )?;
+
+  let record = get(credit_transaction_hash, GetOptions::default())?.ok_or(wasm_error!(Guest(
+    "Could not find the newly created CreditTransaction".to_string()
+  )))?;
+
+  Ok(record)
+}
+
+/// Called remotely by the spender: accepts the session if the current agent is the recipient
+/// and its running balance is the expected one.
+#[hdk_extern]
+pub fn accept_credit_transaction_preflight(
+  proposal: CreditTransactionProposal,
+) -> ExternResult<PreflightResponse> {
+  if hash_entry(&proposal.credit_transaction)? != proposal.preflight_request.app_entry_hash {
+    return Err(wasm_error!(Guest(
+      "The preflight request does not match the proposed CreditTransaction".to_string()
+    )));
+  }
+
+  let credit_transaction = &proposal.credit_transaction;
+  if credit_transaction.recipient != agent_info()?.agent_initial_pubkey {
+    return Err(wasm_error!(Guest(
+      "This CreditTransaction is not addressed to you".to_string()
+    )));
+  }
+
+  if credit_transaction.recipient_balance != get_my_balance(())? + credit_transaction.amount as i64
+  {
+    return Err(wasm_error!(Guest(
+      "The running balance of the recipient is outdated".to_string()
+    )));
+  }
+
+  accept_preflight_request(proposal.preflight_request)
+}
+
+/// Commits the transaction on the current agent's source chain once both agents accepted the
+/// session.
+#[hdk_extern]
+pub fn commit_credit_transaction(input: CommitCreditTransactionInput) -> ExternResult<ActionHash> {
+  let ScopedEntryDefIndex {
+    zome_index,
+    zome_type,
+  } = UnitEntryTypes::CreditTransaction.try_into()?;
+
+  create_countersigned_entry(
+    EntryDefLocation::app(zome_index, zome_type),
+    SerializedBytes::try_from(input.credit_transaction).map_err(|e| wasm_error!(Serialize(e)))?,
+    input.responses,
+  )
+}
+
+#[hdk_extern]
+pub fn get_credit_transaction(
+  credit_transaction_hash: ActionHash,
+) -> ExternResult<Option<CreditTransaction>> {
+  let Some(record) = get(credit_transaction_hash, GetOptions::default())? else {
+    return Ok(None);
+  };
+
+  get_app_entry(&record)
+}
+
+/// Adds a transaction to the indexes of both of its agents, once the countersigning session
+/// completed.
+#[hdk_extern]
+pub fn index_credit_transaction(credit_transaction_hash: ActionHash) -> ExternResult<bool> {
+  let credit_transaction = get_credit_transaction(credit_transaction_hash.clone())?.ok_or(
+    wasm_error!(Guest("Could not find the CreditTransaction".to_string())),
+  )?;
+
+  for agent in [credit_transaction.spender, credit_transaction.recipient] {
+    let already_indexed = get_agent_credit_transactions_links(agent.clone())?
+      .iter()
+      .any(|link| link.target.clone().into_action_hash() == Some(credit_transaction_hash.clone()));
+    if already_indexed {
+      continue;
+    }
+
+    create_link(
+      agent,
+      credit_transaction_hash.clone(),
+      LinkTypes::AgentCreditTransactions,
+      (),
+    )?;
+  }
+
+  Ok(true)
+}
+
+#[hdk_extern]
+pub fn get_agent_credit_transactions_links(agent: AgentPubKey) -> ExternResult<Vec<Link>> {
+  get_links(GetLinksInputBuilder::try_new(agent, LinkTypes::AgentCreditTransactions)?.build())
+}
+
+/// Returns the balance of an agent after its latest indexed transaction.
+#[hdk_extern]
+pub fn get_agent_balance(agent: AgentPubKey) -> ExternResult<i64> {
+  let latest_link = get_agent_credit_transactions_links(agent.clone())?
+    .into_iter()
+    .max_by(|link_a, link_b| link_a.timestamp.cmp(&link_b.timestamp));
+  let Some(latest_link) = latest_link else {
+    return Ok(0);
+  };
+
+  let credit_transaction_hash = latest_link
+    .target
+    .into_action_hash()
+    .ok_or(UtilsError::ActionHashNotFound("credit transaction"))?;
+  let credit_transaction = get_credit_transaction(credit_transaction_hash)?.ok_or(wasm_error!(
+    Guest("Could not find the CreditTransaction".to_string())
+  ))?;
+
+  Ok(credit_transaction.balance_of(&agent).unwrap_or(0))
+}
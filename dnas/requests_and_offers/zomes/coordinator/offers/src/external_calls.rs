@@ -0,0 +1,28 @@
+use hdk::prelude::*;
+use utils::{external_local_call, EntityActionHash};
+
+/// Retrieves the links from an agent to its User profile.
+///
+/// # Arguments
+///
+/// * `agent_pubkey` - The public key of the agent.
+///
+/// # Returns
+///
+/// * `ExternResult<Vec<Link>>` - Returns the `MyUser` links of the agent.
+pub fn get_agent_user(agent_pubkey: AgentPubKey) -> ExternResult<Vec<Link>> {
+  external_local_call("get_agent_user", "users_organizations", agent_pubkey)
+}
+
+/// Checks if an entity has an accepted status.
+///
+/// # Arguments
+///
+/// * `input` - An `EntityActionHash` containing the original action hash and entity name.
+///
+/// # Returns
+///
+/// * `ExternResult<bool>` - Returns `true` if the entity is accepted, otherwise `false`.
+pub fn check_if_entity_is_accepted(input: EntityActionHash) -> ExternResult<bool> {
+  external_local_call("check_if_entity_is_accepted", "administration", input)
+}
@@ -0,0 +1,271 @@
+use hdk::prelude::*;
+use offers_integrity::*;
+use utils::{
+  errors::UtilsError, get_all_revisions_for_entry, on_behalf_of::build_on_behalf_of,
+  EntityActionHash,
+};
+use WasmErrorInner::*;
+
+use crate::external_calls::{check_if_entity_is_accepted, get_agent_user};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct OfferInput {
+  pub offer: Offer,
+  pub organization_hash: Option<ActionHash>,
+}
+
+#[hdk_extern]
+pub fn create_offer(input: OfferInput) -> ExternResult<Record> {
+  let user_links = get_agent_user(agent_info()?.agent_initial_pubkey)?;
+  if user_links.is_empty() {
+    return Err(wasm_error!(Guest(
+      "You must first create a User profile".to_string()
+    )));
+  }
+
+  let user_hash = user_links[0]
+    .target
+    .clone()
+    .into_action_hash()
+    .ok_or(UtilsError::ActionHashNotFound("user"))?;
+
+  if !check_if_entity_is_accepted(EntityActionHash {
+    entity_original_action_hash: user_hash.clone(),
+    entity: "users".to_string(),
+  })? {
+    return Err(wasm_error!(Guest(
+      "Your User profile must be accepted to create offers".to_string()
+    )));
+  }
+
+  let on_behalf_of = input
+    .organization_hash
+    .clone()
+    .map(build_on_behalf_of)
+    .transpose()?;
+
+  let offer_hash = create_entry(&EntryTypes::Offer(Offer {
+    on_behalf_of,
+    ..input.offer
+  }))?;
+  let record = get(offer_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(Guest(
+    "Could not find the newly created Offer".to_string()
+  )))?;
+
+  let path = Path::from("offers");
+  create_link(
+    path.path_entry_hash()?,
+    offer_hash.clone(),
+    LinkTypes::AllOffers,
+    (),
+  )?;
+
+  create_link(user_hash, offer_hash.clone(), LinkTypes::UserOffers, ())?;
+
+  if let Some(organization_hash) = input.organization_hash {
+    create_link(
+      organization_hash.clone(),
+      offer_hash.clone(),
+      LinkTypes::OrganizationOffers,
+      (),
+    )?;
+
+    create_link(
+      offer_hash,
+      organization_hash,
+      LinkTypes::OfferOrganization,
+      (),
+    )?;
+  }
+
+  Ok(record)
+}
+
+#[hdk_extern]
+pub fn get_latest_offer_record(original_action_hash: ActionHash) -> ExternResult<Option<Record>> {
+  let links = get_links(
+    GetLinksInputBuilder::try_new(original_action_hash.clone(), LinkTypes::OfferUpdates)?.build(),
+  )?;
+  let latest_link = links
+    .into_iter()
+    .max_by(|link_a, link_b| link_a.timestamp.cmp(&link_b.timestamp));
+  let latest_offer_hash = match latest_link {
+    Some(link) => link
+      .target
+      .clone()
+      .into_action_hash()
+      .ok_or(UtilsError::ActionHashNotFound("offer"))?,
+    None => original_action_hash.clone(),
+  };
+  get(latest_offer_hash, GetOptions::default())
+}
+
+#[hdk_extern]
+pub fn get_latest_offer(original_action_hash: ActionHash) -> ExternResult<Offer> {
+  let latest_offer_record = get_latest_offer_record(original_action_hash)?;
+  let latest_offer: Offer = latest_offer_record
+    .ok_or(wasm_error!(Guest(
+      "Could not find the latest Offer".to_string()
+    )))?
+    .entry()
+    .to_app_option()
+    .map_err(|e| wasm_error!(Serialize(e)))?
+    .ok_or(wasm_error!(Guest(
+      "Could not find the latest Offer".to_string()
+    )))?;
+
+  Ok(latest_offer)
+}
+
+#[hdk_extern]
+pub fn get_all_revisions_for_offer(original_action_hash: ActionHash) -> ExternResult<Vec<Record>> {
+  get_all_revisions_for_entry(original_action_hash, LinkTypes::OfferUpdates)
+}
+
+#[hdk_extern]
+pub fn get_all_offers_links(_: ()) -> ExternResult<Vec<Link>> {
+  let path = Path::from("offers");
+  get_links(GetLinksInputBuilder::try_new(path.path_entry_hash()?, LinkTypes::AllOffers)?.build())
+}
+
+#[hdk_extern]
+pub fn get_user_offers_links(user_original_action_hash: ActionHash) -> ExternResult<Vec<Link>> {
+  get_links(
+    GetLinksInputBuilder::try_new(user_original_action_hash, LinkTypes::UserOffers)?.build(),
+  )
+}
+
+#[hdk_extern]
+pub fn get_organization_offers_links(
+  organization_original_action_hash: ActionHash,
+) -> ExternResult<Vec<Link>> {
+  get_links(
+    GetLinksInputBuilder::try_new(
+      organization_original_action_hash,
+      LinkTypes::OrganizationOffers,
+    )?
+    .build(),
+  )
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UpdateOfferInput {
+  pub original_action_hash: ActionHash,
+  pub previous_action_hash: ActionHash,
+  pub updated_offer: Offer,
+}
+
+#[hdk_extern]
+pub fn update_offer(input: UpdateOfferInput) -> ExternResult<Record> {
+  let original_record = must_get_valid_record(input.original_action_hash.clone())?;
+
+  let author = original_record.action().author().clone();
+  if author != agent_info()?.agent_initial_pubkey {
+    return Err(wasm_error!(Guest(
+      "Only the author of an Offer can update it".to_string()
+    )));
+  }
+
+  let original_offer: Offer = original_record
+    .entry()
+    .to_app_option()
+    .map_err(|e| wasm_error!(Serialize(e)))?
+    .ok_or(wasm_error!(Guest(
+      "Could not find the original Offer".to_string()
+    )))?;
+
+  let updated_offer_hash = update_entry(
+    input.previous_action_hash.clone(),
+    &Offer {
+      on_behalf_of: original_offer.on_behalf_of,
+      ..input.updated_offer
+    },
+  )?;
+
+  create_link(
+    input.original_action_hash.clone(),
+    updated_offer_hash.clone(),
+    LinkTypes::OfferUpdates,
+    (),
+  )?;
+
+  let record = get(updated_offer_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
+    Guest("Could not find the newly updated Offer".to_string())
+  ))?;
+
+  Ok(record)
+}
+
+#[hdk_extern]
+pub fn delete_offer(offer_original_action_hash: ActionHash) -> ExternResult<bool> {
+  let original_record = must_get_valid_record(offer_original_action_hash.clone())?;
+
+  let author = original_record.action().author().clone();
+  if author != agent_info()?.agent_initial_pubkey {
+    return Err(wasm_error!(Guest(
+      "Only the author of an Offer can delete it".to_string()
+    )));
+  }
+
+  // Delete the global index link
+  let all_offers_links = get_all_offers_links(())?;
+  for link in all_offers_links {
+    if let Some(hash) = link.target.clone().into_action_hash() {
+      if hash == offer_original_action_hash {
+        delete_link(link.create_link_hash)?;
+        break;
+      }
+    }
+  }
+
+  // Delete the author's index link
+  let user_links = get_agent_user(author)?;
+  if let Some(user_link) = user_links.first() {
+    let user_hash = user_link
+      .target
+      .clone()
+      .into_action_hash()
+      .ok_or(UtilsError::ActionHashNotFound("user"))?;
+
+    for link in get_user_offers_links(user_hash)? {
+      if let Some(hash) = link.target.clone().into_action_hash() {
+        if hash == offer_original_action_hash {
+          delete_link(link.create_link_hash)?;
+          break;
+        }
+      }
+    }
+  }
+
+  // Delete the organization links if the offer was made on behalf of one
+  let offer_organization_links = get_links(
+    GetLinksInputBuilder::try_new(
+      offer_original_action_hash.clone(),
+      LinkTypes::OfferOrganization,
+    )?
+    .build(),
+  )?;
+
+  for offer_organization_link in offer_organization_links {
+    let organization_hash = offer_organization_link
+      .target
+      .clone()
+      .into_action_hash()
+      .ok_or(UtilsError::ActionHashNotFound("organization"))?;
+
+    for link in get_organization_offers_links(organization_hash)? {
+      if let Some(hash) = link.target.clone().into_action_hash() {
+        if hash == offer_original_action_hash {
+          delete_link(link.create_link_hash)?;
+          break;
+        }
+      }
+    }
+
+    delete_link(offer_organization_link.create_link_hash)?;
+  }
+
+  delete_entry(offer_original_action_hash)?;
+
+  Ok(true)
+}
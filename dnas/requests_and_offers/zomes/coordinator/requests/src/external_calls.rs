@@ -0,0 +1,15 @@
+use hdk::prelude::*;
+use utils::external_local_call;
+
+/// Retrieves the links from an agent to its User profile.
+///
+/// # Arguments
+///
+/// * `agent_pubkey` - The public key of the agent.
+///
+/// # Returns
+///
+/// * `ExternResult<Vec<Link>>` - Returns the `MyUser` links of the agent.
+pub fn get_agent_user(agent_pubkey: AgentPubKey) -> ExternResult<Vec<Link>> {
+  external_local_call("get_agent_user", "users_organizations", agent_pubkey)
+}
@@ -0,0 +1,260 @@
+use hdk::prelude::*;
+use requests_integrity::*;
+use utils::{errors::UtilsError, get_all_revisions_for_entry, on_behalf_of::build_on_behalf_of};
+use WasmErrorInner::*;
+
+use crate::external_calls::get_agent_user;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RequestInput {
+  pub request: Request,
+  pub organization_hash: Option<ActionHash>,
+}
+
+#[hdk_extern]
+pub fn create_request(input: RequestInput) -> ExternResult<Record> {
+  let user_links = get_agent_user(agent_info()?.agent_initial_pubkey)?;
+  if user_links.is_empty() {
+    return Err(wasm_error!(Guest(
+      "You must first create a User profile".to_string()
+    )));
+  }
+
+  let on_behalf_of = input
+    .organization_hash
+    .clone()
+    .map(build_on_behalf_of)
+    .transpose()?;
+
+  let request_hash = create_entry(&EntryTypes::Request(Request {
+    on_behalf_of,
+    ..input.request
+  }))?;
+  let record = get(request_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(Guest(
+    "Could not find the newly created Request".to_string()
+  )))?;
+
+  let path = Path::from("requests");
+  create_link(
+    path.path_entry_hash()?,
+    request_hash.clone(),
+    LinkTypes::AllRequests,
+    (),
+  )?;
+
+  create_link(
+    user_links[0].target.clone(),
+    request_hash.clone(),
+    LinkTypes::UserRequests,
+    (),
+  )?;
+
+  if let Some(organization_hash) = input.organization_hash {
+    create_link(
+      organization_hash.clone(),
+      request_hash.clone(),
+      LinkTypes::OrganizationRequests,
+      (),
+    )?;
+
+    create_link(
+      request_hash,
+      organization_hash,
+      LinkTypes::RequestOrganization,
+      (),
+    )?;
+  }
+
+  Ok(record)
+}
+
+#[hdk_extern]
+pub fn get_latest_request_record(original_action_hash: ActionHash) -> ExternResult<Option<Record>> {
+  let links = get_links(
+    GetLinksInputBuilder::try_new(original_action_hash.clone(), LinkTypes::RequestUpdates)?.build(),
+  )?;
+  let latest_link = links
+    .into_iter()
+    .max_by(|link_a, link_b| link_a.timestamp.cmp(&link_b.timestamp));
+  let latest_request_hash = match latest_link {
+    Some(link) => link
+      .target
+      .clone()
+      .into_action_hash()
+      .ok_or(UtilsError::ActionHashNotFound("request"))?,
+    None => original_action_hash.clone(),
+  };
+  get(latest_request_hash, GetOptions::default())
+}
+
+#[hdk_extern]
+pub fn get_latest_request(original_action_hash: ActionHash) -> ExternResult<Request> {
+  let latest_request_record = get_latest_request_record(original_action_hash)?;
+  let latest_request: Request = latest_request_record
+    .ok_or(wasm_error!(Guest(
+      "Could not find the latest Request".to_string()
+    )))?
+    .entry()
+    .to_app_option()
+    .map_err(|e| wasm_error!(Serialize(e)))?
+    .ok_or(wasm_error!(Guest(
+      "Could not find the latest Request".to_string()
+    )))?;
+
+  Ok(latest_request)
+}
+
+#[hdk_extern]
+pub fn get_all_revisions_for_request(
+  original_action_hash: ActionHash,
+) -> ExternResult<Vec<Record>> {
+  get_all_revisions_for_entry(original_action_hash, LinkTypes::RequestUpdates)
+}
+
+#[hdk_extern]
+pub fn get_all_requests_links(_: ()) -> ExternResult<Vec<Link>> {
+  let path = Path::from("requests");
+  get_links(GetLinksInputBuilder::try_new(path.path_entry_hash()?, LinkTypes::AllRequests)?.build())
+}
+
+#[hdk_extern]
+pub fn get_user_requests_links(user_original_action_hash: ActionHash) -> ExternResult<Vec<Link>> {
+  get_links(
+    GetLinksInputBuilder::try_new(user_original_action_hash, LinkTypes::UserRequests)?.build(),
+  )
+}
+
+#[hdk_extern]
+pub fn get_organization_requests_links(
+  organization_original_action_hash: ActionHash,
+) -> ExternResult<Vec<Link>> {
+  get_links(
+    GetLinksInputBuilder::try_new(
+      organization_original_action_hash,
+      LinkTypes::OrganizationRequests,
+    )?
+    .build(),
+  )
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UpdateRequestInput {
+  pub original_action_hash: ActionHash,
+  pub previous_action_hash: ActionHash,
+  pub updated_request: Request,
+}
+
+#[hdk_extern]
+pub fn update_request(input: UpdateRequestInput) -> ExternResult<Record> {
+  let original_record = must_get_valid_record(input.original_action_hash.clone())?;
+
+  let author = original_record.action().author().clone();
+  if author != agent_info()?.agent_initial_pubkey {
+    return Err(wasm_error!(Guest(
+      "Only the author of a Request can update it".to_string()
+    )));
+  }
+
+  let original_request: Request = original_record
+    .entry()
+    .to_app_option()
+    .map_err(|e| wasm_error!(Serialize(e)))?
+    .ok_or(wasm_error!(Guest(
+      "Could not find the original Request".to_string()
+    )))?;
+
+  let updated_request_hash = update_entry(
+    input.previous_action_hash.clone(),
+    &Request {
+      on_behalf_of: original_request.on_behalf_of,
+      ..input.updated_request
+    },
+  )?;
+
+  create_link(
+    input.original_action_hash.clone(),
+    updated_request_hash.clone(),
+    LinkTypes::RequestUpdates,
+    (),
+  )?;
+
+  let record = get(updated_request_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
+    Guest("Could not find the newly updated Request".to_string())
+  ))?;
+
+  Ok(record)
+}
+
+#[hdk_extern]
+pub fn delete_request(request_original_action_hash: ActionHash) -> ExternResult<bool> {
+  let original_record = must_get_valid_record(request_original_action_hash.clone())?;
+
+  let author = original_record.action().author().clone();
+  if author != agent_info()?.agent_initial_pubkey {
+    return Err(wasm_error!(Guest(
+      "Only the author of a Request can delete it".to_string()
+    )));
+  }
+
+  // Delete the global index link
+  let all_requests_links = get_all_requests_links(())?;
+  for link in all_requests_links {
+    if let Some(hash) = link.target.clone().into_action_hash() {
+      if hash == request_original_action_hash {
+        delete_link(link.create_link_hash)?;
+        break;
+      }
+    }
+  }
+
+  // Delete the author's index link
+  let user_links = get_agent_user(author)?;
+  if let Some(user_link) = user_links.first() {
+    let user_hash = user_link
+      .target
+      .clone()
+      .into_action_hash()
+      .ok_or(UtilsError::ActionHashNotFound("user"))?;
+
+    for link in get_user_requests_links(user_hash)? {
+      if let Some(hash) = link.target.clone().into_action_hash() {
+        if hash == request_original_action_hash {
+          delete_link(link.create_link_hash)?;
+          break;
+        }
+      }
+    }
+  }
+
+  // Delete the organization links if the request was made on behalf of one
+  let request_organization_links = get_links(
+    GetLinksInputBuilder::try_new(
+      request_original_action_hash.clone(),
+      LinkTypes::RequestOrganization,
+    )?
+    .build(),
+  )?;
+
+  for request_organization_link in request_organization_links {
+    let organization_hash = request_organization_link
+      .target
+      .clone()
+      .into_action_hash()
+      .ok_or(UtilsError::ActionHashNotFound("organization"))?;
+
+    for link in get_organization_requests_links(organization_hash)? {
+      if let Some(hash) = link.target.clone().into_action_hash() {
+        if hash == request_original_action_hash {
+          delete_link(link.create_link_hash)?;
+          break;
+        }
+      }
+    }
+
+    delete_link(request_organization_link.create_link_hash)?;
+  }
+
+  delete_entry(request_original_action_hash)?;
+
+  Ok(true)
+}
@@ -0,0 +1,53 @@
+use hdk::prelude::*;
+use utils::external_local_call;
+
+/// Retrieves the links from an agent to its User profile.
+///
+/// # Arguments
+///
+/// * `agent_pubkey` - The public key of the agent.
+///
+/// # Returns
+///
+/// * `ExternResult<Vec<Link>>` - Returns the `MyUser` links of the agent.
+pub fn get_agent_user(agent_pubkey: AgentPubKey) -> ExternResult<Vec<Link>> {
+  external_local_call("get_agent_user", "users_organizations", agent_pubkey)
+}
+
+/// Checks if the current agent is a coordinator of a given organization.
+///
+/// # Arguments
+///
+/// * `organization_original_action_hash` - The original action hash of the organization.
+///
+/// # Returns
+///
+/// * `ExternResult<bool>` - Returns `true` if the agent is a coordinator, otherwise `false`.
+pub fn check_if_agent_is_organization_coordinator(
+  organization_original_action_hash: ActionHash,
+) -> ExternResult<bool> {
+  external_local_call(
+    "check_if_agent_is_organization_coordinator",
+    "users_organizations",
+    organization_original_action_hash,
+  )
+}
+
+/// Retrieves the latest record of a User profile.
+///
+/// # Arguments
+///
+/// * `user_original_action_hash` - The original action hash of the user.
+///
+/// # Returns
+///
+/// * `ExternResult<Option<Record>>` - Returns the latest User record, if any.
+pub fn get_latest_user_record(
+  user_original_action_hash: ActionHash,
+) -> ExternResult<Option<Record>> {
+  external_local_call(
+    "get_latest_user_record",
+    "users_organizations",
+    user_original_action_hash,
+  )
+}
@@ -0,0 +1,321 @@
+use hdk::prelude::*;
+use responses_integrity::*;
+use utils::{
+  errors::UtilsError,
+  on_behalf_of::{build_on_behalf_of, OnBehalfOf},
+};
+use WasmErrorInner::*;
+
+use crate::external_calls::{
+  check_if_agent_is_organization_coordinator, get_agent_user, get_latest_user_record,
+};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ResponseInput {
+  pub item_original_action_hash: ActionHash,
+  pub message: String,
+}
+
+#[hdk_extern]
+pub fn create_response(input: ResponseInput) -> ExternResult<Record> {
+  let user_links = get_agent_user(agent_info()?.agent_initial_pubkey)?;
+  if user_links.is_empty() {
+    return Err(wasm_error!(Guest(
+      "You must first create a User profile".to_string()
+    )));
+  }
+
+  let item_record = get(
+    input.item_original_action_hash.clone(),
+    GetOptions::default(),
+  )?
+  .ok_or(wasm_error!(Guest(
+    "Could not find the item to respond to".to_string()
+  )))?;
+
+  if *item_record.action().author() == agent_info()?.agent_initial_pubkey {
+    return Err(wasm_error!(Guest(
+      "You cannot respond to your own item".to_string()
+    )));
+  }
+
+  let response = Response {
+    item_original_action_hash: input.item_original_action_hash.clone(),
+    message: input.message,
+    status: ResponseStatus::Pending.to_string(),
+    decided_on_behalf_of: None,
+  };
+
+  let response_hash = create_entry(&EntryTypes::Response(response))?;
+  let record = get(response_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(Guest(
+    "Could not find the newly created Response".to_string()
+  )))?;
+
+  create_link(
+    input.item_original_action_hash,
+    response_hash.clone(),
+    LinkTypes::ItemResponses,
+    (),
+  )?;
+
+  create_link(
+    user_links[0].target.clone(),
+    response_hash,
+    LinkTypes::UserResponses,
+    (),
+  )?;
+
+  Ok(record)
+}
+
+#[hdk_extern]
+pub fn get_latest_response_record(
+  original_action_hash: ActionHash,
+) -> ExternResult<Option<Record>> {
+  let links = get_links(
+    GetLinksInputBuilder::try_new(original_action_hash.clone(), LinkTypes::ResponseUpdates)?
+      .build(),
+  )?;
+  let latest_link = links
+    .into_iter()
+    .max_by(|link_a, link_b| link_a.timestamp.cmp(&link_b.timestamp));
+  let latest_response_hash = match latest_link {
+    Some(link) => link
+      .target
+      .clone()
+      .into_action_hash()
+      .ok_or(UtilsError::ActionHashNotFound("response"))?,
+    None => original_action_hash.clone(),
+  };
+  get(latest_response_hash, GetOptions::default())
+}
+
+#[hdk_extern]
+pub fn get_latest_response(original_action_hash: ActionHash) -> ExternResult<Response> {
+  let latest_response_record = get_latest_response_record(original_action_hash)?;
+  let latest_response: Response = latest_response_record
+    .ok_or(wasm_error!(Guest(
+      "Could not find the latest Response".to_string()
+    )))?
+    .entry()
+    .to_app_option()
+    .map_err(|e| wasm_error!(Serialize(e)))?
+    .ok_or(wasm_error!(Guest(
+      "Could not find the latest Response".to_string()
+    )))?;
+
+  Ok(latest_response)
+}
+
+#[hdk_extern]
+pub fn get_item_responses_links(item_original_action_hash: ActionHash) -> ExternResult<Vec<Link>> {
+  get_links(
+    GetLinksInputBuilder::try_new(item_original_action_hash, LinkTypes::ItemResponses)?.build(),
+  )
+}
+
+#[hdk_extern]
+pub fn get_user_responses_links(user_original_action_hash: ActionHash) -> ExternResult<Vec<Link>> {
+  get_links(
+    GetLinksInputBuilder::try_new(user_original_action_hash, LinkTypes::UserResponses)?.build(),
+  )
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ResponseWithUser {
+  pub response_original_action_hash: ActionHash,
+  pub response: Response,
+  pub user_original_action_hash: ActionHash,
+  pub user_record: Option<Record>,
+}
+
+#[hdk_extern]
+pub fn get_item_responses(
+  item_original_action_hash: ActionHash,
+) -> ExternResult<Vec<ResponseWithUser>> {
+  let links = get_item_responses_links(item_original_action_hash)?;
+
+  links
+    .into_iter()
+    .map(|link| {
+      let response_original_action_hash = link
+        .target
+        .clone()
+        .into_action_hash()
+        .ok_or(UtilsError::ActionHashNotFound("response"))?;
+
+      let user_links = get_agent_user(link.author.clone())?;
+      let user_original_action_hash = user_links
+        .first()
+        .and_then(|link| link.target.clone().into_action_hash())
+        .ok_or(UtilsError::ActionHashNotFound("user"))?;
+
+      Ok(ResponseWithUser {
+        response: get_latest_response(response_original_action_hash.clone())?,
+        response_original_action_hash,
+        user_record: get_latest_user_record(user_original_action_hash.clone())?,
+        user_original_action_hash,
+      })
+    })
+    .collect::<ExternResult<Vec<ResponseWithUser>>>()
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UpdateResponseInput {
+  pub original_action_hash: ActionHash,
+  pub previous_action_hash: ActionHash,
+  pub updated_response: Response,
+}
+
+#[hdk_extern]
+pub fn update_response(input: UpdateResponseInput) -> ExternResult<Record> {
+  let original_record = must_get_valid_record(input.original_action_hash.clone())?;
+
+  let author = original_record.action().author().clone();
+  if author != agent_info()?.agent_initial_pubkey {
+    return Err(wasm_error!(Guest(
+      "Only the author of a Response can update it".to_string()
+    )));
+  }
+
+  let updated_response_hash =
+    update_entry(input.previous_action_hash.clone(), &input.updated_response)?;
+
+  create_link(
+    input.original_action_hash.clone(),
+    updated_response_hash.clone(),
+    LinkTypes::ResponseUpdates,
+    (),
+  )?;
+
+  let record = get(updated_response_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
+    Guest("Could not find the newly updated Response".to_string())
+  ))?;
+
+  Ok(record)
+}
+
+/// Builds the provenance of a decision on responses about an item. The author of the item
+/// decides for itself, while a coordinator decides on behalf of the item's organization.
+fn build_decision_on_behalf_of(
+  item_original_action_hash: ActionHash,
+) -> ExternResult<Option<OnBehalfOf>> {
+  let item_record = get(item_original_action_hash.clone(), GetOptions::default())?.ok_or(
+    wasm_error!(Guest("Could not find the item of the Response".to_string())),
+  )?;
+
+  if *item_record.action().author() == agent_info()?.agent_initial_pubkey {
+    return Ok(None);
+  }
+
+  if !check_if_agent_is_organization_coordinator(item_original_action_hash.clone())? {
+    return Err(wasm_error!(Guest(
+      "Only the author or the coordinators of an item can accept or decline its responses"
+        .to_string()
+    )));
+  }
+
+  build_on_behalf_of(item_original_action_hash).map(Some)
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ResponseDecisionInput {
+  pub original_action_hash: ActionHash,
+  pub previous_action_hash: ActionHash,
+}
+
+fn decide_response(input: ResponseDecisionInput, status: ResponseStatus) -> ExternResult<Record> {
+  let response = get_latest_response(input.original_action_hash.clone())?;
+
+  let decided_on_behalf_of =
+    build_decision_on_behalf_of(response.item_original_action_hash.clone())?;
+
+  if response.status != ResponseStatus::Pending.to_string() {
+    return Err(wasm_error!(Guest(
+      "This Response has already been answered".to_string()
+    )));
+  }
+
+  let updated_response_hash = update_entry(
+    input.previous_action_hash,
+    &Response {
+      status: status.to_string(),
+      decided_on_behalf_of,
+      ..response
+    },
+  )?;
+
+  create_link(
+    input.original_action_hash,
+    updated_response_hash.clone(),
+    LinkTypes::ResponseUpdates,
+    (),
+  )?;
+
+  let record = get(updated_response_hash, GetOptions::default())?.ok_or(wasm_error!(Guest(
+    "Could not find the newly updated Response".to_string()
+  )))?;
+
+  Ok(record)
+}
+
+#[hdk_extern]
+pub fn accept_response(input: ResponseDecisionInput) -> ExternResult<Record> {
+  decide_response(input, ResponseStatus::Accepted)
+}
+
+#[hdk_extern]
+pub fn decline_response(input: ResponseDecisionInput) -> ExternResult<Record> {
+  decide_response(input, ResponseStatus::Declined)
+}
+
+#[hdk_extern]
+pub fn delete_response(response_original_action_hash: ActionHash) -> ExternResult<bool> {
+  let original_record = must_get_valid_record(response_original_action_hash.clone())?;
+
+  let author = original_record.action().author().clone();
+  if author != agent_info()?.agent_initial_pubkey {
+    return Err(wasm_error!(Guest(
+      "Only the author of a Response can delete it".to_string()
+    )));
+  }
+
+  let response: Response = original_record
+    .entry()
+    .to_app_option()
+    .map_err(|e| wasm_error!(Serialize(e)))?
+    .ok_or(wasm_error!(Guest(
+      "Could not find the Response".to_string()
+    )))?;
+
+  for link in get_item_responses_links(response.item_original_action_hash)? {
+    if let Some(hash) = link.target.clone().into_action_hash() {
+      if hash == response_original_action_hash {
+        delete_link(link.create_link_hash)?;
+        break;
+      }
+    }
+  }
+
+  let user_links = get_agent_user(author)?;
+  if let Some(user_link) = user_links.first() {
+    let user_hash = user_link
+      .target
+      .clone()
+      .into_action_hash()
+      .ok_or(UtilsError::ActionHashNotFound("user"))?;
+
+    for link in get_user_responses_links(user_hash)? {
+      if let Some(hash) = link.target.clone().into_action_hash() {
+        if hash == response_original_action_hash {
+          delete_link(link.create_link_hash)?;
+          break;
+        }
+      }
+    }
+  }
+
+  delete_entry(response_original_action_hash)?;
+
+  Ok(true)
+}
@@ -0,0 +1,43 @@
+use hdk::prelude::*;
+use utils::{external_local_call, EntityAgent};
+
+/// Retrieves the agents acting on behalf of a User profile.
+///
+/// # Arguments
+///
+/// * `user_original_action_hash` - The original action hash of the user.
+///
+/// # Returns
+///
+/// * `ExternResult<Vec<AgentPubKey>>` - Returns the public keys of the user's agents.
+pub fn get_user_agents(user_original_action_hash: ActionHash) -> ExternResult<Vec<AgentPubKey>> {
+  external_local_call(
+    "get_user_agents",
+    "users_organizations",
+    user_original_action_hash,
+  )
+}
+
+/// Checks if a given agent is an administrator for a specified entity.
+///
+/// # Arguments
+///
+/// * `entity` - The name of the entity, such as "network".
+/// * `agent_pubkey` - The public key of the agent to check.
+///
+/// # Returns
+///
+/// * `ExternResult<bool>` - Returns `true` if the agent is an administrator, otherwise `false`.
+pub fn check_if_agent_is_administrator(
+  entity: &str,
+  agent_pubkey: AgentPubKey,
+) -> ExternResult<bool> {
+  external_local_call(
+    "check_if_agent_is_administrator",
+    "administration",
+    EntityAgent {
+      entity: entity.to_string(),
+      agent_pubkey,
+    },
+  )
+}
@@ -0,0 +1,383 @@
+use hdk::prelude::*;
+use skills_integrity::*;
+use utils::errors::UtilsError;
+use WasmErrorInner::*;
+
+use crate::external_calls::{check_if_agent_is_administrator, get_user_agents};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SkillAliasesInput {
+  /// The name of the canonical skill.
+  pub skill: String,
+  /// The names resolving to the canonical skill.
+  pub aliases: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UserSkillsInput {
+  pub user_original_action_hash: ActionHash,
+  /// The names of the skills, resolved to canonical skills.
+  pub skills: Vec<String>,
+}
+
+fn all_skills_path_hash() -> ExternResult<EntryHash> {
+  Path::from("skills").path_entry_hash()
+}
+
+#[hdk_extern]
+pub fn get_all_skills_links(_: ()) -> ExternResult<Vec<Link>> {
+  get_links(GetLinksInputBuilder::try_new(all_skills_path_hash()?, LinkTypes::AllSkills)?.build())
+}
+
+/// Returns the `AllSkills` link of the skill with exactly the given normalized name.
+fn find_skill_link(name: &str) -> ExternResult<Option<Link>> {
+  let links = get_links(
+    GetLinksInputBuilder::try_new(all_skills_path_hash()?, LinkTypes::AllSkills)?
+      .tag_prefix(LinkTag::new(name))
+      .build(),
+  )?;
+
+  // The same skill may have been created concurrently, the oldest one is the canonical one
+  Ok(
+    links
+      .into_iter()
+      .filter(|link| link.tag.clone().into_inner() == name.as_bytes())
+      .min_by(|link_a, link_b| link_a.timestamp.cmp(&link_b.timestamp)),
+  )
+}
+
+fn create_skill(name: String) -> ExternResult<ActionHash> {
+  let skill_hash = create_entry(&EntryTypes::Skill(Skill { name: name.clone() }))?;
+
+  create_link(
+    all_skills_path_hash()?,
+    skill_hash.clone(),
+    LinkTypes::AllSkills,
+    LinkTag::new(name),
+  )?;
+
+  Ok(skill_hash)
+}
+
+/// Resolves a skill name to its Skill without following aliases, creating it if needed.
+fn find_or_create_skill(name: &str) -> ExternResult<ActionHash> {
+  let name = normalize_skill_name(name);
+  if name.is_empty() {
+    return Err(wasm_error!(Guest("Skill name cannot be empty".to_string())));
+  }
+
+  match find_skill_link(&name)? {
+    Some(link) => Ok(
+      link
+        .target
+        .into_action_hash()
+        .ok_or(UtilsError::ActionHashNotFound("skill"))?,
+    ),
+    None => create_skill(name),
+  }
+}
+
+/// Returns the `AllSkills` link of the skill with the given name, if any, following the alias
+/// table to the canonical skill. Its tag holds the canonical name of the skill.
+#[hdk_extern]
+pub fn get_skill_link(name: String) -> ExternResult<Option<Link>> {
+  let name = normalize_skill_name(&name);
+  if name.is_empty() {
+    return Ok(None);
+  }
+
+  let Some(link) = find_skill_link(&name)? else {
+    return Ok(None);
+  };
+  let skill_hash = link
+    .target
+    .clone()
+    .into_action_hash()
+    .ok_or(UtilsError::ActionHashNotFound("skill"))?;
+
+  let Some(canonical_skill_link) = get_canonical_skill_link(skill_hash)? else {
+    return Ok(Some(link));
+  };
+  let canonical_skill_hash = canonical_skill_link
+    .target
+    .into_action_hash()
+    .ok_or(UtilsError::ActionHashNotFound("skill"))?;
+  let canonical_skill: Skill = get_skill(canonical_skill_hash)?
+    .ok_or(wasm_error!(Guest(
+      "Could not find the canonical Skill".to_string()
+    )))?
+    .entry()
+    .to_app_option()
+    .map_err(|e| wasm_error!(Serialize(e)))?
+    .ok_or(wasm_error!(Guest(
+      "Could not find the canonical Skill".to_string()
+    )))?;
+
+  find_skill_link(&canonical_skill.name)
+}
+
+#[hdk_extern]
+pub fn get_skill(skill_action_hash: ActionHash) -> ExternResult<Option<Record>> {
+  get(skill_action_hash, GetOptions::default())
+}
+
+/// Resolves a skill name to the action hash of its canonical Skill, creating it if needed.
+#[hdk_extern]
+pub fn get_or_create_skill(name: String) -> ExternResult<ActionHash> {
+  match get_skill_link(name.clone())? {
+    Some(link) => Ok(
+      link
+        .target
+        .into_action_hash()
+        .ok_or(UtilsError::ActionHashNotFound("skill"))?,
+    ),
+    None => find_or_create_skill(&name),
+  }
+}
+
+/// Returns the name of the canonical skill of a skill name. Names without a Skill are only
+/// normalized.
+fn get_canonical_skill_name(name: String) -> ExternResult<String> {
+  match get_skill_link(name.clone())? {
+    Some(link) => String::from_utf8(link.tag.into_inner())
+      .map_err(|_| wasm_error!(Guest("Invalid skill name".to_string()))),
+    None => Ok(normalize_skill_name(&name)),
+  }
+}
+
+/// Maps skill names to the names of their canonical skills, dropping duplicates. Names
+/// without a Skill are only normalized.
+#[hdk_extern]
+pub fn get_canonical_skill_names(names: Vec<String>) -> ExternResult<Vec<String>> {
+  let mut canonical_names: Vec<String> = vec![];
+  for name in names {
+    let canonical_name = get_canonical_skill_name(name)?;
+    if !canonical_name.is_empty() && !canonical_names.contains(&canonical_name) {
+      canonical_names.push(canonical_name);
+    }
+  }
+
+  Ok(canonical_names)
+}
+
+/// Maps each skill name to the name of its canonical skill, keeping the order and the
+/// duplicates of the given names so that callers can match them one to one.
+#[hdk_extern]
+pub fn map_canonical_skill_names(names: Vec<String>) -> ExternResult<Vec<String>> {
+  names.into_iter().map(get_canonical_skill_name).collect()
+}
+
+#[hdk_extern]
+pub fn get_user_skills_links(user_original_action_hash: ActionHash) -> ExternResult<Vec<Link>> {
+  get_links(
+    GetLinksInputBuilder::try_new(user_original_action_hash, LinkTypes::UserSkills)?.build(),
+  )
+}
+
+fn get_direct_skill_users_links(skill_action_hash: ActionHash) -> ExternResult<Vec<Link>> {
+  get_links(GetLinksInputBuilder::try_new(skill_action_hash, LinkTypes::SkillUsers)?.build())
+}
+
+/// Returns the links to the users having a skill or one of its aliases.
+#[hdk_extern]
+pub fn get_skill_users_links(skill_action_hash: ActionHash) -> ExternResult<Vec<Link>> {
+  let mut links = get_direct_skill_users_links(skill_action_hash.clone())?;
+  for alias_link in get_skill_aliases_links(skill_action_hash)? {
+    let alias_hash = alias_link
+      .target
+      .into_action_hash()
+      .ok_or(UtilsError::ActionHashNotFound("skill"))?;
+    links.extend(get_direct_skill_users_links(alias_hash)?);
+  }
+
+  Ok(links)
+}
+
+/// Replaces the skills of a user by the given skill names, maintaining the `UserSkills` and
+/// `SkillUsers` links, and returns the action hashes of the resolved skills.
+#[hdk_extern]
+pub fn set_user_skills(input: UserSkillsInput) -> ExternResult<Vec<ActionHash>> {
+  if !get_user_agents(input.user_original_action_hash.clone())?
+    .contains(&agent_info()?.agent_initial_pubkey)
+  {
+    return Err(wasm_error!(Guest(
+      "Only the agents of a User profile can set its skills".to_string()
+    )));
+  }
+
+  let mut skill_hashes: Vec<ActionHash> = vec![];
+  for name in input.skills {
+    if normalize_skill_name(&name).is_empty() {
+      continue;
+    }
+    let skill_hash = get_or_create_skill(name)?;
+    if !skill_hashes.contains(&skill_hash) {
+      skill_hashes.push(skill_hash);
+    }
+  }
+
+  let user_skills_links = get_user_skills_links(input.user_original_action_hash.clone())?;
+  let mut linked_skill_hashes: Vec<ActionHash> = vec![];
+
+  for link in user_skills_links {
+    let skill_hash = link
+      .target
+      .clone()
+      .into_action_hash()
+      .ok_or(UtilsError::ActionHashNotFound("skill"))?;
+    if skill_hashes.contains(&skill_hash) {
+      linked_skill_hashes.push(skill_hash);
+      continue;
+    }
+
+    delete_link(link.create_link_hash)?;
+    for skill_user_link in get_direct_skill_users_links(skill_hash)? {
+      if skill_user_link.target.clone().into_action_hash()
+        == Some(input.user_original_action_hash.clone())
+      {
+        delete_link(skill_user_link.create_link_hash)?;
+      }
+    }
+  }
+
+  for skill_hash in &skill_hashes {
+    if linked_skill_hashes.contains(skill_hash) {
+      continue;
+    }
+
+    create_link(
+      input.user_original_action_hash.clone(),
+      skill_hash.clone(),
+      LinkTypes::UserSkills,
+      (),
+    )?;
+    create_link(
+      skill_hash.clone(),
+      input.user_original_action_hash.clone(),
+      LinkTypes::SkillUsers,
+      (),
+    )?;
+  }
+
+  Ok(skill_hashes)
+}
+
+#[hdk_extern]
+pub fn get_skill_aliases_links(skill_action_hash: ActionHash) -> ExternResult<Vec<Link>> {
+  get_links(GetLinksInputBuilder::try_new(skill_action_hash, LinkTypes::SkillAliases)?.build())
+}
+
+/// Returns the link from an alias to its canonical skill, if the skill is an alias.
+#[hdk_extern]
+pub fn get_canonical_skill_link(skill_action_hash: ActionHash) -> ExternResult<Option<Link>> {
+  let links = get_links(
+    GetLinksInputBuilder::try_new(skill_action_hash, LinkTypes::CanonicalSkill)?.build(),
+  )?;
+
+  Ok(
+    links
+      .into_iter()
+      .max_by(|link_a, link_b| link_a.timestamp.cmp(&link_b.timestamp)),
+  )
+}
+
+/// Deletes the links between a canonical skill and one of its aliases.
+fn unlink_skill_alias(skill_hash: &ActionHash, alias_hash: &ActionHash) -> ExternResult<()> {
+  for link in get_skill_aliases_links(skill_hash.clone())? {
+    if link.target.clone().into_action_hash().as_ref() == Some(alias_hash) {
+      delete_link(link.create_link_hash)?;
+    }
+  }
+
+  let links = get_links(
+    GetLinksInputBuilder::try_new(alias_hash.clone(), LinkTypes::CanonicalSkill)?.build(),
+  )?;
+  for link in links {
+    if link.target.clone().into_action_hash().as_ref() == Some(skill_hash) {
+      delete_link(link.create_link_hash)?;
+    }
+  }
+
+  Ok(())
+}
+
+/// Declares the complete set of aliases of a canonical skill, such as "js" and "java script"
+/// for "javascript". Users having an alias are then found and displayed with the canonical
+/// skill, without editing their profiles. Only network administrators can do this.
+#[hdk_extern]
+pub fn set_skill_aliases(input: SkillAliasesInput) -> ExternResult<ActionHash> {
+  if !check_if_agent_is_administrator("network", agent_info()?.agent_initial_pubkey)? {
+    return Err(wasm_error!(Guest(
+      "Only administrators can manage skill aliases".to_string()
+    )));
+  }
+
+  let skill_hash = find_or_create_skill(&input.skill)?;
+  if get_canonical_skill_link(skill_hash.clone())?.is_some() {
+    return Err(wasm_error!(Guest(format!(
+      "'{}' is already an alias of another skill",
+      input.skill
+    ))));
+  }
+
+  let mut alias_hashes: Vec<ActionHash> = vec![];
+  for alias in input.aliases {
+    if normalize_skill_name(&alias).is_empty() {
+      continue;
+    }
+    let alias_hash = find_or_create_skill(&alias)?;
+    if alias_hash == skill_hash || alias_hashes.contains(&alias_hash) {
+      continue;
+    }
+    if !get_skill_aliases_links(alias_hash.clone())?.is_empty() {
+      return Err(wasm_error!(Guest(format!(
+        "'{}' has aliases, it cannot become an alias",
+        alias
+      ))));
+    }
+    alias_hashes.push(alias_hash);
+  }
+
+  let mut linked_alias_hashes: Vec<ActionHash> = vec![];
+  for link in get_skill_aliases_links(skill_hash.clone())? {
+    let alias_hash = link
+      .target
+      .into_action_hash()
+      .ok_or(UtilsError::ActionHashNotFound("skill"))?;
+    if alias_hashes.contains(&alias_hash) {
+      linked_alias_hashes.push(alias_hash);
+    } else {
+      unlink_skill_alias(&skill_hash, &alias_hash)?;
+    }
+  }
+
+  for alias_hash in alias_hashes {
+    if linked_alias_hashes.contains(&alias_hash) {
+      continue;
+    }
+
+    // An alias of another skill is moved to this one
+    if let Some(link) = get_canonical_skill_link(alias_hash.clone())? {
+      let previous_skill_hash = link
+        .target
+        .into_action_hash()
+        .ok_or(UtilsError::ActionHashNotFound("skill"))?;
+      unlink_skill_alias(&previous_skill_hash, &alias_hash)?;
+    }
+
+    create_link(
+      skill_hash.clone(),
+      alias_hash.clone(),
+      LinkTypes::SkillAliases,
+      (),
+    )?;
+    create_link(
+      alias_hash,
+      skill_hash.clone(),
+      LinkTypes::CanonicalSkill,
+      (),
+    )?;
+  }
+
+  Ok(skill_hash)
+}
@@ -0,0 +1,242 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Offset, TimeZone};
+use hdk::prelude::*;
+use users_organizations_integrity::*;
+use utils::{errors::UtilsError, time_zone::UserTimeZone};
+use WasmErrorInner::*;
+
+use crate::user::{get_agent_user, get_latest_user};
+
+/// The largest number of slots returned by `get_user_availability_slots`.
+pub const MAX_AVAILABILITY_SLOTS: u32 = 100;
+
+const MINUTES_PER_DAY: i64 = 24 * 60;
+const MINUTES_PER_WEEK: i64 = 7 * MINUTES_PER_DAY;
+const MICROS_PER_MINUTE: i64 = 60_000_000;
+/// The Unix epoch fell on a Thursday, the fourth day of a week starting on Monday.
+const EPOCH_WEEKDAY: i64 = 3;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UpdateAvailabilityInput {
+  pub original_action_hash: ActionHash,
+  pub previous_action_hash: ActionHash,
+  pub updated_availability: Availability,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AvailabilitySlotsInput {
+  pub user_original_action_hash: ActionHash,
+  /// The number of slots to return, at most `MAX_AVAILABILITY_SLOTS`.
+  pub count: u32,
+}
+
+#[hdk_extern]
+pub fn create_availability(availability: Availability) -> ExternResult<Record> {
+  let user_links = get_agent_user(agent_info()?.agent_initial_pubkey)?;
+  let user_original_action_hash = user_links
+    .first()
+    .and_then(|link| link.target.clone().into_action_hash())
+    .ok_or(wasm_error!(Guest(
+      "You must first create a User profile".to_string()
+    )))?;
+
+  if get_user_availability_link(user_original_action_hash.clone())?.is_some() {
+    return Err(wasm_error!(Guest(
+      "You already have an Availability".to_string()
+    )));
+  }
+
+  let availability_hash = create_entry(&EntryTypes::Availability(availability))?;
+  let record = get(availability_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(Guest(
+    "Could not find the newly created Availability".to_string()
+  )))?;
+
+  create_link(
+    user_original_action_hash,
+    availability_hash,
+    LinkTypes::UserAvailability,
+    (),
+  )?;
+
+  Ok(record)
+}
+
+#[hdk_extern]
+pub fn get_user_availability_link(
+  user_original_action_hash: ActionHash,
+) -> ExternResult<Option<Link>> {
+  let links = get_links(
+    GetLinksInputBuilder::try_new(user_original_action_hash, LinkTypes::UserAvailability)?.build(),
+  )?;
+
+  Ok(links.first().cloned())
+}
+
+#[hdk_extern]
+pub fn get_latest_availability_record(
+  original_action_hash: ActionHash,
+) -> ExternResult<Option<Record>> {
+  let links = get_links(
+    GetLinksInputBuilder::try_new(original_action_hash.clone(), LinkTypes::AvailabilityUpdates)?
+      .build(),
+  )?;
+  let latest_link = links
+    .into_iter()
+    .max_by(|link_a, link_b| link_a.timestamp.cmp(&link_b.timestamp));
+  let latest_availability_hash = match latest_link {
+    Some(link) => link
+      .target
+      .clone()
+      .into_action_hash()
+      .ok_or(UtilsError::ActionHashNotFound("availability"))?,
+    None => original_action_hash.clone(),
+  };
+  get(latest_availability_hash, GetOptions::default())
+}
+
+#[hdk_extern]
+pub fn get_user_availability(
+  user_original_action_hash: ActionHash,
+) -> ExternResult<Option<Availability>> {
+  let Some(link) = get_user_availability_link(user_original_action_hash)? else {
+    return Ok(None);
+  };
+  let original_action_hash = link
+    .target
+    .into_action_hash()
+    .ok_or(UtilsError::ActionHashNotFound("availability"))?;
+
+  let Some(record) = get_latest_availability_record(original_action_hash)? else {
+    return Ok(None);
+  };
+
+  record
+    .entry()
+    .to_app_option()
+    .map_err(|e| wasm_error!(Serialize(e)))
+}
+
+#[hdk_extern]
+pub fn update_availability(input: UpdateAvailabilityInput) -> ExternResult<Record> {
+  let original_record = must_get_valid_record(input.original_action_hash.clone())?;
+
+  let author = original_record.action().author().clone();
+  if author != agent_info()?.agent_initial_pubkey {
+    return Err(wasm_error!(Guest(
+      "Only the author of an Availability can update it".to_string()
+    )));
+  }
+
+  let updated_availability_hash = update_entry(
+    input.previous_action_hash.clone(),
+    &input.updated_availability,
+  )?;
+
+  create_link(
+    input.original_action_hash.clone(),
+    updated_availability_hash.clone(),
+    LinkTypes::AvailabilityUpdates,
+    (),
+  )?;
+
+  let record =
+    get(updated_availability_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(Guest(
+      "Could not find the newly updated Availability".to_string()
+    )))?;
+
+  Ok(record)
+}
+
+/// Expands the weekly availability of a user into its next concrete slots, in UTC.
+///
+/// The windows are interpreted in the `time_zone` of the user's profile, either an IANA name
+/// such as "Europe/Paris" or an offset from UTC such as "UTC+02:00".
+#[hdk_extern]
+pub fn get_user_availability_slots(
+  input: AvailabilitySlotsInput,
+) -> ExternResult<Vec<AvailabilitySlot>> {
+  let Some(availability) = get_user_availability(input.user_original_action_hash.clone())? else {
+    return Ok(vec![]);
+  };
+
+  let user = get_latest_user(input.user_original_action_hash)?;
+  let time_zone = UserTimeZone::from_str(&user.time_zone).map_err(|e| wasm_error!(Guest(e)))?;
+
+  Ok(next_availability_slots(
+    &availability.windows,
+    &time_zone,
+    sys_time()?,
+    input.count.min(MAX_AVAILABILITY_SLOTS) as usize,
+  ))
+}
+
+/// Returns the offset from UTC of a time zone at a given minute since the Unix epoch.
+pub fn utc_offset_minutes(time_zone: &UserTimeZone, utc_minute: i64) -> i64 {
+  match time_zone {
+    UserTimeZone::FixedOffset(offset) => *offset,
+    UserTimeZone::Iana(tz) => DateTime::from_timestamp(utc_minute * 60, 0)
+      .map(|date_time| {
+        tz.offset_from_utc_datetime(&date_time.naive_utc())
+          .fix()
+          .local_minus_utc() as i64
+          / 60
+      })
+      .unwrap_or(0),
+  }
+}
+
+/// Converts a local time, in minutes since the Unix epoch, to UTC. Local times skipped or
+/// repeated by a daylight saving change use the offset in effect around them.
+pub fn local_to_utc_minute(time_zone: &UserTimeZone, local_minute: i64) -> i64 {
+  let guess = local_minute - utc_offset_minutes(time_zone, local_minute);
+  local_minute - utc_offset_minutes(time_zone, guess)
+}
+
+/// Expands the weekly windows into the next `count` slots starting at or after `from`, given
+/// the time zone the windows are written in.
+pub fn next_availability_slots(
+  windows: &[AvailabilityWindow],
+  time_zone: &UserTimeZone,
+  from: Timestamp,
+  count: usize,
+) -> Vec<AvailabilitySlot> {
+  let mut windows = windows.to_vec();
+  windows.sort_by_key(|window| (window.weekday, window.start_minute));
+
+  let from_minute = from.as_micros().div_euclid(MICROS_PER_MINUTE);
+  let local_day =
+    (from_minute + utc_offset_minutes(time_zone, from_minute)).div_euclid(MINUTES_PER_DAY);
+  let week_start = (local_day - (local_day + EPOCH_WEEKDAY).rem_euclid(7)) * MINUTES_PER_DAY;
+
+  let mut slots = vec![];
+  if windows.is_empty() {
+    return slots;
+  }
+
+  // The slots of the current week may have started already, so one more week may be needed
+  for week in 0..=(count as i64 / windows.len() as i64 + 1) {
+    for window in &windows {
+      let start = local_to_utc_minute(
+        time_zone,
+        week_start
+          + week * MINUTES_PER_WEEK
+          + window.weekday as i64 * MINUTES_PER_DAY
+          + window.start_minute as i64,
+      );
+      if start < from_minute {
+        continue;
+      }
+      if slots.len() == count {
+        return slots;
+      }
+
+      slots.push(AvailabilitySlot {
+        start: Timestamp::from_micros(start * MICROS_PER_MINUTE),
+        end: Timestamp::from_micros((start + window.duration_minutes as i64) * MICROS_PER_MINUTE),
+      });
+    }
+  }
+
+  slots
+}
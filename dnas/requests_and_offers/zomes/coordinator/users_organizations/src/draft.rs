@@ -0,0 +1,173 @@
+use std::collections::HashSet;
+
+use hdk::prelude::*;
+use users_organizations_integrity::*;
+use WasmErrorInner::*;
+
+use crate::{organization::create_organization, user::create_user};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SaveUserDraftInput {
+  /// The action hash of the draft being revised, if any.
+  pub previous_draft_action_hash: Option<ActionHash>,
+  pub user: User,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SaveOrganizationDraftInput {
+  /// The action hash of the draft being revised, if any.
+  pub previous_draft_action_hash: Option<ActionHash>,
+  pub organization: Organization,
+}
+
+fn draft_entry_type(unit_entry_type: UnitEntryTypes) -> ExternResult<EntryType> {
+  let ScopedEntryDefIndex {
+    zome_index,
+    zome_type,
+  } = unit_entry_type.try_into()?;
+
+  Ok(EntryType::App(AppEntryDef::new(
+    zome_type,
+    zome_index,
+    EntryVisibility::Private,
+  )))
+}
+
+/// Returns the latest revision of every draft of the given type that was not discarded.
+fn get_my_drafts(unit_entry_type: UnitEntryTypes) -> ExternResult<Vec<Record>> {
+  let records = query(
+    ChainQueryFilter::new()
+      .entry_type(draft_entry_type(unit_entry_type)?)
+      .include_entries(true),
+  )?;
+
+  let deleted_hashes: HashSet<ActionHash> =
+    query(ChainQueryFilter::new().action_type(ActionType::Delete))?
+      .into_iter()
+      .filter_map(|record| match record.action() {
+        Action::Delete(delete) => Some(delete.deletes_address.clone()),
+        _ => None,
+      })
+      .collect();
+  let updated_hashes: HashSet<ActionHash> = records
+    .iter()
+    .filter_map(|record| match record.action() {
+      Action::Update(update) => Some(update.original_action_address.clone()),
+      _ => None,
+    })
+    .collect();
+
+  Ok(
+    records
+      .into_iter()
+      .filter(|record| {
+        !deleted_hashes.contains(record.action_address())
+          && !updated_hashes.contains(record.action_address())
+      })
+      .collect(),
+  )
+}
+
+fn get_my_draft<A>(
+  unit_entry_type: UnitEntryTypes,
+  draft_action_hash: &ActionHash,
+) -> ExternResult<A>
+where
+  A: TryFrom<SerializedBytes, Error = SerializedBytesError>,
+{
+  let record = get_my_drafts(unit_entry_type)?
+    .into_iter()
+    .find(|record| record.action_address() == draft_action_hash)
+    .ok_or(wasm_error!(Guest("Could not find the draft".to_string())))?;
+
+  record
+    .entry()
+    .to_app_option()
+    .map_err(|e| wasm_error!(Serialize(e)))?
+    .ok_or(wasm_error!(Guest("Could not find the draft".to_string())))
+}
+
+fn save_draft(
+  unit_entry_type: UnitEntryTypes,
+  previous_draft_action_hash: Option<ActionHash>,
+  draft: EntryTypes,
+) -> ExternResult<Record> {
+  let draft_hash = match previous_draft_action_hash {
+    Some(previous_draft_action_hash) => update_entry(previous_draft_action_hash, &draft)?,
+    None => create_entry(&draft)?,
+  };
+
+  get_my_drafts(unit_entry_type)?
+    .into_iter()
+    .find(|record| *record.action_address() == draft_hash)
+    .ok_or(wasm_error!(Guest(
+      "Could not find the newly saved draft".to_string()
+    )))
+}
+
+/// Saves a private User profile draft, or a new revision of an existing one.
+#[hdk_extern]
+pub fn save_user_draft(input: SaveUserDraftInput) -> ExternResult<Record> {
+  save_draft(
+    UnitEntryTypes::UserDraft,
+    input.previous_draft_action_hash,
+    EntryTypes::UserDraft(UserDraft { user: input.user }),
+  )
+}
+
+#[hdk_extern]
+pub fn get_my_user_drafts(_: ()) -> ExternResult<Vec<Record>> {
+  get_my_drafts(UnitEntryTypes::UserDraft)
+}
+
+#[hdk_extern]
+pub fn discard_user_draft(draft_action_hash: ActionHash) -> ExternResult<ActionHash> {
+  get_my_draft::<UserDraft>(UnitEntryTypes::UserDraft, &draft_action_hash)?;
+  delete_entry(draft_action_hash)
+}
+
+/// Publishes a User profile draft as the agent's User profile, then discards the draft.
+#[hdk_extern]
+pub fn publish_user_draft(draft_action_hash: ActionHash) -> ExternResult<Record> {
+  let draft: UserDraft = get_my_draft(UnitEntryTypes::UserDraft, &draft_action_hash)?;
+
+  let record = create_user(draft.user)?;
+  delete_entry(draft_action_hash)?;
+
+  Ok(record)
+}
+
+/// Saves a private Organization profile draft, or a new revision of an existing one.
+#[hdk_extern]
+pub fn save_organization_draft(input: SaveOrganizationDraftInput) -> ExternResult<Record> {
+  save_draft(
+    UnitEntryTypes::OrganizationDraft,
+    input.previous_draft_action_hash,
+    EntryTypes::OrganizationDraft(OrganizationDraft {
+      organization: input.organization,
+    }),
+  )
+}
+
+#[hdk_extern]
+pub fn get_my_organization_drafts(_: ()) -> ExternResult<Vec<Record>> {
+  get_my_drafts(UnitEntryTypes::OrganizationDraft)
+}
+
+#[hdk_extern]
+pub fn discard_organization_draft(draft_action_hash: ActionHash) -> ExternResult<ActionHash> {
+  get_my_draft::<OrganizationDraft>(UnitEntryTypes::OrganizationDraft, &draft_action_hash)?;
+  delete_entry(draft_action_hash)
+}
+
+/// Publishes an Organization profile draft as a new Organization, then discards the draft.
+#[hdk_extern]
+pub fn publish_organization_draft(draft_action_hash: ActionHash) -> ExternResult<Record> {
+  let draft: OrganizationDraft =
+    get_my_draft(UnitEntryTypes::OrganizationDraft, &draft_action_hash)?;
+
+  let record = create_organization(draft.organization)?;
+  delete_entry(draft_action_hash)?;
+
+  Ok(record)
+}
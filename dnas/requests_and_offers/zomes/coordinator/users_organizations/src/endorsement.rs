@@ -0,0 +1,209 @@
+use std::collections::{HashMap, HashSet};
+
+use hdk::prelude::*;
+use users_organizations_integrity::*;
+use utils::EntityActionHash;
+use WasmErrorInner::*;
+
+use crate::{
+  external_calls::{check_if_entity_is_accepted, get_canonical_skill_names},
+  user::{get_agent_user, get_latest_user_profile, get_user_agents_links, UserProfile},
+};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct EndorsementInput {
+  pub user_original_action_hash: ActionHash,
+  pub skill: String,
+}
+
+/// The number of endorsements of one of a user's skills.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SkillEndorsements {
+  pub skill: String,
+  pub count: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UserWithEndorsements {
+  pub user: User,
+  /// The endorsement counts of the user's skills under their canonical names, in the order of
+  /// its skills.
+  pub skill_endorsements: Vec<SkillEndorsements>,
+}
+
+fn get_skill_name(link: &Link) -> ExternResult<String> {
+  String::from_utf8(link.tag.clone().into_inner())
+    .map_err(|_| wasm_error!(Guest("Invalid skill name".to_string())))
+}
+
+/// Endorses a skill listed on another user's profile. Only accepted users can endorse skills.
+#[hdk_extern]
+pub fn endorse_user_skill(input: EndorsementInput) -> ExternResult<Record> {
+  let agent_pubkey = agent_info()?.agent_initial_pubkey;
+  let endorser = get_agent_user(agent_pubkey.clone())?
+    .first()
+    .and_then(|link| link.target.clone().into_action_hash())
+    .ok_or(wasm_error!(Guest(
+      "You must first create a User profile".to_string()
+    )))?;
+
+  if endorser == input.user_original_action_hash {
+    return Err(wasm_error!(Guest(
+      "You cannot endorse your own skills".to_string()
+    )));
+  }
+
+  if !check_if_entity_is_accepted(EntityActionHash {
+    entity_original_action_hash: endorser.clone(),
+    entity: "users".to_string(),
+  })? {
+    return Err(wasm_error!(Guest(
+      "Only accepted users can endorse skills".to_string()
+    )));
+  }
+
+  let skill = get_canonical_skill_names(vec![input.skill])?
+    .into_iter()
+    .next()
+    .ok_or(wasm_error!(Guest("The skill cannot be empty".to_string())))?;
+
+  // Profiles may list a skill under another spelling or an alias of its canonical skill
+  let endorsed_user = get_latest_user_profile(input.user_original_action_hash.clone())?;
+  if !endorsed_user
+    .canonical_skills
+    .iter()
+    .any(|canonical_skill| canonical_skill.name == skill)
+  {
+    return Err(wasm_error!(Guest(
+      "The user does not list this skill".to_string()
+    )));
+  }
+
+  let already_endorsed = get_user_endorsements_links(input.user_original_action_hash.clone())?
+    .iter()
+    .any(|link| link.author == agent_pubkey && get_skill_name(link).ok().as_ref() == Some(&skill));
+  if already_endorsed {
+    return Err(wasm_error!(Guest(
+      "You already endorsed this skill".to_string()
+    )));
+  }
+
+  let endorser_agent_link = get_user_agents_links(endorser.clone())?
+    .into_iter()
+    .find(|link| link.target.clone().into_agent_pub_key() == Some(agent_pubkey.clone()))
+    .ok_or(wasm_error!(Guest(
+      "Could not find the link to your agent".to_string()
+    )))?;
+
+  let endorsement = Endorsement {
+    endorser,
+    endorser_agent_link: endorser_agent_link.create_link_hash,
+    endorsed_user: input.user_original_action_hash.clone(),
+    skill: skill.clone(),
+  };
+
+  let endorsement_hash = create_entry(&EntryTypes::Endorsement(endorsement))?;
+  let record = get(endorsement_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(Guest(
+    "Could not find the newly created Endorsement".to_string()
+  )))?;
+
+  create_link(
+    input.user_original_action_hash,
+    endorsement_hash,
+    LinkTypes::UserEndorsements,
+    LinkTag::new(skill),
+  )?;
+
+  Ok(record)
+}
+
+/// Returns the links to the endorsements of a user, whose tags hold the endorsed skills.
+#[hdk_extern]
+pub fn get_user_endorsements_links(
+  user_original_action_hash: ActionHash,
+) -> ExternResult<Vec<Link>> {
+  get_links(
+    GetLinksInputBuilder::try_new(user_original_action_hash, LinkTypes::UserEndorsements)?.build(),
+  )
+}
+
+/// Returns the latest version of a User profile along with the number of agents having
+/// endorsed each of its skills.
+#[hdk_extern]
+pub fn get_latest_user_with_endorsements(
+  original_action_hash: ActionHash,
+) -> ExternResult<UserWithEndorsements> {
+  let UserProfile {
+    user,
+    canonical_skills: user_skills,
+  } = get_latest_user_profile(original_action_hash.clone())?;
+
+  let mut canonical_skills: HashMap<String, String> = HashMap::new();
+  let mut endorsers: HashMap<String, HashSet<AgentPubKey>> = HashMap::new();
+  for link in get_user_endorsements_links(original_action_hash)? {
+    let skill = get_skill_name(&link)?;
+    // Skills merged since the endorsement are counted under their canonical skill
+    let canonical_skill = match canonical_skills.get(&skill) {
+      Some(canonical_skill) => canonical_skill.clone(),
+      None => {
+        let canonical_skill = get_canonical_skill_names(vec![skill.clone()])?
+          .into_iter()
+          .next()
+          .unwrap_or(skill.clone());
+        canonical_skills.insert(skill, canonical_skill.clone());
+        canonical_skill
+      }
+    };
+    endorsers
+      .entry(canonical_skill)
+      .or_default()
+      .insert(link.author);
+  }
+
+  let skill_endorsements = user_skills
+    .iter()
+    .map(|skill| SkillEndorsements {
+      skill: skill.name.clone(),
+      count: endorsers
+        .get(&skill.name)
+        .map_or(0, |agents| agents.len() as u32),
+    })
+    .collect();
+
+  Ok(UserWithEndorsements {
+    user,
+    skill_endorsements,
+  })
+}
+
+/// Withdraws an endorsement made by the current agent.
+#[hdk_extern]
+pub fn withdraw_endorsement(endorsement_action_hash: ActionHash) -> ExternResult<bool> {
+  let record = get(endorsement_action_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
+    Guest("Could not find the Endorsement".to_string())
+  ))?;
+
+  if *record.action().author() != agent_info()?.agent_initial_pubkey {
+    return Err(wasm_error!(Guest(
+      "Only the endorser can withdraw an Endorsement".to_string()
+    )));
+  }
+
+  let endorsement: Endorsement = record
+    .entry()
+    .to_app_option()
+    .map_err(|e| wasm_error!(Serialize(e)))?
+    .ok_or(wasm_error!(Guest(
+      "Could not find the Endorsement".to_string()
+    )))?;
+
+  for link in get_user_endorsements_links(endorsement.endorsed_user)? {
+    if link.target.clone().into_action_hash() == Some(endorsement_action_hash.clone()) {
+      delete_link(link.create_link_hash)?;
+    }
+  }
+
+  delete_entry(endorsement_action_hash)?;
+
+  Ok(true)
+}
@@ -67,10 +67,6 @@ pub fn get_accepted_entities(entity: String) -> ExternResult<Vec<Link>> {
 /// # Returns
 ///
 /// * `ExternResult<bool>` - Returns `true` if the status was successfully deleted, otherwise `false`.
-pub fn delete_status(original_action_hash: EntityActionHash) -> ExternResult<bool> {
-  external_local_call("delete_status", "administration", original_action_hash)
-}
-
 pub fn check_if_entity_is_accepted(original_action_hash: EntityActionHash) -> ExternResult<bool> {
   external_local_call(
     "check_if_entity_is_accepted",
@@ -78,3 +74,106 @@ pub fn check_if_entity_is_accepted(original_action_hash: EntityActionHash) -> Ex
     original_action_hash,
   )
 }
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct UserSkillsInput {
+  user_original_action_hash: ActionHash,
+  skills: Vec<String>,
+}
+
+/// Replaces the skills of a user by the canonical skills matching the given names.
+///
+/// # Arguments
+///
+/// * `user_original_action_hash` - The original action hash of the user.
+/// * `skills` - The names of the user's skills.
+///
+/// # Returns
+///
+/// * `ExternResult<Vec<ActionHash>>` - Returns the action hashes of the resolved skills.
+pub fn set_user_skills(
+  user_original_action_hash: ActionHash,
+  skills: Vec<String>,
+) -> ExternResult<Vec<ActionHash>> {
+  external_local_call(
+    "set_user_skills",
+    "skills",
+    UserSkillsInput {
+      user_original_action_hash,
+      skills,
+    },
+  )
+}
+
+/// Retrieves the `AllSkills` link of the skill with the given name, whose tag holds the
+/// canonical name of the skill.
+///
+/// # Arguments
+///
+/// * `name` - The name of the skill.
+///
+/// # Returns
+///
+/// * `ExternResult<Option<Link>>` - Returns the link to the skill, if it exists.
+pub fn get_skill_link(name: String) -> ExternResult<Option<Link>> {
+  external_local_call("get_skill_link", "skills", name)
+}
+
+/// Retrieves the links from a skill to the users having it.
+///
+/// # Arguments
+///
+/// * `skill_action_hash` - The action hash of the skill.
+///
+/// # Returns
+///
+/// * `ExternResult<Vec<Link>>` - Returns the `SkillUsers` links of the skill.
+pub fn get_skill_users_links(skill_action_hash: ActionHash) -> ExternResult<Vec<Link>> {
+  external_local_call("get_skill_users_links", "skills", skill_action_hash)
+}
+
+/// Maps skill names to the names of their canonical skills, following the alias table
+/// maintained by administrators.
+///
+/// # Arguments
+///
+/// * `names` - The names of the skills.
+///
+/// # Returns
+///
+/// * `ExternResult<Vec<String>>` - Returns the canonical names, without duplicates.
+pub fn get_canonical_skill_names(names: Vec<String>) -> ExternResult<Vec<String>> {
+  external_local_call("get_canonical_skill_names", "skills", names)
+}
+
+/// Maps each skill name to the name of its canonical skill in a single call.
+///
+/// # Arguments
+///
+/// * `names` - The names of the skills.
+///
+/// # Returns
+///
+/// * `ExternResult<Vec<String>>` - Returns one canonical name per given name, in the same order.
+pub fn map_canonical_skill_names(names: Vec<String>) -> ExternResult<Vec<String>> {
+  external_local_call("map_canonical_skill_names", "skills", names)
+}
+
+/// Checks that a category exists and is not archived.
+///
+/// # Arguments
+///
+/// * `category_original_action_hash` - The original action hash of the category.
+///
+/// # Returns
+///
+/// * `ExternResult<bool>` - Returns `true` if the category can be used, otherwise `false`.
+pub fn check_if_category_is_active(
+  category_original_action_hash: ActionHash,
+) -> ExternResult<bool> {
+  external_local_call(
+    "check_if_category_is_active",
+    "categories",
+    category_original_action_hash,
+  )
+}
@@ -1,8 +1,17 @@
 pub mod administration;
+pub mod availability;
+pub mod draft;
+pub mod endorsement;
 pub mod organization;
+pub mod organization_category;
+pub mod organization_invitation;
+pub mod organization_join_request;
+pub mod organization_role;
+pub mod skill;
 pub mod user;
 
 mod external_calls;
+mod tests;
 
 use hdk::prelude::*;
 use users_organizations_integrity::*;
@@ -1,14 +1,16 @@
 use hdk::prelude::*;
 use users_organizations_integrity::*;
-use utils::{errors::UtilsError, EntityActionHash, OrganizationUser};
+use utils::{errors::UtilsError, on_behalf_of::OnBehalfOf, EntityActionHash, OrganizationUser};
 use WasmErrorInner::*;
 
 use crate::{
   administration::get_organization_status_link,
-  external_calls::{
-    check_if_entity_is_accepted, create_status, delete_status, get_accepted_entities,
+  external_calls::{check_if_entity_is_accepted, create_status, get_accepted_entities},
+  organization_category::{check_organization_categories, set_organization_categories},
+  organization_role::check_if_agent_has_permission,
+  user::{
+    get_agent_user, get_latest_user, get_my_user_agent_link, get_my_user_original_action_hash,
   },
-  user::{get_agent_user, get_latest_user},
 };
 
 #[hdk_extern]
@@ -20,6 +22,8 @@ pub fn create_organization(organization: Organization) -> ExternResult<Record> {
     )));
   }
 
+  check_organization_categories(&organization.categories)?;
+
   let organization_hash = create_entry(&EntryTypes::Organization(organization.clone()))?;
   let record = get(organization_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(Guest(
     "Could not find the newly created Organization profile".to_string()
@@ -57,12 +61,14 @@ pub fn create_organization(organization: Organization) -> ExternResult<Record> {
   )?;
 
   create_link(
-    organization_hash,
+    organization_hash.clone(),
     user_links[0].target.clone(),
     LinkTypes::OrganizationCoordinators,
-    (),
+    coordinator_grant_tag(&CoordinatorGrant::Creation)?,
   )?;
 
+  set_organization_categories(&organization_hash, &organization.categories)?;
+
   Ok(record)
 }
 
@@ -107,9 +113,12 @@ pub fn get_latest_organization(original_action_hash: ActionHash) -> ExternResult
 
 #[hdk_extern]
 pub fn add_member_to_organization(input: OrganizationUser) -> ExternResult<bool> {
-  if !check_if_agent_is_organization_coordinator(input.organization_original_action_hash.clone())? {
+  if !check_if_agent_has_permission(
+    input.organization_original_action_hash.clone(),
+    OrganizationPermission::ManageMembers,
+  )? {
     return Err(wasm_error!(Guest(
-      "Only coordinators can add other members".to_string()
+      "You are not allowed to add members to this organization".to_string()
     )));
   }
 
@@ -125,6 +134,13 @@ pub fn add_member_to_organization(input: OrganizationUser) -> ExternResult<bool>
     )));
   }
 
+  link_organization_member(input)?;
+
+  Ok(true)
+}
+
+/// Creates the links making a user a member of an organization.
+pub(crate) fn link_organization_member(input: OrganizationUser) -> ExternResult<()> {
   create_link(
     input.organization_original_action_hash.clone(),
     input.user_original_action_hash.clone(),
@@ -133,18 +149,13 @@ pub fn add_member_to_organization(input: OrganizationUser) -> ExternResult<bool>
   )?;
 
   create_link(
-    input.user_original_action_hash.clone(),
-    input.organization_original_action_hash.clone(),
+    input.user_original_action_hash,
+    input.organization_original_action_hash,
     LinkTypes::UserOrganizations,
     (),
   )?;
 
-  Ok(true)
-}
-
-#[hdk_extern]
-pub fn invite_member_to_organization(_input: OrganizationUser) -> ExternResult<bool> {
-  todo!("invite_member_to_organization");
+  Ok(())
 }
 
 #[hdk_extern]
@@ -193,7 +204,7 @@ pub fn is_organization_member(input: OrganizationUser) -> ExternResult<bool> {
       .target
       .clone()
       .into_action_hash()
-      .map_or(false, |hash| hash == input.user_original_action_hash)
+      .is_some_and(|hash| hash == input.user_original_action_hash)
   });
 
   // Check UserOrganizations links
@@ -204,9 +215,7 @@ pub fn is_organization_member(input: OrganizationUser) -> ExternResult<bool> {
       .target
       .clone()
       .into_action_hash()
-      .map_or(false, |hash| {
-        hash == input.organization_original_action_hash
-      })
+      .is_some_and(|hash| hash == input.organization_original_action_hash)
   });
 
   // Both links must exist for valid membership
@@ -248,25 +257,15 @@ pub fn get_user_organizations(
   Ok(organizations)
 }
 
-#[hdk_extern]
-pub fn add_coordinator_to_organization(input: OrganizationUser) -> ExternResult<bool> {
-  if !check_if_agent_is_organization_coordinator(input.organization_original_action_hash.clone())? {
-    return Err(wasm_error!(Guest(
-      "Only coordinators can add coordinators".to_string()
-    )));
-  }
-
+/// Checks that a user can be made a coordinator of an organization: the organization and the
+/// user must be accepted and the user must not be a coordinator yet.
+pub(crate) fn check_if_user_can_become_coordinator(input: OrganizationUser) -> ExternResult<()> {
   if !is_organization_accepted(&input.organization_original_action_hash)? {
     return Err(wasm_error!(Guest(
       "Cannot add coordinators to an organization that is not accepted".to_string()
     )));
   }
 
-  // Add as member first if not already a member
-  if !is_organization_member(input.clone())? {
-    add_member_to_organization(input.clone())?;
-  }
-
   let user_links = get_links(
     GetLinksInputBuilder::try_new(
       input.user_original_action_hash.clone(),
@@ -290,25 +289,39 @@ pub fn add_coordinator_to_organization(input: OrganizationUser) -> ExternResult<
     )));
   }
 
-  if is_organization_coordinator(input.clone())? {
+  if is_organization_coordinator(input)? {
     return Err(wasm_error!(Guest(
       "The user is already a coordinator of this organization".to_string()
     )));
   }
 
+  Ok(())
+}
+
+fn coordinator_grant_tag(grant: &CoordinatorGrant) -> ExternResult<LinkTag> {
+  Ok(LinkTag::new(
+    encode(grant).map_err(|e| wasm_error!(Serialize(e)))?,
+  ))
+}
+
+/// Creates the links making the current agent's user a coordinator of an organization, adding
+/// the user as a member first if needed.
+pub(crate) fn link_organization_coordinator(
+  input: OrganizationUser,
+  grant: CoordinatorGrant,
+) -> ExternResult<()> {
+  if !is_organization_member(input.clone())? {
+    link_organization_member(input.clone())?;
+  }
+
   create_link(
     input.organization_original_action_hash,
     input.user_original_action_hash,
     LinkTypes::OrganizationCoordinators,
-    (),
+    coordinator_grant_tag(&grant)?,
   )?;
 
-  Ok(true)
-}
-
-#[hdk_extern]
-pub fn invite_coordinator_to_organization(_input: OrganizationUser) -> ExternResult<bool> {
-  todo!("invite_coordinator_to_organization");
+  Ok(())
 }
 
 #[hdk_extern]
@@ -355,7 +368,7 @@ pub fn is_organization_coordinator(input: OrganizationUser) -> ExternResult<bool
       .target
       .clone()
       .into_action_hash()
-      .map_or(false, |hash| hash == input.user_original_action_hash)
+      .is_some_and(|hash| hash == input.user_original_action_hash)
   });
 
   Ok(is_coordinator)
@@ -465,9 +478,12 @@ pub fn leave_organization(original_action_hash: ActionHash) -> ExternResult<bool
 
 #[hdk_extern]
 pub fn remove_organization_member(input: OrganizationUser) -> ExternResult<bool> {
-  if !check_if_agent_is_organization_coordinator(input.organization_original_action_hash.clone())? {
+  if !check_if_agent_has_permission(
+    input.organization_original_action_hash.clone(),
+    OrganizationPermission::ManageMembers,
+  )? {
     return Err(wasm_error!(Guest(
-      "Only coordinators can remove members".to_string()
+      "You are not allowed to remove members from this organization".to_string()
     )));
   }
 
@@ -504,7 +520,7 @@ pub fn remove_organization_member(input: OrganizationUser) -> ExternResult<bool>
         .target
         .clone()
         .into_action_hash()
-        .map_or(false, |hash| hash == input.user_original_action_hash)
+        .is_some_and(|hash| hash == input.user_original_action_hash)
     })
     .ok_or(wasm_error!(Guest(
       "Could not find the member link".to_string()
@@ -519,9 +535,7 @@ pub fn remove_organization_member(input: OrganizationUser) -> ExternResult<bool>
       .target
       .clone()
       .into_action_hash()
-      .map_or(false, |hash| {
-        hash == input.organization_original_action_hash
-      })
+      .is_some_and(|hash| hash == input.organization_original_action_hash)
   });
 
   if this_user_organizations_link.is_none() {
@@ -535,11 +549,13 @@ pub fn remove_organization_member(input: OrganizationUser) -> ExternResult<bool>
   Ok(true)
 }
 
+/// Removes the current agent's user from the coordinators of an organization. Coordinators can
+/// only remove themselves.
 #[hdk_extern]
 pub fn remove_organization_coordinator(input: OrganizationUser) -> ExternResult<bool> {
-  if !check_if_agent_is_organization_coordinator(input.organization_original_action_hash.clone())? {
+  if input.user_original_action_hash != get_my_user_original_action_hash()? {
     return Err(wasm_error!(Guest(
-      "Only coordinators can remove other coordinators".to_string()
+      "Coordinators can only remove themselves from an organization".to_string()
     )));
   }
 
@@ -570,7 +586,7 @@ pub fn remove_organization_coordinator(input: OrganizationUser) -> ExternResult<
         .target
         .clone()
         .into_action_hash()
-        .map_or(false, |hash| hash == input.user_original_action_hash)
+        .is_some_and(|hash| hash == input.user_original_action_hash)
     })
     .ok_or(wasm_error!(Guest(
       "Could not find the coordinator link".to_string()
@@ -591,6 +607,47 @@ pub fn is_organization_accepted(
   })
 }
 
+/// Builds the proof that the current agent is a coordinator of the organization, citing its
+/// `OrganizationCoordinators` and `UserAgents` links.
+pub(crate) fn build_coordinator_on_behalf_of(
+  organization_original_action_hash: ActionHash,
+) -> ExternResult<OnBehalfOf> {
+  let user_original_action_hash = get_my_user_original_action_hash()?;
+
+  let coordinator_link =
+    get_organization_coordinators_links(organization_original_action_hash.clone())?
+      .into_iter()
+      .find(|link| {
+        link.target.clone().into_action_hash() == Some(user_original_action_hash.clone())
+      })
+      .ok_or(wasm_error!(Guest(
+        "Could not find the coordinator link".to_string()
+      )))?;
+  let user_agent_link = get_my_user_agent_link(user_original_action_hash)?;
+
+  Ok(OnBehalfOf {
+    organization_original_action_hash,
+    coordinator_link: coordinator_link.create_link_hash,
+    user_agent_link: user_agent_link.create_link_hash,
+  })
+}
+
+/// Creates the `OrganizationCoordinatorProofs` link proving that the current agent is a
+/// coordinator of the organization. Integrity validation expects it as the action right before
+/// an update or delete of the organization.
+fn create_coordinator_proof(organization_original_action_hash: ActionHash) -> ExternResult<()> {
+  let on_behalf_of = build_coordinator_on_behalf_of(organization_original_action_hash.clone())?;
+
+  create_link(
+    organization_original_action_hash,
+    get_my_user_original_action_hash()?,
+    LinkTypes::OrganizationCoordinatorProofs,
+    LinkTag::new(encode(&on_behalf_of).map_err(|e| wasm_error!(Serialize(e)))?),
+  )?;
+
+  Ok(())
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct UpdateOrganizationInput {
   pub original_action_hash: ActionHash,
@@ -606,6 +663,9 @@ pub fn update_organization(input: UpdateOrganizationInput) -> ExternResult<Recor
     )));
   }
 
+  check_organization_categories(&input.updated_organization.categories)?;
+
+  create_coordinator_proof(input.original_action_hash.clone())?;
   let updated_organization_hash = update_entry(
     input.previous_action_hash.clone(),
     &input.updated_organization,
@@ -618,6 +678,11 @@ pub fn update_organization(input: UpdateOrganizationInput) -> ExternResult<Recor
     (),
   )?;
 
+  set_organization_categories(
+    &input.original_action_hash,
+    &input.updated_organization.categories,
+  )?;
+
   let record =
     get(updated_organization_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(Guest(
       "Could not find the newly updated Organization profile".to_string()
@@ -634,7 +699,11 @@ pub fn delete_organization(organization_original_action_hash: ActionHash) -> Ext
     )));
   }
 
-  // Delete member links first
+  // Delete the organization entry first, while the agent is still linked as a coordinator
+  create_coordinator_proof(organization_original_action_hash.clone())?;
+  delete_entry(organization_original_action_hash.clone())?;
+
+  // Delete member links
   let members_links = get_organization_members_links(organization_original_action_hash.clone())?;
   for link in members_links.clone() {
     let user_hash = link
@@ -658,10 +727,16 @@ pub fn delete_organization(organization_original_action_hash: ActionHash) -> Ext
     delete_link(link.create_link_hash)?;
   }
 
-  // Delete coordinator links directly without using remove_organization_coordinator
-  let coordinator_links =
-    get_organization_coordinators_links(organization_original_action_hash.clone())?;
-  for link in coordinator_links {
+  // Coordinators can only delete their own link, the others are left on the deleted
+  // organization
+  let my_user_original_action_hash = get_my_user_original_action_hash()?;
+  let my_coordinator_link =
+    get_organization_coordinators_links(organization_original_action_hash.clone())?
+      .into_iter()
+      .find(|link| {
+        link.target.clone().into_action_hash() == Some(my_user_original_action_hash.clone())
+      });
+  if let Some(link) = my_coordinator_link {
     delete_link(link.create_link_hash)?;
   }
 
@@ -683,7 +758,11 @@ pub fn delete_organization(organization_original_action_hash: ActionHash) -> Ext
     }
   }
 
-  // Delete status links
+  // Delete category links
+  set_organization_categories(&organization_original_action_hash, &[])?;
+
+  // Delete status links here rather than through delete_status, which is reserved to
+  // administrators
   let organization_status_links =
     get_organization_status_link(organization_original_action_hash.clone())?;
   if let Some(link) = organization_status_links {
@@ -697,21 +776,12 @@ pub fn delete_organization(organization_original_action_hash: ActionHash) -> Ext
         .target
         .clone()
         .into_action_hash()
-        .map_or(false, |hash| hash == organization_original_action_hash)
+        .is_some_and(|hash| hash == organization_original_action_hash)
     });
 
   if let Some(link) = organization_status_link {
     delete_link(link.create_link_hash)?;
   }
 
-  // Delete status
-  delete_status(EntityActionHash {
-    entity_original_action_hash: organization_original_action_hash.clone(),
-    entity: String::from("organizations"),
-  })?;
-
-  // Finally delete the organization entry
-  delete_entry(organization_original_action_hash)?;
-
   Ok(true)
 }
@@ -0,0 +1,125 @@
+use std::collections::HashSet;
+
+use hdk::prelude::*;
+use users_organizations_integrity::*;
+use utils::errors::UtilsError;
+use WasmErrorInner::*;
+
+use crate::external_calls::{check_if_category_is_active, get_accepted_entities};
+
+/// Checks that the categories of an organization exist and are not archived.
+pub fn check_organization_categories(categories: &[ActionHash]) -> ExternResult<()> {
+  for category in categories {
+    if !check_if_category_is_active(category.clone())? {
+      return Err(wasm_error!(Guest(
+        "Organizations can only be classified in existing categories that are not archived"
+          .to_string()
+      )));
+    }
+  }
+
+  Ok(())
+}
+
+/// Replaces the `OrganizationCategories` and `CategoryOrganizations` links of an organization
+/// by links to the given categories.
+pub fn set_organization_categories(
+  organization_original_action_hash: &ActionHash,
+  categories: &[ActionHash],
+) -> ExternResult<()> {
+  let mut linked_categories: Vec<ActionHash> = vec![];
+
+  for link in get_organization_categories_links(organization_original_action_hash.clone())? {
+    let category = link
+      .target
+      .clone()
+      .into_action_hash()
+      .ok_or(UtilsError::ActionHashNotFound("category"))?;
+    if categories.contains(&category) {
+      linked_categories.push(category);
+      continue;
+    }
+
+    delete_link(link.create_link_hash)?;
+    for category_link in get_category_organizations_links(category)? {
+      if category_link.target.clone().into_action_hash().as_ref()
+        == Some(organization_original_action_hash)
+      {
+        delete_link(category_link.create_link_hash)?;
+      }
+    }
+  }
+
+  for category in categories {
+    if linked_categories.contains(category) {
+      continue;
+    }
+    linked_categories.push(category.clone());
+
+    create_link(
+      organization_original_action_hash.clone(),
+      category.clone(),
+      LinkTypes::OrganizationCategories,
+      (),
+    )?;
+    create_link(
+      category.clone(),
+      organization_original_action_hash.clone(),
+      LinkTypes::CategoryOrganizations,
+      (),
+    )?;
+  }
+
+  Ok(())
+}
+
+#[hdk_extern]
+pub fn get_organization_categories_links(
+  organization_original_action_hash: ActionHash,
+) -> ExternResult<Vec<Link>> {
+  get_links(
+    GetLinksInputBuilder::try_new(
+      organization_original_action_hash,
+      LinkTypes::OrganizationCategories,
+    )?
+    .build(),
+  )
+}
+
+#[hdk_extern]
+pub fn get_category_organizations_links(
+  category_original_action_hash: ActionHash,
+) -> ExternResult<Vec<Link>> {
+  get_links(
+    GetLinksInputBuilder::try_new(
+      category_original_action_hash,
+      LinkTypes::CategoryOrganizations,
+    )?
+    .build(),
+  )
+}
+
+/// Returns the links to the accepted organizations classified in a category.
+#[hdk_extern]
+pub fn get_accepted_organizations_by_category(
+  category_original_action_hash: ActionHash,
+) -> ExternResult<Vec<Link>> {
+  let accepted_organizations: HashSet<ActionHash> =
+    get_accepted_entities(String::from("organizations"))?
+      .into_iter()
+      .filter_map(|link| link.target.into_action_hash())
+      .collect();
+
+  Ok(
+    get_category_organizations_links(category_original_action_hash)?
+      .into_iter()
+      .filter(|link| {
+        link
+          .target
+          .clone()
+          .into_action_hash()
+          .is_some_and(|organization| accepted_organizations.contains(&organization))
+      })
+      .collect(),
+  )
+}
@@ -0,0 +1,314 @@
+use std::str::FromStr;
+
+use hdk::prelude::*;
+use users_organizations_integrity::*;
+use utils::{errors::UtilsError, OrganizationUser};
+use WasmErrorInner::*;
+
+use crate::{
+  organization::{
+    build_coordinator_on_behalf_of, check_if_agent_is_organization_coordinator,
+    check_if_user_can_become_coordinator, is_organization_accepted, is_organization_member,
+    link_organization_coordinator, link_organization_member,
+  },
+  organization_role::{build_organization_authority, check_if_agent_has_permission},
+  user::{get_my_user_agent_link, get_my_user_original_action_hash},
+};
+
+fn is_pending(invitation: &OrganizationInvitation) -> bool {
+  invitation.status == InvitationStatus::Pending.to_string()
+}
+
+/// Invites a user to become a member of an organization. Requires the 'manage_members'
+/// permission.
+#[hdk_extern]
+pub fn invite_member_to_organization(input: OrganizationUser) -> ExternResult<Record> {
+  if !check_if_agent_has_permission(
+    input.organization_original_action_hash.clone(),
+    OrganizationPermission::ManageMembers,
+  )? {
+    return Err(wasm_error!(Guest(
+      "You are not allowed to invite members to this organization".to_string()
+    )));
+  }
+
+  if !is_organization_accepted(&input.organization_original_action_hash)? {
+    return Err(wasm_error!(Guest(
+      "Cannot invite members to an organization that is not accepted".to_string()
+    )));
+  }
+
+  if is_organization_member(input.clone())? {
+    return Err(wasm_error!(Guest(
+      "The invited user is already a member".to_string()
+    )));
+  }
+
+  create_organization_invitation(input, InvitationRole::Member)
+}
+
+/// Invites a user to become a coordinator of an organization. The user is only promoted once
+/// they accept the invitation.
+#[hdk_extern]
+pub fn invite_coordinator_to_organization(input: OrganizationUser) -> ExternResult<Record> {
+  if !check_if_agent_is_organization_coordinator(input.organization_original_action_hash.clone())? {
+    return Err(wasm_error!(Guest(
+      "Only coordinators can invite coordinators".to_string()
+    )));
+  }
+
+  check_if_user_can_become_coordinator(input.clone())?;
+
+  create_organization_invitation(input, InvitationRole::Coordinator)
+}
+
+fn create_organization_invitation(
+  input: OrganizationUser,
+  role: InvitationRole,
+) -> ExternResult<Record> {
+  for link in get_user_invitations_links(input.user_original_action_hash.clone())? {
+    let invitation_original_action_hash = link
+      .target
+      .into_action_hash()
+      .ok_or(UtilsError::ActionHashNotFound("organization invitation"))?;
+    let invitation = get_latest_organization_invitation(invitation_original_action_hash)?;
+    if invitation.organization == input.organization_original_action_hash
+      && invitation.role == role.to_string()
+      && is_pending(&invitation)
+    {
+      return Err(wasm_error!(Guest(format!(
+        "The user already has a pending invitation to become {} of this organization",
+        match role {
+          InvitationRole::Member => "a member",
+          InvitationRole::Coordinator => "a coordinator",
+        }
+      ))));
+    }
+  }
+
+  // Members are invited with the 'manage_members' permission, coordinators by coordinators only
+  let authority = match role {
+    InvitationRole::Member => build_organization_authority(
+      input.organization_original_action_hash.clone(),
+      OrganizationPermission::ManageMembers,
+    )?,
+    InvitationRole::Coordinator => OrganizationAuthority::Coordinator(
+      build_coordinator_on_behalf_of(input.organization_original_action_hash.clone())?,
+    ),
+  };
+
+  let invitation = OrganizationInvitation {
+    authority,
+    organization: input.organization_original_action_hash,
+    inviter: get_my_user_original_action_hash()?,
+    invitee: input.user_original_action_hash.clone(),
+    role: role.to_string(),
+    status: InvitationStatus::Pending.to_string(),
+    invitee_agent_link: None,
+  };
+
+  let invitation_hash = create_entry(&EntryTypes::OrganizationInvitation(invitation))?;
+  let record = get(invitation_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(Guest(
+    "Could not find the newly created OrganizationInvitation".to_string()
+  )))?;
+
+  create_link(
+    input.user_original_action_hash,
+    invitation_hash,
+    LinkTypes::UserInvitations,
+    (),
+  )?;
+
+  Ok(record)
+}
+
+#[hdk_extern]
+pub fn get_latest_organization_invitation_record(
+  original_action_hash: ActionHash,
+) -> ExternResult<Option<Record>> {
+  let links = get_links(
+    GetLinksInputBuilder::try_new(
+      original_action_hash.clone(),
+      LinkTypes::OrganizationInvitationUpdates,
+    )?
+    .build(),
+  )?;
+  let latest_link = links
+    .into_iter()
+    .max_by(|link_a, link_b| link_a.timestamp.cmp(&link_b.timestamp));
+  let latest_invitation_hash = match latest_link {
+    Some(link) => link
+      .target
+      .clone()
+      .into_action_hash()
+      .ok_or(UtilsError::ActionHashNotFound("organization invitation"))?,
+    None => original_action_hash.clone(),
+  };
+  get(latest_invitation_hash, GetOptions::default())
+}
+
+#[hdk_extern]
+pub fn get_latest_organization_invitation(
+  original_action_hash: ActionHash,
+) -> ExternResult<OrganizationInvitation> {
+  let latest_invitation_record = get_latest_organization_invitation_record(original_action_hash)?;
+  let latest_invitation: OrganizationInvitation = latest_invitation_record
+    .ok_or(wasm_error!(Guest(
+      "Could not find the latest OrganizationInvitation".to_string()
+    )))?
+    .entry()
+    .to_app_option()
+    .map_err(|e| wasm_error!(Serialize(e)))?
+    .ok_or(wasm_error!(Guest(
+      "Could not find the latest OrganizationInvitation".to_string()
+    )))?;
+
+  Ok(latest_invitation)
+}
+
+/// Returns the links to all the invitations a user received, whatever their status.
+#[hdk_extern]
+pub fn get_user_invitations_links(
+  user_original_action_hash: ActionHash,
+) -> ExternResult<Vec<Link>> {
+  get_links(
+    GetLinksInputBuilder::try_new(user_original_action_hash, LinkTypes::UserInvitations)?.build(),
+  )
+}
+
+/// Returns the links to the invitations of the current agent that are still open.
+#[hdk_extern]
+pub fn get_my_pending_invitations_links(_: ()) -> ExternResult<Vec<Link>> {
+  let mut pending_links = vec![];
+  for link in get_user_invitations_links(get_my_user_original_action_hash()?)? {
+    let invitation_original_action_hash = link
+      .target
+      .clone()
+      .into_action_hash()
+      .ok_or(UtilsError::ActionHashNotFound("organization invitation"))?;
+    if is_pending(&get_latest_organization_invitation(
+      invitation_original_action_hash,
+    )?) {
+      pending_links.push(link);
+    }
+  }
+
+  Ok(pending_links)
+}
+
+/// Moves an open invitation to a new status, returning the action hash of the update.
+fn close_organization_invitation(
+  original_action_hash: ActionHash,
+  status: InvitationStatus,
+) -> ExternResult<ActionHash> {
+  let latest_record = get_latest_organization_invitation_record(original_action_hash.clone())?
+    .ok_or(wasm_error!(Guest(
+      "Could not find the latest OrganizationInvitation".to_string()
+    )))?;
+  let invitation: OrganizationInvitation = latest_record
+    .entry()
+    .to_app_option()
+    .map_err(|e| wasm_error!(Serialize(e)))?
+    .ok_or(wasm_error!(Guest(
+      "Could not find the latest OrganizationInvitation".to_string()
+    )))?;
+
+  if !is_pending(&invitation) {
+    return Err(wasm_error!(Guest(
+      "This invitation is no longer open".to_string()
+    )));
+  }
+
+  let invitee_agent_link = match status {
+    InvitationStatus::Accepted | InvitationStatus::Declined => {
+      Some(get_my_user_agent_link(invitation.invitee.clone())?.create_link_hash)
+    }
+    _ => None,
+  };
+  let invitation = OrganizationInvitation {
+    status: status.to_string(),
+    invitee_agent_link,
+    ..invitation
+  };
+  let updated_invitation_hash = update_entry(latest_record.action_address().clone(), &invitation)?;
+
+  create_link(
+    original_action_hash,
+    updated_invitation_hash.clone(),
+    LinkTypes::OrganizationInvitationUpdates,
+    (),
+  )?;
+
+  Ok(updated_invitation_hash)
+}
+
+fn check_if_agent_is_invitee(
+  original_action_hash: ActionHash,
+) -> ExternResult<OrganizationInvitation> {
+  let invitation = get_latest_organization_invitation(original_action_hash)?;
+  if invitation.invitee != get_my_user_original_action_hash()? {
+    return Err(wasm_error!(Guest(
+      "Only the invited user can decide on an invitation".to_string()
+    )));
+  }
+
+  Ok(invitation)
+}
+
+/// Accepts an open invitation, making the current agent's user a member or a coordinator of the
+/// organization.
+#[hdk_extern]
+pub fn accept_organization_invitation(original_action_hash: ActionHash) -> ExternResult<bool> {
+  let invitation = check_if_agent_is_invitee(original_action_hash.clone())?;
+  let organization_user = OrganizationUser {
+    organization_original_action_hash: invitation.organization.clone(),
+    user_original_action_hash: invitation.invitee.clone(),
+  };
+
+  let role = InvitationRole::from_str(&invitation.role).map_err(|e| wasm_error!(Guest(e)))?;
+  // The organization or the user may have changed since the invitation was sent
+  if role == InvitationRole::Coordinator {
+    check_if_user_can_become_coordinator(organization_user.clone())?;
+  }
+
+  let acceptance_action_hash =
+    close_organization_invitation(original_action_hash, InvitationStatus::Accepted)?;
+
+  match role {
+    InvitationRole::Member => {
+      if !is_organization_member(organization_user.clone())? {
+        link_organization_member(organization_user)?;
+      }
+    }
+    InvitationRole::Coordinator => link_organization_coordinator(
+      organization_user,
+      CoordinatorGrant::Invitation(acceptance_action_hash),
+    )?,
+  }
+
+  Ok(true)
+}
+
+#[hdk_extern]
+pub fn decline_organization_invitation(original_action_hash: ActionHash) -> ExternResult<bool> {
+  check_if_agent_is_invitee(original_action_hash.clone())?;
+
+  close_organization_invitation(original_action_hash, InvitationStatus::Declined)?;
+
+  Ok(true)
+}
+
+/// Cancels an open invitation. Only the agent who sent it can cancel it.
+#[hdk_extern]
+pub fn cancel_organization_invitation(original_action_hash: ActionHash) -> ExternResult<bool> {
+  let original_record = must_get_valid_record(original_action_hash.clone())?;
+  if *original_record.action().author() != agent_info()?.agent_initial_pubkey {
+    return Err(wasm_error!(Guest(
+      "Only the inviter can cancel an invitation".to_string()
+    )));
+  }
+
+  close_organization_invitation(original_action_hash, InvitationStatus::Cancelled)?;
+
+  Ok(true)
+}
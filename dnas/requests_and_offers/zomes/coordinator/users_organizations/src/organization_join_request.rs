@@ -0,0 +1,264 @@
+use hdk::prelude::*;
+use users_organizations_integrity::*;
+use utils::{errors::UtilsError, OrganizationUser};
+use WasmErrorInner::*;
+
+use crate::{
+  organization::{is_organization_accepted, is_organization_member, link_organization_member},
+  organization_role::{build_organization_authority, check_if_agent_has_permission},
+  user::{get_agent_user, get_my_user_agent_link},
+};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct JoinRequestInput {
+  pub organization_original_action_hash: ActionHash,
+  pub message: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RejectJoinRequestInput {
+  pub original_action_hash: ActionHash,
+  pub reason: String,
+}
+
+fn is_pending(join_request: &OrganizationJoinRequest) -> bool {
+  join_request.status == JoinRequestStatus::Pending.to_string()
+}
+
+/// Asks to join an accepted organization as a member.
+#[hdk_extern]
+pub fn request_to_join_organization(input: JoinRequestInput) -> ExternResult<Record> {
+  let requester = get_agent_user(agent_info()?.agent_initial_pubkey)?
+    .first()
+    .and_then(|link| link.target.clone().into_action_hash())
+    .ok_or(wasm_error!(Guest(
+      "You must first create a User profile".to_string()
+    )))?;
+
+  if !is_organization_accepted(&input.organization_original_action_hash)? {
+    return Err(wasm_error!(Guest(
+      "Cannot join an organization that is not accepted".to_string()
+    )));
+  }
+
+  if is_organization_member(OrganizationUser {
+    organization_original_action_hash: input.organization_original_action_hash.clone(),
+    user_original_action_hash: requester.clone(),
+  })? {
+    return Err(wasm_error!(Guest(
+      "You are already a member of this organization".to_string()
+    )));
+  }
+
+  for link in get_organization_join_requests_links(input.organization_original_action_hash.clone())?
+  {
+    let join_request_original_action_hash = link
+      .target
+      .into_action_hash()
+      .ok_or(UtilsError::ActionHashNotFound("join request"))?;
+    let join_request = get_latest_organization_join_request(join_request_original_action_hash)?;
+    if join_request.requester == requester && is_pending(&join_request) {
+      return Err(wasm_error!(Guest(
+        "You already have a pending join request for this organization".to_string()
+      )));
+    }
+  }
+
+  let join_request = OrganizationJoinRequest {
+    organization: input.organization_original_action_hash.clone(),
+    requester_agent_link: get_my_user_agent_link(requester.clone())?.create_link_hash,
+    requester,
+    message: input.message,
+    status: JoinRequestStatus::Pending.to_string(),
+    rejection_reason: None,
+    decision_authority: None,
+  };
+
+  let join_request_hash = create_entry(&EntryTypes::OrganizationJoinRequest(join_request))?;
+  let record = get(join_request_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(Guest(
+    "Could not find the newly created OrganizationJoinRequest".to_string()
+  )))?;
+
+  create_link(
+    input.organization_original_action_hash,
+    join_request_hash,
+    LinkTypes::OrganizationJoinRequests,
+    (),
+  )?;
+
+  Ok(record)
+}
+
+#[hdk_extern]
+pub fn get_latest_organization_join_request_record(
+  original_action_hash: ActionHash,
+) -> ExternResult<Option<Record>> {
+  let links = get_links(
+    GetLinksInputBuilder::try_new(
+      original_action_hash.clone(),
+      LinkTypes::OrganizationJoinRequestUpdates,
+    )?
+    .build(),
+  )?;
+  let latest_link = links
+    .into_iter()
+    .max_by(|link_a, link_b| link_a.timestamp.cmp(&link_b.timestamp));
+  let latest_join_request_hash = match latest_link {
+    Some(link) => link
+      .target
+      .clone()
+      .into_action_hash()
+      .ok_or(UtilsError::ActionHashNotFound("join request"))?,
+    None => original_action_hash.clone(),
+  };
+  get(latest_join_request_hash, GetOptions::default())
+}
+
+#[hdk_extern]
+pub fn get_latest_organization_join_request(
+  original_action_hash: ActionHash,
+) -> ExternResult<OrganizationJoinRequest> {
+  let latest_join_request_record =
+    get_latest_organization_join_request_record(original_action_hash)?;
+  let latest_join_request: OrganizationJoinRequest = latest_join_request_record
+    .ok_or(wasm_error!(Guest(
+      "Could not find the latest OrganizationJoinRequest".to_string()
+    )))?
+    .entry()
+    .to_app_option()
+    .map_err(|e| wasm_error!(Serialize(e)))?
+    .ok_or(wasm_error!(Guest(
+      "Could not find the latest OrganizationJoinRequest".to_string()
+    )))?;
+
+  Ok(latest_join_request)
+}
+
+/// Returns the links to all the join requests of an organization, whatever their status.
+#[hdk_extern]
+pub fn get_organization_join_requests_links(
+  organization_original_action_hash: ActionHash,
+) -> ExternResult<Vec<Link>> {
+  get_links(
+    GetLinksInputBuilder::try_new(
+      organization_original_action_hash,
+      LinkTypes::OrganizationJoinRequests,
+    )?
+    .build(),
+  )
+}
+
+/// Returns the links to the join requests of an organization waiting for a decision. Requires
+/// the 'manage_members' permission.
+#[hdk_extern]
+pub fn get_pending_join_requests_links(
+  organization_original_action_hash: ActionHash,
+) -> ExternResult<Vec<Link>> {
+  if !check_if_agent_has_permission(
+    organization_original_action_hash.clone(),
+    OrganizationPermission::ManageMembers,
+  )? {
+    return Err(wasm_error!(Guest(
+      "You are not allowed to list the join requests of this organization".to_string()
+    )));
+  }
+
+  let mut pending_links = vec![];
+  for link in get_organization_join_requests_links(organization_original_action_hash)? {
+    let join_request_original_action_hash = link
+      .target
+      .clone()
+      .into_action_hash()
+      .ok_or(UtilsError::ActionHashNotFound("join request"))?;
+    if is_pending(&get_latest_organization_join_request(
+      join_request_original_action_hash,
+    )?) {
+      pending_links.push(link);
+    }
+  }
+
+  Ok(pending_links)
+}
+
+/// Records a decision on a pending join request, returning the updated join request.
+fn decide_on_join_request(
+  original_action_hash: ActionHash,
+  status: JoinRequestStatus,
+  rejection_reason: Option<String>,
+) -> ExternResult<OrganizationJoinRequest> {
+  let latest_record = get_latest_organization_join_request_record(original_action_hash.clone())?
+    .ok_or(wasm_error!(Guest(
+      "Could not find the latest OrganizationJoinRequest".to_string()
+    )))?;
+  let join_request: OrganizationJoinRequest = latest_record
+    .entry()
+    .to_app_option()
+    .map_err(|e| wasm_error!(Serialize(e)))?
+    .ok_or(wasm_error!(Guest(
+      "Could not find the latest OrganizationJoinRequest".to_string()
+    )))?;
+
+  if !check_if_agent_has_permission(
+    join_request.organization.clone(),
+    OrganizationPermission::ManageMembers,
+  )? {
+    return Err(wasm_error!(Guest(
+      "You are not allowed to decide on the join requests of this organization".to_string()
+    )));
+  }
+
+  if !is_pending(&join_request) {
+    return Err(wasm_error!(Guest(
+      "This join request was already decided".to_string()
+    )));
+  }
+
+  let join_request = OrganizationJoinRequest {
+    status: status.to_string(),
+    rejection_reason,
+    decision_authority: Some(build_organization_authority(
+      join_request.organization.clone(),
+      OrganizationPermission::ManageMembers,
+    )?),
+    ..join_request
+  };
+  let updated_join_request_hash =
+    update_entry(latest_record.action_address().clone(), &join_request)?;
+
+  create_link(
+    original_action_hash,
+    updated_join_request_hash,
+    LinkTypes::OrganizationJoinRequestUpdates,
+    (),
+  )?;
+
+  Ok(join_request)
+}
+
+/// Approves a pending join request, making its requester a member of the organization.
+#[hdk_extern]
+pub fn approve_join_request(original_action_hash: ActionHash) -> ExternResult<bool> {
+  let join_request =
+    decide_on_join_request(original_action_hash, JoinRequestStatus::Approved, None)?;
+
+  let organization_user = OrganizationUser {
+    organization_original_action_hash: join_request.organization,
+    user_original_action_hash: join_request.requester,
+  };
+  if !is_organization_member(organization_user.clone())? {
+    link_organization_member(organization_user)?;
+  }
+
+  Ok(true)
+}
+
+#[hdk_extern]
+pub fn reject_join_request(input: RejectJoinRequestInput) -> ExternResult<bool> {
+  decide_on_join_request(
+    input.original_action_hash,
+    JoinRequestStatus::Rejected,
+    Some(input.reason),
+  )?;
+
+  Ok(true)
+}
@@ -0,0 +1,438 @@
+use std::str::FromStr;
+
+use hdk::prelude::*;
+use users_organizations_integrity::*;
+use utils::{errors::UtilsError, OrganizationUser};
+use WasmErrorInner::*;
+
+use crate::{
+  organization::{
+    build_coordinator_on_behalf_of, is_organization_coordinator, is_organization_member,
+  },
+  user::{get_my_user_agent_link, get_my_user_original_action_hash},
+};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct OrganizationPermissionInput {
+  pub organization_original_action_hash: ActionHash,
+  pub permission: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UpdateOrganizationRoleInput {
+  pub original_action_hash: ActionHash,
+  pub previous_action_hash: ActionHash,
+  pub updated_role: OrganizationRole,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RoleAssignmentInput {
+  pub role_original_action_hash: ActionHash,
+  pub user_original_action_hash: ActionHash,
+}
+
+/// Checks if a user holds a permission in an organization, either as a coordinator or through
+/// one of the roles assigned to them as a member.
+pub(crate) fn check_if_user_has_organization_permission(
+  input: OrganizationUser,
+  permission: OrganizationPermission,
+) -> ExternResult<bool> {
+  if is_organization_coordinator(input.clone())? {
+    return Ok(true);
+  }
+
+  // Roles of users who left the organization grant nothing
+  if !is_organization_member(input.clone())? {
+    return Ok(false);
+  }
+
+  for link in get_user_roles_links(input.user_original_action_hash)? {
+    let role_original_action_hash = link
+      .target
+      .into_action_hash()
+      .ok_or(UtilsError::ActionHashNotFound("organization role"))?;
+    let Some(role) = get_latest_organization_role(role_original_action_hash)? else {
+      continue;
+    };
+
+    if role.organization == input.organization_original_action_hash
+      && role.permissions.contains(&permission.to_string())
+    {
+      return Ok(true);
+    }
+  }
+
+  Ok(false)
+}
+
+/// Checks if the current agent holds a permission in an organization.
+pub(crate) fn check_if_agent_has_permission(
+  organization_original_action_hash: ActionHash,
+  permission: OrganizationPermission,
+) -> ExternResult<bool> {
+  check_if_user_has_organization_permission(
+    OrganizationUser {
+      organization_original_action_hash,
+      user_original_action_hash: get_my_user_original_action_hash()?,
+    },
+    permission,
+  )
+}
+
+#[hdk_extern]
+pub fn check_if_agent_has_organization_permission(
+  input: OrganizationPermissionInput,
+) -> ExternResult<bool> {
+  let permission =
+    OrganizationPermission::from_str(&input.permission).map_err(|e| wasm_error!(Guest(e)))?;
+
+  check_if_agent_has_permission(input.organization_original_action_hash, permission)
+}
+
+fn check_if_agent_can_manage_roles(
+  organization_original_action_hash: ActionHash,
+) -> ExternResult<()> {
+  if !check_if_agent_has_permission(
+    organization_original_action_hash,
+    OrganizationPermission::ManageRoles,
+  )? {
+    return Err(wasm_error!(Guest(
+      "You are not allowed to manage the roles of this organization".to_string()
+    )));
+  }
+
+  Ok(())
+}
+
+/// Builds the proof that the current agent holds a permission in an organization, as one of its
+/// coordinators or through one of the roles assigned to their user.
+pub(crate) fn build_organization_authority(
+  organization_original_action_hash: ActionHash,
+  permission: OrganizationPermission,
+) -> ExternResult<OrganizationAuthority> {
+  let user_original_action_hash = get_my_user_original_action_hash()?;
+  if is_organization_coordinator(OrganizationUser {
+    organization_original_action_hash: organization_original_action_hash.clone(),
+    user_original_action_hash: user_original_action_hash.clone(),
+  })? {
+    return Ok(OrganizationAuthority::Coordinator(
+      build_coordinator_on_behalf_of(organization_original_action_hash)?,
+    ));
+  }
+
+  for link in get_user_roles_links(user_original_action_hash.clone())? {
+    let role_original_action_hash = link
+      .target
+      .into_action_hash()
+      .ok_or(UtilsError::ActionHashNotFound("organization role"))?;
+    let Some(role_record) = get_latest_organization_role_record(role_original_action_hash.clone())?
+    else {
+      continue;
+    };
+    let Some(role) = role_record
+      .entry()
+      .to_app_option::<OrganizationRole>()
+      .map_err(|e| wasm_error!(Serialize(e)))?
+    else {
+      continue;
+    };
+    if role.organization != organization_original_action_hash
+      || !role.permissions.contains(&permission.to_string())
+    {
+      continue;
+    }
+
+    let Some(role_assignment_link) = get_role_users_links(role_original_action_hash)?
+      .into_iter()
+      .find(|link| {
+        link.target.clone().into_action_hash() == Some(user_original_action_hash.clone())
+      })
+    else {
+      continue;
+    };
+
+    return Ok(OrganizationAuthority::Role(RoleGrant {
+      role: role_record.action_address().clone(),
+      role_assignment_link: role_assignment_link.create_link_hash,
+      user_agent_link: get_my_user_agent_link(user_original_action_hash)?.create_link_hash,
+    }));
+  }
+
+  Err(wasm_error!(Guest(format!(
+    "Could not find a role granting the '{}' permission",
+    permission
+  ))))
+}
+
+/// Creates the `OrganizationAuthorityProofs` link proving that the current agent holds a
+/// permission in the organization. Integrity validation expects it as the action right before
+/// the action requiring the permission.
+pub(crate) fn create_authority_proof(
+  organization_original_action_hash: ActionHash,
+  permission: OrganizationPermission,
+) -> ExternResult<()> {
+  let authority =
+    build_organization_authority(organization_original_action_hash.clone(), permission)?;
+
+  create_link(
+    organization_original_action_hash,
+    get_my_user_original_action_hash()?,
+    LinkTypes::OrganizationAuthorityProofs,
+    LinkTag::new(encode(&authority).map_err(|e| wasm_error!(Serialize(e)))?),
+  )?;
+
+  Ok(())
+}
+
+fn check_if_role_name_is_free(
+  role: &OrganizationRole,
+  role_original_action_hash: Option<&ActionHash>,
+) -> ExternResult<()> {
+  for link in get_organization_roles_links(role.organization.clone())? {
+    let other_role_original_action_hash = link
+      .target
+      .into_action_hash()
+      .ok_or(UtilsError::ActionHashNotFound("organization role"))?;
+    if Some(&other_role_original_action_hash) == role_original_action_hash {
+      continue;
+    }
+
+    if let Some(other_role) = get_latest_organization_role(other_role_original_action_hash)? {
+      if other_role.name.to_lowercase() == role.name.to_lowercase() {
+        return Err(wasm_error!(Guest(
+          "This organization already has a role with this name".to_string()
+        )));
+      }
+    }
+  }
+
+  Ok(())
+}
+
+#[hdk_extern]
+pub fn create_organization_role(role: OrganizationRole) -> ExternResult<Record> {
+  check_if_agent_can_manage_roles(role.organization.clone())?;
+  check_if_role_name_is_free(&role, None)?;
+
+  create_authority_proof(
+    role.organization.clone(),
+    OrganizationPermission::ManageRoles,
+  )?;
+  let role_hash = create_entry(&EntryTypes::OrganizationRole(role.clone()))?;
+  let record = get(role_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(Guest(
+    "Could not find the newly created OrganizationRole".to_string()
+  )))?;
+
+  create_link(
+    role.organization,
+    role_hash,
+    LinkTypes::OrganizationRoles,
+    (),
+  )?;
+
+  Ok(record)
+}
+
+#[hdk_extern]
+pub fn get_organization_roles_links(
+  organization_original_action_hash: ActionHash,
+) -> ExternResult<Vec<Link>> {
+  get_links(
+    GetLinksInputBuilder::try_new(
+      organization_original_action_hash,
+      LinkTypes::OrganizationRoles,
+    )?
+    .build(),
+  )
+}
+
+#[hdk_extern]
+pub fn get_latest_organization_role_record(
+  original_action_hash: ActionHash,
+) -> ExternResult<Option<Record>> {
+  let links = get_links(
+    GetLinksInputBuilder::try_new(
+      original_action_hash.clone(),
+      LinkTypes::OrganizationRoleUpdates,
+    )?
+    .build(),
+  )?;
+  let latest_link = links
+    .into_iter()
+    .max_by(|link_a, link_b| link_a.timestamp.cmp(&link_b.timestamp));
+  let latest_role_hash = match latest_link {
+    Some(link) => link
+      .target
+      .clone()
+      .into_action_hash()
+      .ok_or(UtilsError::ActionHashNotFound("organization role"))?,
+    None => original_action_hash.clone(),
+  };
+  get(latest_role_hash, GetOptions::default())
+}
+
+/// Returns the latest version of a role, or `None` if it cannot be found.
+#[hdk_extern]
+pub fn get_latest_organization_role(
+  original_action_hash: ActionHash,
+) -> ExternResult<Option<OrganizationRole>> {
+  let Some(latest_role_record) = get_latest_organization_role_record(original_action_hash)? else {
+    return Ok(None);
+  };
+
+  latest_role_record
+    .entry()
+    .to_app_option()
+    .map_err(|e| wasm_error!(Serialize(e)))
+}
+
+#[hdk_extern]
+pub fn update_organization_role(input: UpdateOrganizationRoleInput) -> ExternResult<Record> {
+  let Some(role) = get_latest_organization_role(input.original_action_hash.clone())? else {
+    return Err(wasm_error!(Guest(
+      "Could not find the OrganizationRole".to_string()
+    )));
+  };
+  check_if_agent_can_manage_roles(role.organization.clone())?;
+  check_if_role_name_is_free(&input.updated_role, Some(&input.original_action_hash))?;
+
+  create_authority_proof(role.organization, OrganizationPermission::ManageRoles)?;
+  let updated_role_hash = update_entry(input.previous_action_hash, &input.updated_role)?;
+
+  create_link(
+    input.original_action_hash,
+    updated_role_hash.clone(),
+    LinkTypes::OrganizationRoleUpdates,
+    (),
+  )?;
+
+  let record = get(updated_role_hash, GetOptions::default())?.ok_or(wasm_error!(Guest(
+    "Could not find the newly updated OrganizationRole".to_string()
+  )))?;
+
+  Ok(record)
+}
+
+/// Deletes a role, removing it from the members it was assigned to.
+#[hdk_extern]
+pub fn delete_organization_role(role_original_action_hash: ActionHash) -> ExternResult<bool> {
+  let Some(role) = get_latest_organization_role(role_original_action_hash.clone())? else {
+    return Err(wasm_error!(Guest(
+      "Could not find the OrganizationRole".to_string()
+    )));
+  };
+  check_if_agent_can_manage_roles(role.organization.clone())?;
+
+  for link in get_role_users_links(role_original_action_hash.clone())? {
+    if let Some(user_original_action_hash) = link.target.into_action_hash() {
+      delete_role_assignment_links(&role_original_action_hash, &user_original_action_hash)?;
+    }
+  }
+
+  for link in get_organization_roles_links(role.organization.clone())? {
+    if link.target.clone().into_action_hash() == Some(role_original_action_hash.clone()) {
+      delete_link(link.create_link_hash)?;
+    }
+  }
+
+  create_authority_proof(role.organization, OrganizationPermission::ManageRoles)?;
+  delete_entry(role_original_action_hash)?;
+
+  Ok(true)
+}
+
+/// Returns the links to the users a role is assigned to.
+#[hdk_extern]
+pub fn get_role_users_links(role_original_action_hash: ActionHash) -> ExternResult<Vec<Link>> {
+  get_links(GetLinksInputBuilder::try_new(role_original_action_hash, LinkTypes::RoleUsers)?.build())
+}
+
+/// Returns the links to the roles assigned to a user, in any organization.
+#[hdk_extern]
+pub fn get_user_roles_links(user_original_action_hash: ActionHash) -> ExternResult<Vec<Link>> {
+  get_links(GetLinksInputBuilder::try_new(user_original_action_hash, LinkTypes::UserRoles)?.build())
+}
+
+/// Assigns a role to a member of its organization.
+#[hdk_extern]
+pub fn assign_organization_role(input: RoleAssignmentInput) -> ExternResult<bool> {
+  let Some(role) = get_latest_organization_role(input.role_original_action_hash.clone())? else {
+    return Err(wasm_error!(Guest(
+      "Could not find the OrganizationRole".to_string()
+    )));
+  };
+  check_if_agent_can_manage_roles(role.organization.clone())?;
+
+  if !is_organization_member(OrganizationUser {
+    organization_original_action_hash: role.organization.clone(),
+    user_original_action_hash: input.user_original_action_hash.clone(),
+  })? {
+    return Err(wasm_error!(Guest(
+      "Roles can only be assigned to members of the organization".to_string()
+    )));
+  }
+
+  let is_assigned = get_role_users_links(input.role_original_action_hash.clone())?
+    .into_iter()
+    .any(|link| link.target.into_action_hash() == Some(input.user_original_action_hash.clone()));
+  if is_assigned {
+    return Err(wasm_error!(Guest(
+      "The user already has this role".to_string()
+    )));
+  }
+
+  // The assignment carries the authority of the agent making it, checked by integrity validation
+  let authority =
+    build_organization_authority(role.organization, OrganizationPermission::ManageRoles)?;
+  create_link(
+    input.role_original_action_hash.clone(),
+    input.user_original_action_hash.clone(),
+    LinkTypes::RoleUsers,
+    LinkTag::new(encode(&authority).map_err(|e| wasm_error!(Serialize(e)))?),
+  )?;
+
+  create_link(
+    input.user_original_action_hash,
+    input.role_original_action_hash,
+    LinkTypes::UserRoles,
+    (),
+  )?;
+
+  Ok(true)
+}
+
+fn delete_role_assignment_links(
+  role_original_action_hash: &ActionHash,
+  user_original_action_hash: &ActionHash,
+) -> ExternResult<()> {
+  for link in get_role_users_links(role_original_action_hash.clone())? {
+    if link.target.clone().into_action_hash().as_ref() == Some(user_original_action_hash) {
+      delete_link(link.create_link_hash)?;
+    }
+  }
+
+  for link in get_user_roles_links(user_original_action_hash.clone())? {
+    if link.target.clone().into_action_hash().as_ref() == Some(role_original_action_hash) {
+      delete_link(link.create_link_hash)?;
+    }
+  }
+
+  Ok(())
+}
+
+#[hdk_extern]
+pub fn unassign_organization_role(input: RoleAssignmentInput) -> ExternResult<bool> {
+  let Some(role) = get_latest_organization_role(input.role_original_action_hash.clone())? else {
+    return Err(wasm_error!(Guest(
+      "Could not find the OrganizationRole".to_string()
+    )));
+  };
+  check_if_agent_can_manage_roles(role.organization)?;
+
+  delete_role_assignment_links(
+    &input.role_original_action_hash,
+    &input.user_original_action_hash,
+  )?;
+
+  Ok(true)
+}
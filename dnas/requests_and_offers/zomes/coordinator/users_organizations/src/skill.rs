@@ -0,0 +1,93 @@
+use std::collections::{HashMap, HashSet};
+
+use hdk::prelude::*;
+use users_organizations_integrity::*;
+use utils::errors::UtilsError;
+use WasmErrorInner::*;
+
+use crate::{
+  external_calls::{get_accepted_entities, get_skill_link, get_skill_users_links, set_user_skills},
+  user::{get_agent_user, get_latest_user},
+};
+
+/// An accepted user matching some of the searched skills.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UserSkillsMatch {
+  pub user_original_action_hash: ActionHash,
+  pub user: User,
+  /// The canonical names of the searched skills the user has.
+  pub matching_skills: Vec<String>,
+}
+
+/// Returns the accepted users having at least one of the given skills, the users matching the
+/// most skills first.
+#[hdk_extern]
+pub fn find_users_by_skills(skills: Vec<String>) -> ExternResult<Vec<UserSkillsMatch>> {
+  let mut matching_skills: HashMap<ActionHash, Vec<String>> = HashMap::new();
+  for skill in skills {
+    let Some(skill_link) = get_skill_link(skill)? else {
+      continue;
+    };
+    let skill_name = String::from_utf8(skill_link.tag.into_inner())
+      .map_err(|_| wasm_error!(Guest("Invalid skill name".to_string())))?;
+    let skill_action_hash = skill_link
+      .target
+      .into_action_hash()
+      .ok_or(UtilsError::ActionHashNotFound("skill"))?;
+
+    for link in get_skill_users_links(skill_action_hash)? {
+      if let Some(user_original_action_hash) = link.target.into_action_hash() {
+        let user_skills = matching_skills
+          .entry(user_original_action_hash)
+          .or_default();
+        if !user_skills.contains(&skill_name) {
+          user_skills.push(skill_name.clone());
+        }
+      }
+    }
+  }
+
+  let accepted_users: HashSet<ActionHash> = get_accepted_entities(String::from("users"))?
+    .into_iter()
+    .filter_map(|link| link.target.into_action_hash())
+    .collect();
+
+  let mut matches = matching_skills
+    .into_iter()
+    .filter(|(user_original_action_hash, _)| accepted_users.contains(user_original_action_hash))
+    .map(|(user_original_action_hash, matching_skills)| {
+      Ok(UserSkillsMatch {
+        user: get_latest_user(user_original_action_hash.clone())?,
+        user_original_action_hash,
+        matching_skills,
+      })
+    })
+    .collect::<ExternResult<Vec<UserSkillsMatch>>>()?;
+
+  matches.sort_by(|match_a, match_b| {
+    match_b
+      .matching_skills
+      .len()
+      .cmp(&match_a.matching_skills.len())
+      .then_with(|| match_a.user.name.cmp(&match_b.user.name))
+  });
+
+  Ok(matches)
+}
+
+/// Links the current agent's User profile to the canonical skills matching its free-text
+/// skills. Profiles created before the skills zome existed must run it once.
+#[hdk_extern]
+pub fn migrate_my_user_skills(_: ()) -> ExternResult<Vec<ActionHash>> {
+  let user_links = get_agent_user(agent_info()?.agent_initial_pubkey)?;
+  let user_original_action_hash = user_links
+    .first()
+    .and_then(|link| link.target.clone().into_action_hash())
+    .ok_or(wasm_error!(Guest(
+      "You must first create a User profile".to_string()
+    )))?;
+
+  let user = get_latest_user(user_original_action_hash.clone())?;
+
+  set_user_skills(user_original_action_hash, user.skill_names())
+}
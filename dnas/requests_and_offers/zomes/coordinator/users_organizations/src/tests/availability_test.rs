@@ -0,0 +1,86 @@
+#[cfg(test)]
+mod tests {
+  use std::str::FromStr;
+
+  use hdk::prelude::Timestamp;
+  use users_organizations_integrity::AvailabilityWindow;
+  use utils::time_zone::UserTimeZone;
+
+  use crate::availability::next_availability_slots;
+
+  /// Monday 2024-01-01 00:00 UTC, in minutes.
+  const MONDAY: i64 = 28_401_120;
+  const UTC: UserTimeZone = UserTimeZone::FixedOffset(0);
+
+  fn minutes(timestamp: Timestamp) -> i64 {
+    timestamp.as_micros() / 60_000_000
+  }
+
+  fn at(minute: i64) -> Timestamp {
+    Timestamp::from_micros(minute * 60_000_000)
+  }
+
+  fn tuesday_hour() -> AvailabilityWindow {
+    AvailabilityWindow {
+      weekday: 1,
+      start_minute: 18 * 60,
+      duration_minutes: 60,
+    }
+  }
+
+  #[test]
+  fn test_next_availability_slots() {
+    // On Wednesday, the next Tuesday slot is the following week
+    let slots = next_availability_slots(&[tuesday_hour()], &UTC, at(MONDAY + 2 * 24 * 60), 2);
+    assert_eq!(slots.len(), 2);
+    assert_eq!(minutes(slots[0].start), MONDAY + 8 * 24 * 60 + 18 * 60);
+    assert_eq!(minutes(slots[0].end), MONDAY + 8 * 24 * 60 + 19 * 60);
+    assert_eq!(minutes(slots[1].start), MONDAY + 15 * 24 * 60 + 18 * 60);
+
+    // Windows are written in the user's time zone
+    let slots = next_availability_slots(
+      &[tuesday_hour()],
+      &UserTimeZone::FixedOffset(120),
+      at(MONDAY),
+      1,
+    );
+    assert_eq!(minutes(slots[0].start), MONDAY + 24 * 60 + 16 * 60);
+
+    // Slots of several windows are returned in order
+    let windows = vec![
+      tuesday_hour(),
+      AvailabilityWindow {
+        weekday: 0,
+        start_minute: 9 * 60,
+        duration_minutes: 30,
+      },
+    ];
+    let slots = next_availability_slots(&windows, &UTC, at(MONDAY), 3);
+    assert_eq!(minutes(slots[0].start), MONDAY + 9 * 60);
+    assert_eq!(minutes(slots[1].start), MONDAY + 24 * 60 + 18 * 60);
+    assert_eq!(minutes(slots[2].start), MONDAY + 7 * 24 * 60 + 9 * 60);
+
+    assert!(next_availability_slots(&[], &UTC, at(MONDAY), 3).is_empty());
+  }
+
+  #[test]
+  fn test_next_availability_slots_follow_daylight_saving_time() {
+    let paris = UserTimeZone::from_str("Europe/Paris").unwrap();
+
+    // Paris is one hour ahead of UTC in winter
+    let slots = next_availability_slots(&[tuesday_hour()], &paris, at(MONDAY), 1);
+    assert_eq!(minutes(slots[0].start), MONDAY + 24 * 60 + 17 * 60);
+
+    // And two hours ahead in summer, from Sunday 2024-03-31
+    let slots =
+      next_availability_slots(&[tuesday_hour()], &paris, at(MONDAY + 12 * 7 * 24 * 60), 2);
+    assert_eq!(
+      minutes(slots[0].start),
+      MONDAY + 12 * 7 * 24 * 60 + 24 * 60 + 17 * 60
+    );
+    assert_eq!(
+      minutes(slots[1].start),
+      MONDAY + 13 * 7 * 24 * 60 + 24 * 60 + 16 * 60
+    );
+  }
+}
@@ -0,0 +1 @@
+mod availability_test;
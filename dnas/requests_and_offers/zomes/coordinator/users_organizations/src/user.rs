@@ -3,7 +3,7 @@ use users_organizations_integrity::*;
 use utils::errors::UtilsError;
 use WasmErrorInner::*;
 
-use crate::external_calls::create_status;
+use crate::external_calls::{create_status, map_canonical_skill_names, set_user_skills};
 
 #[hdk_extern]
 pub fn create_user(user: User) -> ExternResult<Record> {
@@ -50,6 +50,8 @@ pub fn create_user(user: User) -> ExternResult<Record> {
     (),
   )?;
 
+  set_user_skills(user_hash, user.skill_names())?;
+
   Ok(record)
 }
 
@@ -72,6 +74,38 @@ pub fn get_latest_user_record(original_action_hash: ActionHash) -> ExternResult<
   get(latest_user_hash, GetOptions::default())
 }
 
+/// A User profile along with its skills mapped to their canonical names.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UserProfile {
+  /// The profile as stored, with the skill names written by the user.
+  pub user: User,
+  /// The skills of the profile under their canonical names, as used to find and endorse users.
+  pub canonical_skills: Vec<UserSkill>,
+}
+
+/// Maps the names of a user's skills to the names of their canonical skills. Skills merged into
+/// the same canonical skill keep the level of the first one listed.
+fn canonicalize_user_skills(skills: &[UserSkill]) -> ExternResult<Vec<UserSkill>> {
+  let names = map_canonical_skill_names(skills.iter().map(|skill| skill.name.clone()).collect())?;
+
+  let mut canonical_skills: Vec<UserSkill> = vec![];
+  for (skill, name) in skills.iter().zip(names) {
+    if name.is_empty()
+      || canonical_skills
+        .iter()
+        .any(|canonical_skill| canonical_skill.name == name)
+    {
+      continue;
+    }
+    canonical_skills.push(UserSkill {
+      name,
+      ..skill.clone()
+    });
+  }
+
+  Ok(canonical_skills)
+}
+
 #[hdk_extern]
 pub fn get_latest_user(original_action_hash: ActionHash) -> ExternResult<User> {
   let latest_user_record = get_latest_user_record(original_action_hash)?;
@@ -88,11 +122,52 @@ pub fn get_latest_user(original_action_hash: ActionHash) -> ExternResult<User> {
   Ok(latest_user)
 }
 
+/// Returns the latest version of a User profile along with its skills mapped to their
+/// canonical names.
+#[hdk_extern]
+pub fn get_latest_user_profile(original_action_hash: ActionHash) -> ExternResult<UserProfile> {
+  let user = get_latest_user(original_action_hash)?;
+  let canonical_skills = canonicalize_user_skills(&user.skills)?;
+
+  Ok(UserProfile {
+    user,
+    canonical_skills,
+  })
+}
+
 #[hdk_extern]
 pub fn get_agent_user(author: AgentPubKey) -> ExternResult<Vec<Link>> {
   get_links(GetLinksInputBuilder::try_new(author, LinkTypes::MyUser)?.build())
 }
 
+/// Returns the original action hash of the current agent's User profile.
+pub(crate) fn get_my_user_original_action_hash() -> ExternResult<ActionHash> {
+  get_agent_user(agent_info()?.agent_initial_pubkey)?
+    .first()
+    .and_then(|link| link.target.clone().into_action_hash())
+    .ok_or(wasm_error!(Guest(
+      "You must first create a User profile".to_string()
+    )))
+}
+
+#[hdk_extern]
+pub fn get_user_agents_links(user_original_action_hash: ActionHash) -> ExternResult<Vec<Link>> {
+  get_links(
+    GetLinksInputBuilder::try_new(user_original_action_hash, LinkTypes::UserAgents)?.build(),
+  )
+}
+
+/// Returns the `UserAgents` link from the given user to the current agent.
+pub(crate) fn get_my_user_agent_link(user_original_action_hash: ActionHash) -> ExternResult<Link> {
+  let my_pubkey = agent_info()?.agent_initial_pubkey;
+  get_user_agents_links(user_original_action_hash)?
+    .into_iter()
+    .find(|link| link.target.clone().into_agent_pub_key() == Some(my_pubkey.clone()))
+    .ok_or(wasm_error!(Guest(
+      "Could not find the user agent link".to_string()
+    )))
+}
+
 #[hdk_extern]
 pub fn get_user_agents(user_original_action_hash: ActionHash) -> ExternResult<Vec<AgentPubKey>> {
   let links = get_links(
@@ -126,6 +201,11 @@ pub fn update_user(input: UpdateUserInput) -> ExternResult<Record> {
 
   let updated_user_hash = update_entry(input.previous_action_hash.clone(), &input.updated_user)?;
 
+  set_user_skills(
+    input.original_action_hash.clone(),
+    input.updated_user.skill_names(),
+  )?;
+
   create_link(
     input.original_action_hash.clone(),
     updated_user_hash.clone(),
@@ -12,16 +12,12 @@ pub enum EntryTypes {
   Status(Status),
 }
 
-#[derive(Serialize, Deserialize)]
-#[hdk_link_types]
-pub enum LinkTypes {
-  AllAdministrators,
-  AgentAdministrators,
-  StatusUpdates,
-  AllStatuses,
-  EntityStatus,
-  AcceptedEntity,
-}
+// Declared in `utils` so that the other zomes resolve these link types from the same definition
+utils::administration_link_types!(
+  #[derive(Serialize, Deserialize)]
+  #[hdk_link_types]
+  pub enum LinkTypes
+);
 
 #[hdk_extern]
 pub fn genesis_self_check(_data: GenesisSelfCheckData) -> ExternResult<ValidateCallbackResult> {
@@ -11,6 +11,7 @@ pub enum StatusType {
   Rejected,
   SuspendedIndefinitely,
   SuspendedTemporarily,
+  Expired,
 }
 
 impl FromStr for StatusType {
@@ -23,6 +24,7 @@ impl FromStr for StatusType {
       "rejected" => Ok(Self::Rejected),
       "suspended indefinitely" => Ok(Self::SuspendedIndefinitely),
       "suspended temporarily" => Ok(Self::SuspendedTemporarily),
+      "expired" => Ok(Self::Expired),
       _ => Err(format!("Invalid status type: {}", s)),
     }
   }
@@ -61,10 +63,18 @@ impl Status {
     }
   }
 
+  pub fn expire() -> Self {
+    Self {
+      status_type: "expired".to_string(),
+      reason: None,
+      suspended_until: None,
+    }
+  }
+
   pub fn suspend(reason: &str, time: Option<(Duration, &Timestamp)>) -> Self {
-    if time.is_some() {
-      let duration = time.unwrap().0.num_microseconds().unwrap_or(0);
-      let now = time.unwrap().1.as_micros();
+    if let Some((duration, now)) = time {
+      let duration = duration.num_microseconds().unwrap_or(0);
+      let now = now.as_micros();
 
       return Self {
         status_type: "suspended temporarily".to_string(),
@@ -81,9 +91,9 @@ impl Status {
   }
 
   pub fn mut_suspend(&mut self, reason: &str, time: Option<(Duration, &Timestamp)>) {
-    if time.is_some() {
-      let duration = time.unwrap().0.num_microseconds().unwrap_or(0);
-      let now = time.unwrap().1.as_micros();
+    if let Some((duration, now)) = time {
+      let duration = duration.num_microseconds().unwrap_or(0);
+      let now = now.as_micros();
 
       self.status_type = "suspended temporarily".to_string();
       self.reason = Some(reason.to_string());
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod status_tests {
   use chrono::Duration;
-  use hdi::prelude::Timestamp;
+  use hdi::prelude::{Timestamp, ValidateCallbackResult};
   use utils::timetamp_now;
 
   use crate::status::*;
@@ -16,6 +16,10 @@ mod status_tests {
 
     status = Status::reject();
     assert_eq!(status.status_type, "rejected");
+
+    status = Status::expire();
+    assert_eq!(status.status_type, "expired");
+    assert!(validate_status(status).unwrap() == ValidateCallbackResult::Valid);
   }
 
   #[test]
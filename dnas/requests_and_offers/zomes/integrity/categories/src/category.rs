@@ -0,0 +1,199 @@
+use hdi::{hash_path::path::Path, prelude::*};
+use utils::{
+  countersigning::get_administration_link, errors::UtilsError, link_types::AdministrationLinkTypes,
+};
+
+/// The path the agents of the administrators of the network are linked to.
+const NETWORK_ADMINISTRATORS_PATH: &str = "network.administrators";
+
+/// Represents a Category Entry, created by administrators to classify offers, projects and
+/// organizations.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct Category {
+  /// The name of the category.
+  pub name: String,
+  /// The original action hash of the parent category, if any.
+  pub parent: Option<ActionHash>,
+  /// Whether the category was archived. Archived categories are hidden from navigation.
+  pub archived: bool,
+  /// The `AgentAdministrators` link of the author of an update, proving they are an
+  /// administrator of the network.
+  pub administrator_link: Option<ActionHash>,
+}
+
+/// Returns the `Path` component of a category name, such as "web-development" for
+/// "Web Development".
+pub fn category_path_component(name: &str) -> String {
+  name
+    .split_whitespace()
+    .collect::<Vec<&str>>()
+    .join("-")
+    .to_lowercase()
+}
+
+pub fn validate_category(category: Category) -> ExternResult<ValidateCallbackResult> {
+  if category_path_component(&category.name).is_empty() {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "Category name cannot be empty",
+    )));
+  }
+
+  if category.name.contains('.') {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "Category name cannot contain '.'",
+    )));
+  }
+
+  Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_create_category(category: Category) -> ExternResult<ValidateCallbackResult> {
+  let result = validate_category(category.clone())?;
+  if result != ValidateCallbackResult::Valid {
+    return Ok(result);
+  }
+
+  if category.archived {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "A Category cannot be created archived",
+    )));
+  }
+
+  if let Some(parent) = category.parent {
+    let record = must_get_valid_record(parent)?;
+    let _parent: Category = record
+      .entry()
+      .to_app_option()
+      .map_err(|e| wasm_error!(e))?
+      .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+        "The parent of a Category must be a Category"
+      ))))?;
+  }
+
+  Ok(ValidateCallbackResult::Valid)
+}
+
+/// Checks that a category is updated by its author or by an administrator of the network,
+/// citing the `AgentAdministrators` link of the author.
+pub fn validate_update_category(
+  action: Update,
+  category: Category,
+  original_action: EntryCreationAction,
+  original_category: Category,
+) -> ExternResult<ValidateCallbackResult> {
+  if category.parent != original_category.parent {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "The parent of a Category cannot be changed",
+    )));
+  }
+
+  if original_category.archived && !category.archived {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "An archived Category cannot be restored",
+    )));
+  }
+
+  if action.author == *original_action.author() {
+    return Ok(ValidateCallbackResult::Valid);
+  }
+
+  let administrator_link = match category.administrator_link {
+    Some(administrator_link) => get_administration_link(
+      administrator_link,
+      AdministrationLinkTypes::AgentAdministrators,
+    )?,
+    None => None,
+  };
+
+  Ok(check_administrator_link(
+    administrator_link.as_ref(),
+    &Path::from(NETWORK_ADMINISTRATORS_PATH).path_entry_hash()?,
+    &action.author,
+    action.timestamp,
+  ))
+}
+
+/// Checks the `AgentAdministrators` link cited by a category update, once fetched.
+pub fn check_administrator_link(
+  administrator_link: Option<&CreateLink>,
+  administrators_path_hash: &EntryHash,
+  author: &AgentPubKey,
+  timestamp: Timestamp,
+) -> ValidateCallbackResult {
+  let Some(administrator_link) = administrator_link else {
+    return ValidateCallbackResult::Invalid(String::from(
+      "Only the author of a Category or an administrator can update it",
+    ));
+  };
+
+  if administrator_link.base_address != AnyLinkableHash::from(author.clone())
+    || administrator_link.target_address != AnyLinkableHash::from(administrators_path_hash.clone())
+  {
+    return ValidateCallbackResult::Invalid(String::from(
+      "Only the author of a Category or an administrator can update it",
+    ));
+  }
+
+  if administrator_link.timestamp > timestamp {
+    return ValidateCallbackResult::Invalid(String::from(
+      "The author must be an administrator when updating the Category",
+    ));
+  }
+
+  ValidateCallbackResult::Valid
+}
+
+pub fn validate_delete_category(
+  _action: Delete,
+  _original_action: EntryCreationAction,
+  _original_category: Category,
+) -> ExternResult<ValidateCallbackResult> {
+  Ok(ValidateCallbackResult::Invalid(String::from(
+    "A Category cannot be deleted, archive it instead",
+  )))
+}
+
+pub fn validate_create_link_category_updates(
+  _action: CreateLink,
+  base_address: AnyLinkableHash,
+  target_address: AnyLinkableHash,
+  _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+  let action_hash = base_address
+    .into_action_hash()
+    .ok_or(UtilsError::ActionHashNotFound("category"))?;
+  let record = must_get_valid_record(action_hash)?;
+  let _category: crate::Category = record
+    .entry()
+    .to_app_option()
+    .map_err(|e| wasm_error!(e))?
+    .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+      "Linked action must reference an entry"
+    ))))?;
+  // Check the entry type for the given action hash
+  let action_hash = target_address
+    .into_action_hash()
+    .ok_or(UtilsError::ActionHashNotFound("category"))?;
+  let record = must_get_valid_record(action_hash)?;
+  let _category: crate::Category = record
+    .entry()
+    .to_app_option()
+    .map_err(|e| wasm_error!(e))?
+    .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+      "Linked action must reference an entry"
+    ))))?;
+  Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_category_updates(
+  _action: DeleteLink,
+  _original_action: CreateLink,
+  _base: AnyLinkableHash,
+  _target: AnyLinkableHash,
+  _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+  Ok(ValidateCallbackResult::Invalid(String::from(
+    "CategoryUpdates links cannot be deleted",
+  )))
+}
@@ -0,0 +1,145 @@
+#[cfg(test)]
+mod tests {
+  use hdi::prelude::*;
+  use utils::link_types::AdministrationLinkTypes;
+
+  use crate::{
+    category_path_component, check_administrator_link, validate_category, validate_create_category,
+    Category,
+  };
+
+  fn sample_category() -> Category {
+    Category {
+      name: "Web Development".to_string(),
+      parent: None,
+      archived: false,
+      administrator_link: None,
+    }
+  }
+
+  fn agent(byte: u8) -> AgentPubKey {
+    AgentPubKey::from_raw_36(vec![byte; 36])
+  }
+
+  fn administrators_path_hash() -> EntryHash {
+    EntryHash::from_raw_36(vec![4; 36])
+  }
+
+  fn administrator_link() -> CreateLink {
+    CreateLink {
+      author: agent(20),
+      timestamp: Timestamp::from_micros(100),
+      action_seq: 5,
+      prev_action: ActionHash::from_raw_36(vec![5; 36]),
+      base_address: agent(10).into(),
+      target_address: administrators_path_hash().into(),
+      zome_index: ZomeIndex(0),
+      link_type: AdministrationLinkTypes::AgentAdministrators.into(),
+      tag: LinkTag::new(vec![]),
+      weight: RateWeight::default(),
+    }
+  }
+
+  #[test]
+  fn test_category_path_component() {
+    assert_eq!(
+      category_path_component(" Web  Development "),
+      "web-development"
+    );
+    assert_eq!(category_path_component("Testing"), "testing");
+  }
+
+  #[test]
+  fn test_category_is_valid() {
+    let result = validate_create_category(sample_category()).unwrap();
+    assert!(result == ValidateCallbackResult::Valid);
+  }
+
+  #[test]
+  fn test_category_with_empty_name() {
+    let category = Category {
+      name: "  ".to_string(),
+      ..sample_category()
+    };
+    let result = validate_category(category).unwrap();
+    assert!(
+      result == ValidateCallbackResult::Invalid(String::from("Category name cannot be empty"))
+    );
+  }
+
+  #[test]
+  fn test_category_with_dot_in_name() {
+    let category = Category {
+      name: "Node.js".to_string(),
+      ..sample_category()
+    };
+    let result = validate_category(category).unwrap();
+    assert!(
+      result == ValidateCallbackResult::Invalid(String::from("Category name cannot contain '.'"))
+    );
+  }
+
+  #[test]
+  fn test_category_cannot_be_created_archived() {
+    let category = Category {
+      archived: true,
+      parent: Some(ActionHash::from_raw_36(vec![0; 36])),
+      ..sample_category()
+    };
+    let result = validate_create_category(category).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from("A Category cannot be created archived"))
+    );
+  }
+
+  #[test]
+  fn test_category_update_administrator_link() {
+    let result = check_administrator_link(
+      Some(&administrator_link()),
+      &administrators_path_hash(),
+      &agent(10),
+      Timestamp::from_micros(200),
+    );
+    assert!(result == ValidateCallbackResult::Valid);
+
+    let result = check_administrator_link(
+      Some(&administrator_link()),
+      &administrators_path_hash(),
+      &agent(11),
+      Timestamp::from_micros(200),
+    );
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "Only the author of a Category or an administrator can update it"
+        ))
+    );
+
+    let result = check_administrator_link(
+      Some(&administrator_link()),
+      &administrators_path_hash(),
+      &agent(10),
+      Timestamp::from_micros(50),
+    );
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "The author must be an administrator when updating the Category"
+        ))
+    );
+
+    let result = check_administrator_link(
+      None,
+      &administrators_path_hash(),
+      &agent(10),
+      Timestamp::from_micros(200),
+    );
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "Only the author of a Category or an administrator can update it"
+        ))
+    );
+  }
+}
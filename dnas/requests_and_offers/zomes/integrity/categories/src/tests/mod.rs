@@ -0,0 +1 @@
+mod category_test;
@@ -0,0 +1,333 @@
+use std::{fmt::Display, str::FromStr};
+
+use hdi::prelude::*;
+use utils::{
+  countersigning::get_user_agent_link_target,
+  errors::UtilsError,
+  lifecycle::{Lifecycle, Transition, TransitionActor},
+};
+
+/// Represents an exchange Entry agreed between a provider and a receiver user.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct Exchange {
+  /// The original action hash of the provider's User profile.
+  pub provider: ActionHash,
+  /// The original action hash of the receiver's User profile.
+  pub receiver: ActionHash,
+  /// The terms agreed between both parties.
+  pub terms: String,
+  /// The original action hash of the item the exchange is about, such as a request or an offer.
+  pub reference: Option<ActionHash>,
+  pub provider_confirmed: bool,
+  pub receiver_confirmed: bool,
+  pub provider_completed: bool,
+  pub receiver_completed: bool,
+  /// The status of the exchange, either 'proposed', 'agreed' or 'completed'.
+  pub status: String,
+  /// The action hash of the `UserAgents` link from the provider or the receiver to the agent
+  /// authoring this version of the exchange.
+  pub party_agent_link: ActionHash,
+}
+
+/// The party of an exchange an agent acts for.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExchangeParty {
+  Provider,
+  Receiver,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExchangeStatus {
+  Proposed,
+  Agreed,
+  Completed,
+}
+
+impl Display for ExchangeStatus {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Proposed => write!(f, "proposed"),
+      Self::Agreed => write!(f, "agreed"),
+      Self::Completed => write!(f, "completed"),
+    }
+  }
+}
+
+impl FromStr for ExchangeStatus {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "proposed" => Ok(Self::Proposed),
+      "agreed" => Ok(Self::Agreed),
+      "completed" => Ok(Self::Completed),
+      _ => Err(format!("Invalid exchange status: {}", s)),
+    }
+  }
+}
+
+/// An exchange is proposed, becomes agreed once both parties confirmed it and completed once
+/// both parties marked it as done. The proposer confirms on creation, so the other party agrees
+/// it, while either party may be the last to mark it as done. Which party changes which
+/// confirmation is checked by `validate_exchange_party_changes`.
+pub const EXCHANGE_LIFECYCLE: Lifecycle<ExchangeStatus> = Lifecycle {
+  initial_states: &[ExchangeStatus::Proposed],
+  transitions: &[
+    Transition {
+      from: ExchangeStatus::Proposed,
+      to: ExchangeStatus::Agreed,
+      actor: TransitionActor::Other,
+    },
+    Transition {
+      from: ExchangeStatus::Agreed,
+      to: ExchangeStatus::Completed,
+      actor: TransitionActor::Anyone,
+    },
+  ],
+};
+
+impl Exchange {
+  /// Returns the status matching the confirmations of both parties.
+  pub fn expected_status(&self) -> ExchangeStatus {
+    if self.provider_completed && self.receiver_completed {
+      ExchangeStatus::Completed
+    } else if self.provider_confirmed && self.receiver_confirmed {
+      ExchangeStatus::Agreed
+    } else {
+      ExchangeStatus::Proposed
+    }
+  }
+}
+
+pub fn validate_exchange(exchange: Exchange) -> ExternResult<ValidateCallbackResult> {
+  if exchange.terms.is_empty() {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "Exchange terms cannot be empty",
+    )));
+  }
+
+  if exchange.provider == exchange.receiver {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "The provider and the receiver of an Exchange must be different users",
+    )));
+  }
+
+  let Ok(status) = ExchangeStatus::from_str(&exchange.status) else {
+    return Ok(ValidateCallbackResult::Invalid(format!(
+      "Exchange status must be '{}', '{}' or '{}'.",
+      ExchangeStatus::Proposed,
+      ExchangeStatus::Agreed,
+      ExchangeStatus::Completed,
+    )));
+  };
+
+  if (exchange.provider_completed || exchange.receiver_completed)
+    && !(exchange.provider_confirmed && exchange.receiver_confirmed)
+  {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "An Exchange cannot be marked as done before being agreed",
+    )));
+  }
+
+  if status != exchange.expected_status() {
+    return Ok(ValidateCallbackResult::Invalid(format!(
+      "The Exchange status should be '{}'",
+      exchange.expected_status()
+    )));
+  }
+
+  Ok(ValidateCallbackResult::Valid)
+}
+
+/// Returns the party the author of a version of an exchange acts for, from the `UserAgents`
+/// link it cites.
+pub fn get_exchange_party(
+  exchange: &Exchange,
+  author: &AgentPubKey,
+) -> ExternResult<Option<ExchangeParty>> {
+  if get_user_agent_link_target(&exchange.provider, exchange.party_agent_link.clone())?.as_ref()
+    == Some(author)
+  {
+    return Ok(Some(ExchangeParty::Provider));
+  }
+
+  if get_user_agent_link_target(&exchange.receiver, exchange.party_agent_link.clone())?.as_ref()
+    == Some(author)
+  {
+    return Ok(Some(ExchangeParty::Receiver));
+  }
+
+  Ok(None)
+}
+
+/// Checks that only the party of the author changed its confirmations, given the previous
+/// version of the exchange, or `None` when it is proposed.
+pub fn validate_exchange_party_changes(
+  original_exchange: Option<&Exchange>,
+  exchange: &Exchange,
+  party: Option<ExchangeParty>,
+) -> ValidateCallbackResult {
+  let Some(party) = party else {
+    return ValidateCallbackResult::Invalid(String::from(
+      "Only an agent of the provider or the receiver can propose or confirm an Exchange",
+    ));
+  };
+
+  let (provider_changed, receiver_changed) = match original_exchange {
+    Some(original_exchange) => (
+      exchange.provider_confirmed != original_exchange.provider_confirmed
+        || exchange.provider_completed != original_exchange.provider_completed,
+      exchange.receiver_confirmed != original_exchange.receiver_confirmed
+        || exchange.receiver_completed != original_exchange.receiver_completed,
+    ),
+    None => (exchange.provider_confirmed, exchange.receiver_confirmed),
+  };
+
+  if (provider_changed && party != ExchangeParty::Provider)
+    || (receiver_changed && party != ExchangeParty::Receiver)
+  {
+    return ValidateCallbackResult::Invalid(String::from(
+      "Each party can only set its own confirmations of an Exchange",
+    ));
+  }
+
+  if original_exchange.is_none() && !provider_changed && !receiver_changed {
+    return ValidateCallbackResult::Invalid(String::from(
+      "The proposer of an Exchange must confirm it",
+    ));
+  }
+
+  ValidateCallbackResult::Valid
+}
+
+pub fn validate_create_exchange(exchange: Exchange) -> ExternResult<ValidateCallbackResult> {
+  let result = validate_exchange(exchange.clone())?;
+  if result != ValidateCallbackResult::Valid {
+    return Ok(result);
+  }
+
+  EXCHANGE_LIFECYCLE.validate_initial_state(&exchange.status)
+}
+
+/// Checks that an exchange is proposed by an agent of one of its parties, who confirms it.
+pub fn validate_exchange_proposer(
+  action: &Create,
+  exchange: &Exchange,
+) -> ExternResult<ValidateCallbackResult> {
+  let party = get_exchange_party(exchange, &action.author)?;
+
+  Ok(validate_exchange_party_changes(None, exchange, party))
+}
+
+pub fn validate_update_exchange(
+  action: Update,
+  exchange: Exchange,
+  original_action: EntryCreationAction,
+  original_exchange: Exchange,
+) -> ExternResult<ValidateCallbackResult> {
+  if exchange.provider != original_exchange.provider
+    || exchange.receiver != original_exchange.receiver
+    || exchange.terms != original_exchange.terms
+    || exchange.reference != original_exchange.reference
+  {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "The parties, terms and reference of an Exchange cannot be changed",
+    )));
+  }
+
+  let withdrawn = |before: bool, after: bool| before && !after;
+  if withdrawn(
+    original_exchange.provider_confirmed,
+    exchange.provider_confirmed,
+  ) || withdrawn(
+    original_exchange.receiver_confirmed,
+    exchange.receiver_confirmed,
+  ) || withdrawn(
+    original_exchange.provider_completed,
+    exchange.provider_completed,
+  ) || withdrawn(
+    original_exchange.receiver_completed,
+    exchange.receiver_completed,
+  ) {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "A confirmation cannot be withdrawn",
+    )));
+  }
+
+  let party = get_exchange_party(&exchange, &action.author)?;
+  let result = validate_exchange_party_changes(Some(&original_exchange), &exchange, party);
+  if result != ValidateCallbackResult::Valid {
+    return Ok(result);
+  }
+
+  EXCHANGE_LIFECYCLE.validate_transition(
+    &original_exchange.status,
+    &exchange.status,
+    action.author == *original_action.author(),
+  )
+}
+
+pub fn validate_delete_exchange(
+  action: Delete,
+  original_action: EntryCreationAction,
+  original_exchange: Exchange,
+) -> ExternResult<ValidateCallbackResult> {
+  if action.author != *original_action.author() {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "Only the author of an Exchange can delete it",
+    )));
+  }
+
+  if original_exchange.status != ExchangeStatus::Proposed.to_string() {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "An Exchange cannot be deleted once agreed",
+    )));
+  }
+
+  Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_create_link_exchange_updates(
+  _action: CreateLink,
+  base_address: AnyLinkableHash,
+  target_address: AnyLinkableHash,
+  _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+  let action_hash = base_address
+    .into_action_hash()
+    .ok_or(UtilsError::ActionHashNotFound("exchange"))?;
+  let record = must_get_valid_record(action_hash)?;
+  let _exchange: crate::Exchange = record
+    .entry()
+    .to_app_option()
+    .map_err(|e| wasm_error!(e))?
+    .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+      "Linked action must reference an entry"
+    ))))?;
+  // Check the entry type for the given action hash
+  let action_hash = target_address
+    .into_action_hash()
+    .ok_or(UtilsError::ActionHashNotFound("exchange"))?;
+  let record = must_get_valid_record(action_hash)?;
+  let _exchange: crate::Exchange = record
+    .entry()
+    .to_app_option()
+    .map_err(|e| wasm_error!(e))?
+    .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+      "Linked action must reference an entry"
+    ))))?;
+  Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_exchange_updates(
+  _action: DeleteLink,
+  _original_action: CreateLink,
+  _base: AnyLinkableHash,
+  _target: AnyLinkableHash,
+  _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+  Ok(ValidateCallbackResult::Invalid(String::from(
+    "ExchangeUpdates links cannot be deleted",
+  )))
+}
@@ -0,0 +1,230 @@
+#[cfg(test)]
+mod tests {
+  use hdi::prelude::{ActionHash, ValidateCallbackResult};
+
+  use crate::{
+    validate_create_exchange, validate_exchange, validate_exchange_party_changes, Exchange,
+    ExchangeParty, EXCHANGE_LIFECYCLE,
+  };
+
+  fn sample_exchange() -> Exchange {
+    Exchange {
+      provider: ActionHash::from_raw_36(vec![0; 36]),
+      receiver: ActionHash::from_raw_36(vec![1; 36]),
+      terms: "Two hours of code review".to_string(),
+      reference: None,
+      provider_confirmed: true,
+      receiver_confirmed: false,
+      provider_completed: false,
+      receiver_completed: false,
+      status: "proposed".to_string(),
+      party_agent_link: ActionHash::from_raw_36(vec![2; 36]),
+    }
+  }
+
+  #[test]
+  fn test_exchange_is_valid() {
+    let result = validate_create_exchange(sample_exchange()).unwrap();
+    assert!(result == ValidateCallbackResult::Valid);
+  }
+
+  #[test]
+  fn test_exchange_with_empty_terms() {
+    let exchange = Exchange {
+      terms: "".to_string(),
+      ..sample_exchange()
+    };
+    let result = validate_exchange(exchange).unwrap();
+    assert!(
+      result == ValidateCallbackResult::Invalid(String::from("Exchange terms cannot be empty"))
+    );
+  }
+
+  #[test]
+  fn test_exchange_with_same_parties() {
+    let exchange = Exchange {
+      receiver: ActionHash::from_raw_36(vec![0; 36]),
+      ..sample_exchange()
+    };
+    let result = validate_exchange(exchange).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "The provider and the receiver of an Exchange must be different users"
+        ))
+    );
+  }
+
+  #[test]
+  fn test_exchange_status_must_match_confirmations() {
+    let exchange = Exchange {
+      status: "agreed".to_string(),
+      ..sample_exchange()
+    };
+    let result = validate_exchange(exchange).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "The Exchange status should be 'proposed'"
+        ))
+    );
+
+    let exchange = Exchange {
+      receiver_confirmed: true,
+      status: "agreed".to_string(),
+      ..sample_exchange()
+    };
+    let result = validate_exchange(exchange).unwrap();
+    assert!(result == ValidateCallbackResult::Valid);
+  }
+
+  #[test]
+  fn test_exchange_cannot_be_done_before_agreed() {
+    let exchange = Exchange {
+      provider_completed: true,
+      ..sample_exchange()
+    };
+    let result = validate_exchange(exchange).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "An Exchange cannot be marked as done before being agreed"
+        ))
+    );
+  }
+
+  #[test]
+  fn test_exchange_cannot_be_created_agreed() {
+    let exchange = Exchange {
+      receiver_confirmed: true,
+      status: "agreed".to_string(),
+      ..sample_exchange()
+    };
+    let result = validate_create_exchange(exchange).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "An entry cannot be created in the 'agreed' state"
+        ))
+    );
+  }
+
+  #[test]
+  fn test_exchange_status_transitions() {
+    let result = EXCHANGE_LIFECYCLE
+      .validate_transition("proposed", "agreed", false)
+      .unwrap();
+    assert!(result == ValidateCallbackResult::Valid);
+
+    // The proposer confirmed on creation, so only the other party can agree
+    let result = EXCHANGE_LIFECYCLE
+      .validate_transition("proposed", "agreed", true)
+      .unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "The author cannot go from the 'proposed' state to the 'agreed' state"
+        ))
+    );
+
+    let result = EXCHANGE_LIFECYCLE
+      .validate_transition("agreed", "completed", true)
+      .unwrap();
+    assert!(result == ValidateCallbackResult::Valid);
+
+    let result = EXCHANGE_LIFECYCLE
+      .validate_transition("proposed", "completed", true)
+      .unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "Cannot go from the 'proposed' state to the 'completed' state"
+        ))
+    );
+  }
+
+  #[test]
+  fn test_exchange_proposer_party() {
+    let result =
+      validate_exchange_party_changes(None, &sample_exchange(), Some(ExchangeParty::Provider));
+    assert!(result == ValidateCallbackResult::Valid);
+
+    let result = validate_exchange_party_changes(None, &sample_exchange(), None);
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "Only an agent of the provider or the receiver can propose or confirm an Exchange"
+        ))
+    );
+
+    let result =
+      validate_exchange_party_changes(None, &sample_exchange(), Some(ExchangeParty::Receiver));
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "Each party can only set its own confirmations of an Exchange"
+        ))
+    );
+
+    let unconfirmed = Exchange {
+      provider_confirmed: false,
+      ..sample_exchange()
+    };
+    let result = validate_exchange_party_changes(None, &unconfirmed, Some(ExchangeParty::Provider));
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "The proposer of an Exchange must confirm it"
+        ))
+    );
+  }
+
+  #[test]
+  fn test_exchange_confirmation_parties() {
+    let proposed = sample_exchange();
+    let agreed = Exchange {
+      receiver_confirmed: true,
+      status: "agreed".to_string(),
+      ..sample_exchange()
+    };
+
+    let result =
+      validate_exchange_party_changes(Some(&proposed), &agreed, Some(ExchangeParty::Receiver));
+    assert!(result == ValidateCallbackResult::Valid);
+
+    let result =
+      validate_exchange_party_changes(Some(&proposed), &agreed, Some(ExchangeParty::Provider));
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "Each party can only set its own confirmations of an Exchange"
+        ))
+    );
+
+    let provider_done = Exchange {
+      provider_completed: true,
+      ..agreed.clone()
+    };
+    let result =
+      validate_exchange_party_changes(Some(&agreed), &provider_done, Some(ExchangeParty::Receiver));
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "Each party can only set its own confirmations of an Exchange"
+        ))
+    );
+
+    let both_done = Exchange {
+      receiver_completed: true,
+      ..provider_done.clone()
+    };
+    let result =
+      validate_exchange_party_changes(Some(&agreed), &both_done, Some(ExchangeParty::Provider));
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "Each party can only set its own confirmations of an Exchange"
+        ))
+    );
+  }
+}
@@ -0,0 +1,383 @@
+use std::{fmt::Display, str::FromStr};
+
+use hdi::{hash_path::path::Path, prelude::*};
+use utils::{
+  countersigning::{get_administration_link, get_user_agent_link_target},
+  errors::UtilsError,
+  lifecycle::{Lifecycle, Transition, TransitionActor},
+  link_types::AdministrationLinkTypes,
+};
+
+/// The path the agents of the matchmakers of the network are linked to.
+const NETWORK_MATCHMAKERS_PATH: &str = "network.matchmakers";
+
+/// Represents an introduction Entry proposed by a matchmaker between two users.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct Introduction {
+  /// The original action hash of the matchmaker's User profile.
+  pub matchmaker: ActionHash,
+  /// The original action hash of the first introduced user.
+  pub first_user: ActionHash,
+  /// The original action hash of the second introduced user.
+  pub second_user: ActionHash,
+  /// Why the matchmaker thinks both users should meet.
+  pub note: String,
+  /// The decision of the first user, either 'pending', 'accepted' or 'declined'.
+  pub first_user_decision: String,
+  /// The decision of the second user, either 'pending', 'accepted' or 'declined'.
+  pub second_user_decision: String,
+  /// The action hash of the `AgentMatchmakers` link granting the matchmaker role to the author.
+  pub matchmaker_link: ActionHash,
+  /// The action hash of the `UserAgents` link from the first user to the agent who decided for
+  /// them.
+  pub first_user_agent_link: Option<ActionHash>,
+  /// The action hash of the `UserAgents` link from the second user to the agent who decided for
+  /// them.
+  pub second_user_agent_link: Option<ActionHash>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IntroductionDecision {
+  Pending,
+  Accepted,
+  Declined,
+}
+
+impl Display for IntroductionDecision {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Pending => write!(f, "pending"),
+      Self::Accepted => write!(f, "accepted"),
+      Self::Declined => write!(f, "declined"),
+    }
+  }
+}
+
+impl FromStr for IntroductionDecision {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "pending" => Ok(Self::Pending),
+      "accepted" => Ok(Self::Accepted),
+      "declined" => Ok(Self::Declined),
+      _ => Err(format!("Invalid introduction decision: {}", s)),
+    }
+  }
+}
+
+/// The decision of each introduced user starts pending and can only be made once, by the
+/// introduced users rather than by the matchmaker. Which agents decide for each user is checked
+/// by `validate_introduction_decider`.
+pub const INTRODUCTION_DECISION_LIFECYCLE: Lifecycle<IntroductionDecision> = Lifecycle {
+  initial_states: &[IntroductionDecision::Pending],
+  transitions: &[
+    Transition {
+      from: IntroductionDecision::Pending,
+      to: IntroductionDecision::Accepted,
+      actor: TransitionActor::Other,
+    },
+    Transition {
+      from: IntroductionDecision::Pending,
+      to: IntroductionDecision::Declined,
+      actor: TransitionActor::Other,
+    },
+  ],
+};
+
+pub fn validate_introduction(introduction: Introduction) -> ExternResult<ValidateCallbackResult> {
+  if introduction.note.trim().is_empty() {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "Introduction note cannot be empty",
+    )));
+  }
+
+  if introduction.first_user == introduction.second_user {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "An Introduction must be between two different users",
+    )));
+  }
+
+  if introduction.matchmaker == introduction.first_user
+    || introduction.matchmaker == introduction.second_user
+  {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "A matchmaker cannot introduce themselves",
+    )));
+  }
+
+  for decision in [
+    &introduction.first_user_decision,
+    &introduction.second_user_decision,
+  ] {
+    if IntroductionDecision::from_str(decision).is_err() {
+      return Ok(ValidateCallbackResult::Invalid(format!(
+        "Introduction decision must be '{}', '{}' or '{}'.",
+        IntroductionDecision::Pending,
+        IntroductionDecision::Accepted,
+        IntroductionDecision::Declined,
+      )));
+    }
+  }
+
+  Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_create_introduction(
+  introduction: Introduction,
+) -> ExternResult<ValidateCallbackResult> {
+  let result = validate_introduction(introduction.clone())?;
+  if result != ValidateCallbackResult::Valid {
+    return Ok(result);
+  }
+
+  if introduction.first_user_agent_link.is_some() || introduction.second_user_agent_link.is_some() {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "An Introduction cannot be created as decided",
+    )));
+  }
+
+  let result =
+    INTRODUCTION_DECISION_LIFECYCLE.validate_initial_state(&introduction.first_user_decision)?;
+  if result != ValidateCallbackResult::Valid {
+    return Ok(result);
+  }
+
+  INTRODUCTION_DECISION_LIFECYCLE.validate_initial_state(&introduction.second_user_decision)
+}
+
+/// Checks that an introduction is created by a matchmaker of the network, citing the
+/// `AgentMatchmakers` link of its author.
+///
+/// Matchmakers are removed by administrators, which leaves no trace on the chain of the
+/// matchmaker, so an introduction is only checked against the link it cites.
+pub fn validate_introduction_matchmaker(
+  action: &Create,
+  introduction: &Introduction,
+) -> ExternResult<ValidateCallbackResult> {
+  let matchmaker_link = get_administration_link(
+    introduction.matchmaker_link.clone(),
+    AdministrationLinkTypes::AgentMatchmakers,
+  )?;
+
+  Ok(check_matchmaker_link(
+    matchmaker_link.as_ref(),
+    &Path::from(NETWORK_MATCHMAKERS_PATH).path_entry_hash()?,
+    &action.author,
+    action.timestamp,
+  ))
+}
+
+/// Checks the `AgentMatchmakers` link cited by an introduction, once fetched.
+pub fn check_matchmaker_link(
+  matchmaker_link: Option<&CreateLink>,
+  matchmakers_path_hash: &EntryHash,
+  author: &AgentPubKey,
+  timestamp: Timestamp,
+) -> ValidateCallbackResult {
+  let Some(matchmaker_link) = matchmaker_link else {
+    return ValidateCallbackResult::Invalid(String::from(
+      "The matchmaker link must be an AgentMatchmakers link",
+    ));
+  };
+
+  if matchmaker_link.base_address != AnyLinkableHash::from(author.clone())
+    || matchmaker_link.target_address != AnyLinkableHash::from(matchmakers_path_hash.clone())
+  {
+    return ValidateCallbackResult::Invalid(String::from(
+      "Only matchmakers of the network can introduce users",
+    ));
+  }
+
+  if matchmaker_link.timestamp > timestamp {
+    return ValidateCallbackResult::Invalid(String::from(
+      "The author must be a matchmaker when introducing users",
+    ));
+  }
+
+  ValidateCallbackResult::Valid
+}
+
+pub fn validate_update_introduction(
+  action: Update,
+  introduction: Introduction,
+  original_action: EntryCreationAction,
+  original_introduction: Introduction,
+) -> ExternResult<ValidateCallbackResult> {
+  if introduction.matchmaker != original_introduction.matchmaker
+    || introduction.first_user != original_introduction.first_user
+    || introduction.second_user != original_introduction.second_user
+    || introduction.note != original_introduction.note
+  {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "The matchmaker, users and note of an Introduction cannot be changed",
+    )));
+  }
+
+  let first_user_changed =
+    introduction.first_user_decision != original_introduction.first_user_decision;
+  let second_user_changed =
+    introduction.second_user_decision != original_introduction.second_user_decision;
+  if first_user_changed && second_user_changed {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "Each user must decide on an Introduction separately",
+    )));
+  }
+
+  let is_author = action.author == *original_action.author();
+  let result = INTRODUCTION_DECISION_LIFECYCLE.validate_transition(
+    &original_introduction.first_user_decision,
+    &introduction.first_user_decision,
+    is_author,
+  )?;
+  if result != ValidateCallbackResult::Valid {
+    return Ok(result);
+  }
+
+  let result = INTRODUCTION_DECISION_LIFECYCLE.validate_transition(
+    &original_introduction.second_user_decision,
+    &introduction.second_user_decision,
+    is_author,
+  )?;
+  if result != ValidateCallbackResult::Valid {
+    return Ok(result);
+  }
+
+  let result = check_introduction_decider_links(&original_introduction, &introduction);
+  if result != ValidateCallbackResult::Valid {
+    return Ok(result);
+  }
+
+  if !first_user_changed && !second_user_changed {
+    return Ok(ValidateCallbackResult::Valid);
+  }
+
+  validate_introduction_decider(&action, &introduction, first_user_changed)
+}
+
+/// Checks that each decision cites the `UserAgents` link of the deciding user, and that the
+/// links of the decisions already made do not change.
+pub fn check_introduction_decider_links(
+  original_introduction: &Introduction,
+  introduction: &Introduction,
+) -> ValidateCallbackResult {
+  for (original_decision, decision, original_link, link) in [
+    (
+      &original_introduction.first_user_decision,
+      &introduction.first_user_decision,
+      &original_introduction.first_user_agent_link,
+      &introduction.first_user_agent_link,
+    ),
+    (
+      &original_introduction.second_user_decision,
+      &introduction.second_user_decision,
+      &original_introduction.second_user_agent_link,
+      &introduction.second_user_agent_link,
+    ),
+  ] {
+    if decision == original_decision {
+      if link != original_link {
+        return ValidateCallbackResult::Invalid(String::from(
+          "The agent who decided for a user cannot be changed",
+        ));
+      }
+    } else if link.is_none() {
+      return ValidateCallbackResult::Invalid(String::from(
+        "A decision on an Introduction must cite the UserAgents link of the deciding user",
+      ));
+    }
+  }
+
+  ValidateCallbackResult::Valid
+}
+
+/// Checks that a decision on an introduction is made by an agent of the deciding user.
+pub fn validate_introduction_decider(
+  action: &Update,
+  introduction: &Introduction,
+  first_user_changed: bool,
+) -> ExternResult<ValidateCallbackResult> {
+  let (user, user_agent_link) = if first_user_changed {
+    (
+      &introduction.first_user,
+      &introduction.first_user_agent_link,
+    )
+  } else {
+    (
+      &introduction.second_user,
+      &introduction.second_user_agent_link,
+    )
+  };
+  let Some(user_agent_link) = user_agent_link else {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "A decision on an Introduction must cite the UserAgents link of the deciding user",
+    )));
+  };
+
+  if get_user_agent_link_target(user, user_agent_link.clone())?.as_ref() != Some(&action.author) {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "Only an agent of an introduced user can decide for them",
+    )));
+  }
+
+  Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_introduction(
+  action: Delete,
+  original_action: EntryCreationAction,
+  _original_introduction: Introduction,
+) -> ExternResult<ValidateCallbackResult> {
+  if action.author != *original_action.author() {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "Only the matchmaker of an Introduction can delete it",
+    )));
+  }
+
+  Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_create_link_introduction_updates(
+  _action: CreateLink,
+  base_address: AnyLinkableHash,
+  target_address: AnyLinkableHash,
+  _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+  let action_hash = base_address
+    .into_action_hash()
+    .ok_or(UtilsError::ActionHashNotFound("introduction"))?;
+  let record = must_get_valid_record(action_hash)?;
+  let _introduction: crate::Introduction = record
+    .entry()
+    .to_app_option()
+    .map_err(|e| wasm_error!(e))?
+    .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+      "Linked action must reference an entry"
+    ))))?;
+  // Check the entry type for the given action hash
+  let action_hash = target_address
+    .into_action_hash()
+    .ok_or(UtilsError::ActionHashNotFound("introduction"))?;
+  let record = must_get_valid_record(action_hash)?;
+  let _introduction: crate::Introduction = record
+    .entry()
+    .to_app_option()
+    .map_err(|e| wasm_error!(e))?
+    .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+      "Linked action must reference an entry"
+    ))))?;
+  Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_introduction_updates(
+  _action: DeleteLink,
+  _original_action: CreateLink,
+  _base: AnyLinkableHash,
+  _target: AnyLinkableHash,
+  _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+  Ok(ValidateCallbackResult::Invalid(String::from(
+    "IntroductionUpdates links cannot be deleted",
+  )))
+}
@@ -0,0 +1,200 @@
+pub mod introduction;
+mod tests;
+
+pub use introduction::*;
+
+use hdi::prelude::*;
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[hdk_entry_types]
+#[unit_enum(UnitEntryTypes)]
+pub enum EntryTypes {
+  Introduction(Introduction),
+}
+
+#[derive(Serialize, Deserialize)]
+#[hdk_link_types]
+pub enum LinkTypes {
+  IntroductionUpdates,
+  UserIntroductions,
+}
+
+#[hdk_extern]
+pub fn genesis_self_check(_data: GenesisSelfCheckData) -> ExternResult<ValidateCallbackResult> {
+  Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_agent_joining(
+  _agent_pub_key: AgentPubKey,
+  _membrane_proof: &Option<MembraneProof>,
+) -> ExternResult<ValidateCallbackResult> {
+  Ok(ValidateCallbackResult::Valid)
+}
+
+/// Validates the provided `Op` to ensure the entry and link types adhere to the defined constraints.
+#[allow(clippy::collapsible_match, clippy::single_match)]
+#[hdk_extern]
+pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
+  if let FlatOp::StoreEntry(store_entry) = op.flattened::<EntryTypes, LinkTypes>()? {
+    match store_entry {
+      OpEntry::CreateEntry { app_entry, .. } => match app_entry {
+        EntryTypes::Introduction(introduction) => {
+          return validate_create_introduction(introduction);
+        }
+      },
+      OpEntry::UpdateEntry { app_entry, .. } => match app_entry {
+        EntryTypes::Introduction(introduction) => {
+          return validate_introduction(introduction);
+        }
+      },
+
+      _ => (),
+    }
+  }
+  if let FlatOp::RegisterCreateLink {
+    link_type,
+    base_address,
+    target_address,
+    tag,
+    action,
+  } = op.flattened::<EntryTypes, LinkTypes>()?
+  {
+    match link_type {
+      LinkTypes::IntroductionUpdates => {
+        return validate_create_link_introduction_updates(
+          action,
+          base_address,
+          target_address,
+          tag,
+        );
+      }
+      _ => (),
+    }
+  }
+  if let FlatOp::RegisterDeleteLink {
+    link_type,
+    base_address,
+    target_address,
+    tag,
+    original_action,
+    action,
+  } = op.flattened::<EntryTypes, LinkTypes>()?
+  {
+    match link_type {
+      LinkTypes::IntroductionUpdates => {
+        return validate_delete_link_introduction_updates(
+          action,
+          original_action,
+          base_address,
+          target_address,
+          tag,
+        );
+      }
+      _ => (),
+    }
+  }
+  if let FlatOp::StoreRecord(store_record) = op.flattened::<EntryTypes, LinkTypes>()? {
+    match store_record {
+      OpRecord::CreateEntry {
+        action,
+        app_entry: EntryTypes::Introduction(introduction),
+      } => {
+        return validate_introduction_matchmaker(&action, &introduction);
+      }
+      OpRecord::UpdateEntry {
+        original_action_hash,
+        app_entry,
+        action,
+        ..
+      } => {
+        let original_record = must_get_valid_record(original_action_hash)?;
+        let original_action = match original_record.action().clone() {
+          Action::Create(create) => EntryCreationAction::Create(create),
+          Action::Update(update) => EntryCreationAction::Update(update),
+          _ => {
+            return Ok(ValidateCallbackResult::Invalid(
+              "Original action for an update must be a Create or Update action".to_string(),
+            ));
+          }
+        };
+        match app_entry {
+          EntryTypes::Introduction(introduction) => {
+            let original_introduction: Introduction = match original_record
+              .entry()
+              .to_app_option()
+              .map_err(|e| wasm_error!(e))?
+            {
+              Some(original_introduction) => original_introduction,
+              None => {
+                return Ok(ValidateCallbackResult::Invalid(
+                  "The updated entry type must be the same as the original entry type".to_string(),
+                ));
+              }
+            };
+            return validate_update_introduction(
+              action,
+              introduction,
+              original_action,
+              original_introduction,
+            );
+          }
+        }
+      }
+      OpRecord::DeleteEntry {
+        original_action_hash,
+        action,
+        ..
+      } => {
+        let original_record = must_get_valid_record(original_action_hash)?;
+        let original_action = original_record.action().clone();
+        let original_action = match original_action {
+          Action::Create(create) => EntryCreationAction::Create(create),
+          Action::Update(update) => EntryCreationAction::Update(update),
+          _ => {
+            return Ok(ValidateCallbackResult::Invalid(
+              "Original action for a delete must be a Create or Update action".to_string(),
+            ));
+          }
+        };
+        let app_entry_type = match original_action.entry_type() {
+          EntryType::App(app_entry_type) => app_entry_type,
+          _ => {
+            return Ok(ValidateCallbackResult::Valid);
+          }
+        };
+        let entry = match original_record.entry().as_option() {
+          Some(entry) => entry,
+          None => {
+            if original_action.entry_type().visibility().is_public() {
+              return Ok(ValidateCallbackResult::Invalid(
+                "Original record for a delete of a public entry must contain an entry".to_string(),
+              ));
+            } else {
+              return Ok(ValidateCallbackResult::Valid);
+            }
+          }
+        };
+        let original_app_entry = match EntryTypes::deserialize_from_type(
+          *app_entry_type.zome_index,
+          app_entry_type.entry_index,
+          entry,
+        )? {
+          Some(app_entry) => app_entry,
+          None => {
+            return Ok(ValidateCallbackResult::Invalid(
+              "Original app entry must be one of the defined entry types for this zome".to_string(),
+            ));
+          }
+        };
+        match original_app_entry {
+          EntryTypes::Introduction(original_introduction) => {
+            return validate_delete_introduction(action, original_action, original_introduction);
+          }
+        }
+      }
+      _ => (),
+    }
+  }
+  Ok(ValidateCallbackResult::Valid)
+}
@@ -0,0 +1,241 @@
+#[cfg(test)]
+mod tests {
+  use hdi::prelude::*;
+  use utils::link_types::AdministrationLinkTypes;
+
+  use crate::{
+    check_introduction_decider_links, check_matchmaker_link, validate_create_introduction,
+    validate_introduction, Introduction, INTRODUCTION_DECISION_LIFECYCLE,
+  };
+
+  fn sample_introduction() -> Introduction {
+    Introduction {
+      matchmaker: ActionHash::from_raw_36(vec![0; 36]),
+      first_user: ActionHash::from_raw_36(vec![1; 36]),
+      second_user: ActionHash::from_raw_36(vec![2; 36]),
+      note: "You both work on community gardens".to_string(),
+      first_user_decision: "pending".to_string(),
+      second_user_decision: "pending".to_string(),
+      matchmaker_link: ActionHash::from_raw_36(vec![3; 36]),
+      first_user_agent_link: None,
+      second_user_agent_link: None,
+    }
+  }
+
+  fn agent(byte: u8) -> AgentPubKey {
+    AgentPubKey::from_raw_36(vec![byte; 36])
+  }
+
+  fn matchmakers_path_hash() -> EntryHash {
+    EntryHash::from_raw_36(vec![4; 36])
+  }
+
+  fn matchmaker_link() -> CreateLink {
+    CreateLink {
+      author: agent(20),
+      timestamp: Timestamp::from_micros(100),
+      action_seq: 5,
+      prev_action: ActionHash::from_raw_36(vec![5; 36]),
+      base_address: agent(10).into(),
+      target_address: matchmakers_path_hash().into(),
+      zome_index: ZomeIndex(0),
+      link_type: AdministrationLinkTypes::AgentMatchmakers.into(),
+      tag: LinkTag::new(vec![]),
+      weight: RateWeight::default(),
+    }
+  }
+
+  #[test]
+  fn test_introduction_is_valid() {
+    let result = validate_create_introduction(sample_introduction()).unwrap();
+    assert!(result == ValidateCallbackResult::Valid);
+  }
+
+  #[test]
+  fn test_introduction_with_empty_note() {
+    let introduction = Introduction {
+      note: " ".to_string(),
+      ..sample_introduction()
+    };
+    let result = validate_introduction(introduction).unwrap();
+    assert!(
+      result == ValidateCallbackResult::Invalid(String::from("Introduction note cannot be empty"))
+    );
+  }
+
+  #[test]
+  fn test_introduction_with_same_users() {
+    let introduction = Introduction {
+      second_user: ActionHash::from_raw_36(vec![1; 36]),
+      ..sample_introduction()
+    };
+    let result = validate_introduction(introduction).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "An Introduction must be between two different users"
+        ))
+    );
+  }
+
+  #[test]
+  fn test_introduction_of_the_matchmaker() {
+    let introduction = Introduction {
+      first_user: ActionHash::from_raw_36(vec![0; 36]),
+      ..sample_introduction()
+    };
+    let result = validate_introduction(introduction).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "A matchmaker cannot introduce themselves"
+        ))
+    );
+  }
+
+  #[test]
+  fn test_introduction_cannot_be_created_decided() {
+    let introduction = Introduction {
+      second_user_decision: "accepted".to_string(),
+      ..sample_introduction()
+    };
+    let result = validate_create_introduction(introduction).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "An entry cannot be created in the 'accepted' state"
+        ))
+    );
+  }
+
+  #[test]
+  fn test_introduction_cannot_be_created_with_a_decider() {
+    let introduction = Introduction {
+      first_user_agent_link: Some(ActionHash::from_raw_36(vec![6; 36])),
+      ..sample_introduction()
+    };
+    let result = validate_create_introduction(introduction).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "An Introduction cannot be created as decided"
+        ))
+    );
+  }
+
+  #[test]
+  fn test_introduction_matchmaker_link() {
+    let result = check_matchmaker_link(
+      Some(&matchmaker_link()),
+      &matchmakers_path_hash(),
+      &agent(10),
+      Timestamp::from_micros(200),
+    );
+    assert!(result == ValidateCallbackResult::Valid);
+
+    let result = check_matchmaker_link(
+      Some(&matchmaker_link()),
+      &matchmakers_path_hash(),
+      &agent(11),
+      Timestamp::from_micros(200),
+    );
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "Only matchmakers of the network can introduce users"
+        ))
+    );
+
+    let result = check_matchmaker_link(
+      Some(&matchmaker_link()),
+      &matchmakers_path_hash(),
+      &agent(10),
+      Timestamp::from_micros(50),
+    );
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "The author must be a matchmaker when introducing users"
+        ))
+    );
+
+    let result = check_matchmaker_link(
+      None,
+      &matchmakers_path_hash(),
+      &agent(10),
+      Timestamp::from_micros(200),
+    );
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "The matchmaker link must be an AgentMatchmakers link"
+        ))
+    );
+  }
+
+  #[test]
+  fn test_introduction_decider_links() {
+    let pending = sample_introduction();
+    let accepted = Introduction {
+      first_user_decision: "accepted".to_string(),
+      first_user_agent_link: Some(ActionHash::from_raw_36(vec![6; 36])),
+      ..sample_introduction()
+    };
+    let result = check_introduction_decider_links(&pending, &accepted);
+    assert!(result == ValidateCallbackResult::Valid);
+
+    let without_link = Introduction {
+      first_user_agent_link: None,
+      ..accepted.clone()
+    };
+    let result = check_introduction_decider_links(&pending, &without_link);
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "A decision on an Introduction must cite the UserAgents link of the deciding user"
+        ))
+    );
+
+    let changed_link = Introduction {
+      second_user_decision: "declined".to_string(),
+      first_user_agent_link: Some(ActionHash::from_raw_36(vec![7; 36])),
+      second_user_agent_link: Some(ActionHash::from_raw_36(vec![8; 36])),
+      ..accepted.clone()
+    };
+    let result = check_introduction_decider_links(&accepted, &changed_link);
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "The agent who decided for a user cannot be changed"
+        ))
+    );
+  }
+
+  #[test]
+  fn test_introduction_decision_transitions() {
+    let result = INTRODUCTION_DECISION_LIFECYCLE
+      .validate_transition("pending", "declined", false)
+      .unwrap();
+    assert!(result == ValidateCallbackResult::Valid);
+
+    let result = INTRODUCTION_DECISION_LIFECYCLE
+      .validate_transition("pending", "accepted", true)
+      .unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "The author cannot go from the 'pending' state to the 'accepted' state"
+        ))
+    );
+
+    let result = INTRODUCTION_DECISION_LIFECYCLE
+      .validate_transition("declined", "accepted", false)
+      .unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "Cannot go from the 'declined' state to the 'accepted' state"
+        ))
+    );
+  }
+}
@@ -0,0 +1 @@
+mod introduction_test;
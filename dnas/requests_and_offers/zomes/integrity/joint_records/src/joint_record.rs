@@ -0,0 +1,144 @@
+use hdi::prelude::*;
+use utils::{
+  countersigning::{get_app_entry, get_user_agent_link_target},
+  errors::UtilsError,
+};
+
+/// Represents a record both users agreed on, committed atomically to the source chains of
+/// their agents through a countersigning session.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct JointRecord {
+  /// The original action hash of the first user's profile.
+  pub first_user: ActionHash,
+  /// The action hash of the `UserAgents` link from the first user to its signing agent.
+  pub first_user_agent_link: ActionHash,
+  /// The original action hash of the second user's profile.
+  pub second_user: ActionHash,
+  /// The action hash of the `UserAgents` link from the second user to its signing agent.
+  pub second_user_agent_link: ActionHash,
+  /// What both users agree on, such as a hand-off or the confirmation of some work done.
+  pub content: String,
+  /// The original action hash of the item the record is about, such as an exchange.
+  pub reference: Option<ActionHash>,
+}
+
+pub fn validate_joint_record(joint_record: JointRecord) -> ExternResult<ValidateCallbackResult> {
+  if joint_record.content.is_empty() {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "JointRecord content cannot be empty",
+    )));
+  }
+
+  if joint_record.first_user == joint_record.second_user {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "The users of a JointRecord must be different",
+    )));
+  }
+
+  Ok(ValidateCallbackResult::Valid)
+}
+
+/// Checks that a joint record was countersigned by exactly the two agents linked to its users.
+pub fn validate_create_joint_record(
+  joint_record: JointRecord,
+  signing_agents: Option<Vec<AgentPubKey>>,
+) -> ExternResult<ValidateCallbackResult> {
+  let result = validate_joint_record(joint_record.clone())?;
+  if result != ValidateCallbackResult::Valid {
+    return Ok(result);
+  }
+
+  let Some(signing_agents) = signing_agents else {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "A JointRecord must be countersigned by both users",
+    )));
+  };
+
+  if signing_agents.len() != 2 {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "A JointRecord must be countersigned by exactly two agents",
+    )));
+  }
+
+  let (Some(first_agent), Some(second_agent)) = (
+    get_user_agent_link_target(&joint_record.first_user, joint_record.first_user_agent_link)?,
+    get_user_agent_link_target(
+      &joint_record.second_user,
+      joint_record.second_user_agent_link,
+    )?,
+  ) else {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "The agent links of a JointRecord must be UserAgents links of its users",
+    )));
+  };
+
+  if !signing_agents.contains(&first_agent)
+    || !signing_agents.contains(&second_agent)
+    || first_agent == second_agent
+  {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "The signers of a JointRecord must be the agents linked to its users",
+    )));
+  }
+
+  Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_update_joint_record(
+  _action: Update,
+  _joint_record: JointRecord,
+  _original_action: EntryCreationAction,
+  _original_joint_record: JointRecord,
+) -> ExternResult<ValidateCallbackResult> {
+  Ok(ValidateCallbackResult::Invalid(String::from(
+    "A JointRecord cannot be updated",
+  )))
+}
+
+pub fn validate_delete_joint_record(
+  _action: Delete,
+  _original_action: EntryCreationAction,
+  _original_joint_record: JointRecord,
+) -> ExternResult<ValidateCallbackResult> {
+  Ok(ValidateCallbackResult::Invalid(String::from(
+    "A JointRecord cannot be deleted",
+  )))
+}
+
+pub fn validate_create_link_user_joint_records(
+  _action: CreateLink,
+  base_address: AnyLinkableHash,
+  target_address: AnyLinkableHash,
+  _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+  let action_hash = target_address
+    .into_action_hash()
+    .ok_or(UtilsError::ActionHashNotFound("joint record"))?;
+  let record = must_get_valid_record(action_hash)?;
+  let joint_record: crate::JointRecord = get_app_entry(&record)?.ok_or(wasm_error!(
+    WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+  ))?;
+
+  if base_address != AnyLinkableHash::from(joint_record.first_user)
+    && base_address != AnyLinkableHash::from(joint_record.second_user)
+  {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "A JointRecord can only be linked from one of its users",
+    )));
+  }
+
+  Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_user_joint_records(
+  _action: DeleteLink,
+  _original_action: CreateLink,
+  _base: AnyLinkableHash,
+  _target: AnyLinkableHash,
+  _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+  Ok(ValidateCallbackResult::Invalid(String::from(
+    "UserJointRecords links cannot be deleted",
+  )))
+}
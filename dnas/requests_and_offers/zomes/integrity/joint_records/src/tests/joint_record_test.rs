@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod tests {
+  use hdi::prelude::{ActionHash, AgentPubKey, ValidateCallbackResult};
+
+  use crate::{validate_create_joint_record, validate_joint_record, JointRecord};
+
+  fn sample_joint_record() -> JointRecord {
+    JointRecord {
+      first_user: ActionHash::from_raw_36(vec![0; 36]),
+      first_user_agent_link: ActionHash::from_raw_36(vec![1; 36]),
+      second_user: ActionHash::from_raw_36(vec![2; 36]),
+      second_user_agent_link: ActionHash::from_raw_36(vec![3; 36]),
+      content: "The bike was handed over".to_string(),
+      reference: None,
+    }
+  }
+
+  #[test]
+  fn test_joint_record_is_valid() {
+    let result = validate_joint_record(sample_joint_record()).unwrap();
+    assert!(result == ValidateCallbackResult::Valid);
+  }
+
+  #[test]
+  fn test_joint_record_with_empty_content() {
+    let joint_record = JointRecord {
+      content: "".to_string(),
+      ..sample_joint_record()
+    };
+    let result = validate_joint_record(joint_record).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from("JointRecord content cannot be empty"))
+    );
+  }
+
+  #[test]
+  fn test_joint_record_with_same_users() {
+    let joint_record = JointRecord {
+      second_user: ActionHash::from_raw_36(vec![0; 36]),
+      ..sample_joint_record()
+    };
+    let result = validate_joint_record(joint_record).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "The users of a JointRecord must be different"
+        ))
+    );
+  }
+
+  #[test]
+  fn test_joint_record_must_be_countersigned() {
+    let result = validate_create_joint_record(sample_joint_record(), None).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "A JointRecord must be countersigned by both users"
+        ))
+    );
+
+    let signing_agents = vec![AgentPubKey::from_raw_36(vec![4; 36])];
+    let result = validate_create_joint_record(sample_joint_record(), Some(signing_agents)).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "A JointRecord must be countersigned by exactly two agents"
+        ))
+    );
+  }
+}
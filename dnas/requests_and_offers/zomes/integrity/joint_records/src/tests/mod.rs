@@ -0,0 +1 @@
+mod joint_record_test;
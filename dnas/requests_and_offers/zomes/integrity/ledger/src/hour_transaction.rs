@@ -0,0 +1,220 @@
+use std::{fmt::Display, str::FromStr};
+
+use hdi::prelude::*;
+use utils::{
+  countersigning::{get_app_entry, get_user_agent_link_target},
+  errors::UtilsError,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LedgerLineKind {
+  /// Hours given by the user.
+  Credit,
+  /// Hours received by the user.
+  Debit,
+}
+
+impl Display for LedgerLineKind {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Credit => write!(f, "credit"),
+      Self::Debit => write!(f, "debit"),
+    }
+  }
+}
+
+impl FromStr for LedgerLineKind {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "credit" => Ok(Self::Credit),
+      "debit" => Ok(Self::Debit),
+      _ => Err(format!("Invalid ledger line kind: {}", s)),
+    }
+  }
+}
+
+/// One side of an hour transaction, crediting or debiting the time of a user.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct LedgerLine {
+  /// The original action hash of the user's profile.
+  pub user: ActionHash,
+  /// The action hash of the `UserAgents` link from the user to its signing agent.
+  pub user_agent_link: ActionHash,
+  /// Either 'credit' or 'debit'.
+  pub kind: String,
+  /// The amount of time, in minutes.
+  pub minutes: u32,
+}
+
+impl LedgerLine {
+  /// Returns the signed amount of minutes of the line, positive for a credit.
+  pub fn signed_minutes(&self) -> i64 {
+    match LedgerLineKind::from_str(&self.kind) {
+      Ok(LedgerLineKind::Credit) => self.minutes as i64,
+      Ok(LedgerLineKind::Debit) => -(self.minutes as i64),
+      Err(_) => 0,
+    }
+  }
+}
+
+/// Represents hours given by a user to another, committed to the source chains of both users'
+/// agents through a countersigning session.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct HourTransaction {
+  /// The credit and debit lines of the transaction.
+  pub lines: Vec<LedgerLine>,
+  /// What the hours were given for.
+  pub description: String,
+  /// The original action hash of the item the transaction is about, such as an exchange.
+  pub reference: Option<ActionHash>,
+}
+
+pub fn validate_hour_transaction(
+  hour_transaction: HourTransaction,
+) -> ExternResult<ValidateCallbackResult> {
+  if hour_transaction.description.is_empty() {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "HourTransaction description cannot be empty",
+    )));
+  }
+
+  let [first_line, second_line] = hour_transaction.lines.as_slice() else {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "An HourTransaction must have exactly two lines",
+    )));
+  };
+
+  if first_line.user == second_line.user {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "The lines of an HourTransaction must be on different users",
+    )));
+  }
+
+  for line in &hour_transaction.lines {
+    if LedgerLineKind::from_str(&line.kind).is_err() {
+      return Ok(ValidateCallbackResult::Invalid(format!(
+        "Ledger line kind must be '{}' or '{}'.",
+        LedgerLineKind::Credit,
+        LedgerLineKind::Debit,
+      )));
+    }
+
+    if line.minutes == 0 {
+      return Ok(ValidateCallbackResult::Invalid(String::from(
+        "Ledger line minutes must be greater than zero",
+      )));
+    }
+  }
+
+  if first_line.signed_minutes() + second_line.signed_minutes() != 0 {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "An HourTransaction must net to zero",
+    )));
+  }
+
+  Ok(ValidateCallbackResult::Valid)
+}
+
+/// Checks that a transaction was countersigned by exactly the agents linked to its users.
+pub fn validate_create_hour_transaction(
+  hour_transaction: HourTransaction,
+  signing_agents: Option<Vec<AgentPubKey>>,
+) -> ExternResult<ValidateCallbackResult> {
+  let result = validate_hour_transaction(hour_transaction.clone())?;
+  if result != ValidateCallbackResult::Valid {
+    return Ok(result);
+  }
+
+  let Some(signing_agents) = signing_agents else {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "An HourTransaction must be countersigned by both users",
+    )));
+  };
+
+  if signing_agents.len() != hour_transaction.lines.len() {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "An HourTransaction must be countersigned by exactly two agents",
+    )));
+  }
+
+  let mut line_agents: Vec<AgentPubKey> = vec![];
+  for line in hour_transaction.lines {
+    let Some(agent) = get_user_agent_link_target(&line.user, line.user_agent_link)? else {
+      return Ok(ValidateCallbackResult::Invalid(String::from(
+        "The agent links of an HourTransaction must be UserAgents links of its users",
+      )));
+    };
+
+    if !signing_agents.contains(&agent) || line_agents.contains(&agent) {
+      return Ok(ValidateCallbackResult::Invalid(String::from(
+        "The signers of an HourTransaction must be the agents linked to its users",
+      )));
+    }
+    line_agents.push(agent);
+  }
+
+  Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_update_hour_transaction(
+  _action: Update,
+  _hour_transaction: HourTransaction,
+  _original_action: EntryCreationAction,
+  _original_hour_transaction: HourTransaction,
+) -> ExternResult<ValidateCallbackResult> {
+  Ok(ValidateCallbackResult::Invalid(String::from(
+    "An HourTransaction cannot be updated",
+  )))
+}
+
+pub fn validate_delete_hour_transaction(
+  _action: Delete,
+  _original_action: EntryCreationAction,
+  _original_hour_transaction: HourTransaction,
+) -> ExternResult<ValidateCallbackResult> {
+  Ok(ValidateCallbackResult::Invalid(String::from(
+    "An HourTransaction cannot be deleted",
+  )))
+}
+
+pub fn validate_create_link_user_hour_transactions(
+  _action: CreateLink,
+  base_address: AnyLinkableHash,
+  target_address: AnyLinkableHash,
+  _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+  let action_hash = target_address
+    .into_action_hash()
+    .ok_or(UtilsError::ActionHashNotFound("hour transaction"))?;
+  let record = must_get_valid_record(action_hash)?;
+  let hour_transaction: crate::HourTransaction = get_app_entry(&record)?.ok_or(wasm_error!(
+    WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+  ))?;
+
+  if !hour_transaction
+    .lines
+    .iter()
+    .any(|line| base_address == AnyLinkableHash::from(line.user.clone()))
+  {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "An HourTransaction can only be linked from one of its users",
+    )));
+  }
+
+  Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_user_hour_transactions(
+  _action: DeleteLink,
+  _original_action: CreateLink,
+  _base: AnyLinkableHash,
+  _target: AnyLinkableHash,
+  _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+  Ok(ValidateCallbackResult::Invalid(String::from(
+    "UserHourTransactions links cannot be deleted",
+  )))
+}
@@ -0,0 +1,200 @@
+pub mod hour_transaction;
+mod tests;
+
+pub use hour_transaction::*;
+
+use hdi::prelude::*;
+use utils::countersigning::get_countersigning_agents;
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type")]
+#[hdk_entry_types]
+#[unit_enum(UnitEntryTypes)]
+pub enum EntryTypes {
+  HourTransaction(HourTransaction),
+}
+
+#[derive(Serialize, Deserialize)]
+#[hdk_link_types]
+pub enum LinkTypes {
+  UserHourTransactions,
+}
+
+#[hdk_extern]
+pub fn genesis_self_check(_data: GenesisSelfCheckData) -> ExternResult<ValidateCallbackResult> {
+  Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_agent_joining(
+  _agent_pub_key: AgentPubKey,
+  _membrane_proof: &Option<MembraneProof>,
+) -> ExternResult<ValidateCallbackResult> {
+  Ok(ValidateCallbackResult::Valid)
+}
+
+/// Validates the provided `Op` to ensure the entry and link types adhere to the defined constraints.
+#[allow(clippy::collapsible_match, clippy::single_match)]
+#[hdk_extern]
+pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
+  let signing_agents = match &op {
+    Op::StoreEntry(StoreEntry { entry, .. }) => get_countersigning_agents(entry),
+    _ => None,
+  };
+  if let FlatOp::StoreEntry(store_entry) = op.flattened::<EntryTypes, LinkTypes>()? {
+    match store_entry {
+      OpEntry::CreateEntry { app_entry, .. } => match app_entry {
+        EntryTypes::HourTransaction(hour_transaction) => {
+          return validate_create_hour_transaction(hour_transaction, signing_agents);
+        }
+      },
+      OpEntry::UpdateEntry { app_entry, .. } => match app_entry {
+        EntryTypes::HourTransaction(hour_transaction) => {
+          return validate_hour_transaction(hour_transaction);
+        }
+      },
+
+      _ => (),
+    }
+  }
+  if let FlatOp::RegisterCreateLink {
+    link_type,
+    base_address,
+    target_address,
+    tag,
+    action,
+  } = op.flattened::<EntryTypes, LinkTypes>()?
+  {
+    match link_type {
+      LinkTypes::UserHourTransactions => {
+        return validate_create_link_user_hour_transactions(
+          action,
+          base_address,
+          target_address,
+          tag,
+        );
+      }
+    }
+  }
+  if let FlatOp::RegisterDeleteLink {
+    link_type,
+    base_address,
+    target_address,
+    tag,
+    original_action,
+    action,
+  } = op.flattened::<EntryTypes, LinkTypes>()?
+  {
+    match link_type {
+      LinkTypes::UserHourTransactions => {
+        return validate_delete_link_user_hour_transactions(
+          action,
+          original_action,
+          base_address,
+          target_address,
+          tag,
+        );
+      }
+    }
+  }
+  if let FlatOp::StoreRecord(store_record) = op.flattened::<EntryTypes, LinkTypes>()? {
+    match store_record {
+      OpRecord::UpdateEntry {
+        original_action_hash,
+        app_entry,
+        action,
+        ..
+      } => {
+        let original_record = must_get_valid_record(original_action_hash)?;
+        let original_action = match original_record.action().clone() {
+          Action::Create(create) => EntryCreationAction::Create(create),
+          Action::Update(update) => EntryCreationAction::Update(update),
+          _ => {
+            return Ok(ValidateCallbackResult::Invalid(
+              "Original action for an update must be a Create or Update action".to_string(),
+            ));
+          }
+        };
+        match app_entry {
+          EntryTypes::HourTransaction(hour_transaction) => {
+            let original_hour_transaction: HourTransaction = match original_record
+              .entry()
+              .to_app_option()
+              .map_err(|e| wasm_error!(e))?
+            {
+              Some(original_hour_transaction) => original_hour_transaction,
+              None => {
+                return Ok(ValidateCallbackResult::Invalid(
+                  "The updated entry type must be the same as the original entry type".to_string(),
+                ));
+              }
+            };
+            return validate_update_hour_transaction(
+              action,
+              hour_transaction,
+              original_action,
+              original_hour_transaction,
+            );
+          }
+        }
+      }
+      OpRecord::DeleteEntry {
+        original_action_hash,
+        action,
+        ..
+      } => {
+        let original_record = must_get_valid_record(original_action_hash)?;
+        let original_action = original_record.action().clone();
+        let original_action = match original_action {
+          Action::Create(create) => EntryCreationAction::Create(create),
+          Action::Update(update) => EntryCreationAction::Update(update),
+          _ => {
+            return Ok(ValidateCallbackResult::Invalid(
+              "Original action for a delete must be a Create or Update action".to_string(),
+            ));
+          }
+        };
+        let app_entry_type = match original_action.entry_type() {
+          EntryType::App(app_entry_type) => app_entry_type,
+          _ => {
+            return Ok(ValidateCallbackResult::Valid);
+          }
+        };
+        let entry = match original_record.entry().as_option() {
+          Some(entry) => entry,
+          None => {
+            if original_action.entry_type().visibility().is_public() {
+              return Ok(ValidateCallbackResult::Invalid(
+                "Original record for a delete of a public entry must contain an entry".to_string(),
+              ));
+            } else {
+              return Ok(ValidateCallbackResult::Valid);
+            }
+          }
+        };
+        let original_app_entry = match EntryTypes::deserialize_from_type(
+          *app_entry_type.zome_index,
+          app_entry_type.entry_index,
+          entry,
+        )? {
+          Some(app_entry) => app_entry,
+          None => {
+            return Ok(ValidateCallbackResult::Invalid(
+              "Original app entry must be one of the defined entry types for this zome".to_string(),
+            ));
+          }
+        };
+        match original_app_entry {
+          EntryTypes::HourTransaction(original_hour_transaction) => {
+            return validate_delete_hour_transaction(
+              action,
+              original_action,
+              original_hour_transaction,
+            );
+          }
+        }
+      }
+      _ => (),
+    }
+  }
+  Ok(ValidateCallbackResult::Valid)
+}
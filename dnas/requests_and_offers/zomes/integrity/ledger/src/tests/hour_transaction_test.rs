@@ -0,0 +1,116 @@
+#[cfg(test)]
+mod tests {
+  use hdi::prelude::{ActionHash, ValidateCallbackResult};
+
+  use crate::{
+    validate_create_hour_transaction, validate_hour_transaction, HourTransaction, LedgerLine,
+  };
+
+  fn sample_line(user: u8, kind: &str, minutes: u32) -> LedgerLine {
+    LedgerLine {
+      user: ActionHash::from_raw_36(vec![user; 36]),
+      user_agent_link: ActionHash::from_raw_36(vec![user + 10; 36]),
+      kind: kind.to_string(),
+      minutes,
+    }
+  }
+
+  fn sample_hour_transaction() -> HourTransaction {
+    HourTransaction {
+      lines: vec![sample_line(0, "credit", 90), sample_line(1, "debit", 90)],
+      description: "Garden help".to_string(),
+      reference: None,
+    }
+  }
+
+  #[test]
+  fn test_hour_transaction_is_valid() {
+    let result = validate_hour_transaction(sample_hour_transaction()).unwrap();
+    assert!(result == ValidateCallbackResult::Valid);
+  }
+
+  #[test]
+  fn test_hour_transaction_must_net_to_zero() {
+    let hour_transaction = HourTransaction {
+      lines: vec![sample_line(0, "credit", 90), sample_line(1, "debit", 60)],
+      ..sample_hour_transaction()
+    };
+    let result = validate_hour_transaction(hour_transaction).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from("An HourTransaction must net to zero"))
+    );
+
+    let hour_transaction = HourTransaction {
+      lines: vec![sample_line(0, "credit", 90), sample_line(1, "credit", 90)],
+      ..sample_hour_transaction()
+    };
+    let result = validate_hour_transaction(hour_transaction).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from("An HourTransaction must net to zero"))
+    );
+  }
+
+  #[test]
+  fn test_hour_transaction_lines() {
+    let hour_transaction = HourTransaction {
+      lines: vec![sample_line(0, "credit", 90)],
+      ..sample_hour_transaction()
+    };
+    let result = validate_hour_transaction(hour_transaction).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "An HourTransaction must have exactly two lines"
+        ))
+    );
+
+    let hour_transaction = HourTransaction {
+      lines: vec![sample_line(0, "credit", 90), sample_line(0, "debit", 90)],
+      ..sample_hour_transaction()
+    };
+    let result = validate_hour_transaction(hour_transaction).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "The lines of an HourTransaction must be on different users"
+        ))
+    );
+
+    let hour_transaction = HourTransaction {
+      lines: vec![sample_line(0, "gift", 90), sample_line(1, "debit", 90)],
+      ..sample_hour_transaction()
+    };
+    let result = validate_hour_transaction(hour_transaction).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "Ledger line kind must be 'credit' or 'debit'."
+        ))
+    );
+
+    let hour_transaction = HourTransaction {
+      lines: vec![sample_line(0, "credit", 0), sample_line(1, "debit", 0)],
+      ..sample_hour_transaction()
+    };
+    let result = validate_hour_transaction(hour_transaction).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "Ledger line minutes must be greater than zero"
+        ))
+    );
+  }
+
+  #[test]
+  fn test_hour_transaction_must_be_countersigned() {
+    let result = validate_create_hour_transaction(sample_hour_transaction(), None).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "An HourTransaction must be countersigned by both users"
+        ))
+    );
+  }
+}
@@ -0,0 +1 @@
+mod hour_transaction_test;
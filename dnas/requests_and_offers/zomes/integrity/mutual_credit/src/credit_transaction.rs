@@ -0,0 +1,236 @@
+use hdi::prelude::*;
+use utils::{
+  countersigning::{app_entry_from_entry, get_app_entry},
+  errors::UtilsError,
+  DnaProperties,
+};
+
+use crate::UnitEntryTypes;
+
+/// Represents credits spent by an agent to another, carrying the running balances of both
+/// agents after the transaction. It is committed to both source chains through a
+/// countersigning session.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct CreditTransaction {
+  pub spender: AgentPubKey,
+  pub recipient: AgentPubKey,
+  pub amount: u32,
+  /// The balance of the spender after the transaction.
+  pub spender_balance: i64,
+  /// The balance of the recipient after the transaction.
+  pub recipient_balance: i64,
+  pub description: String,
+}
+
+impl CreditTransaction {
+  /// Returns the balance of an agent after the transaction, if it is one of its parties.
+  pub fn balance_of(&self, agent: &AgentPubKey) -> Option<i64> {
+    if *agent == self.spender {
+      Some(self.spender_balance)
+    } else if *agent == self.recipient {
+      Some(self.recipient_balance)
+    } else {
+      None
+    }
+  }
+}
+
+pub fn validate_credit_transaction(
+  credit_transaction: CreditTransaction,
+) -> ExternResult<ValidateCallbackResult> {
+  if credit_transaction.amount == 0 {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "CreditTransaction amount must be greater than zero",
+    )));
+  }
+
+  if credit_transaction.spender == credit_transaction.recipient {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "The spender and the recipient of a CreditTransaction must be different",
+    )));
+  }
+
+  Ok(ValidateCallbackResult::Valid)
+}
+
+/// Checks that a transaction was countersigned by exactly its spender and recipient.
+pub fn validate_create_credit_transaction(
+  credit_transaction: CreditTransaction,
+  signing_agents: Option<Vec<AgentPubKey>>,
+) -> ExternResult<ValidateCallbackResult> {
+  let result = validate_credit_transaction(credit_transaction.clone())?;
+  if result != ValidateCallbackResult::Valid {
+    return Ok(result);
+  }
+
+  let Some(signing_agents) = signing_agents else {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "A CreditTransaction must be countersigned by both agents",
+    )));
+  };
+
+  if signing_agents.len() != 2
+    || !signing_agents.contains(&credit_transaction.spender)
+    || !signing_agents.contains(&credit_transaction.recipient)
+  {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "The signers of a CreditTransaction must be its spender and its recipient",
+    )));
+  }
+
+  Ok(ValidateCallbackResult::Valid)
+}
+
+/// Checks the running balance of the author of a transaction given its balance before the
+/// transaction.
+pub fn validate_credit_transaction_balance(
+  credit_transaction: &CreditTransaction,
+  author: &AgentPubKey,
+  previous_balance: i64,
+  credit_limit: u32,
+) -> ValidateCallbackResult {
+  let amount = credit_transaction.amount as i64;
+  let (balance, expected_balance) = if *author == credit_transaction.spender {
+    (
+      credit_transaction.spender_balance,
+      previous_balance - amount,
+    )
+  } else if *author == credit_transaction.recipient {
+    (
+      credit_transaction.recipient_balance,
+      previous_balance + amount,
+    )
+  } else {
+    return ValidateCallbackResult::Invalid(String::from(
+      "Only the spender or the recipient of a CreditTransaction can commit it",
+    ));
+  };
+
+  if balance != expected_balance {
+    return ValidateCallbackResult::Invalid(format!(
+      "The running balance should be {}",
+      expected_balance
+    ));
+  }
+
+  if balance < -(credit_limit as i64) {
+    return ValidateCallbackResult::Invalid(format!(
+      "A balance cannot go below the credit limit of -{}",
+      credit_limit
+    ));
+  }
+
+  ValidateCallbackResult::Valid
+}
+
+/// Walks the chain of an agent back from an action to find its balance after its latest
+/// transaction.
+pub fn get_balance_at(agent: AgentPubKey, action_hash: ActionHash) -> ExternResult<i64> {
+  let ScopedEntryDefIndex {
+    zome_index,
+    zome_type,
+  } = UnitEntryTypes::CreditTransaction.try_into()?;
+
+  let latest_transaction = must_get_agent_activity(agent.clone(), ChainFilter::new(action_hash))?
+    .into_iter()
+    .filter_map(|activity| match activity.action.hashed.content {
+      Action::Create(create) => match create.entry_type {
+        EntryType::App(app_entry_def)
+          if app_entry_def.zome_index == zome_index && app_entry_def.entry_index == zome_type =>
+        {
+          Some((create.action_seq, create.entry_hash))
+        }
+        _ => None,
+      },
+      _ => None,
+    })
+    .max_by_key(|(action_seq, _)| *action_seq);
+
+  let Some((_, entry_hash)) = latest_transaction else {
+    return Ok(0);
+  };
+
+  let credit_transaction: CreditTransaction = app_entry_from_entry(
+    must_get_entry(entry_hash)?.as_content(),
+  )?
+  .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+    "Could not find the previous CreditTransaction"
+  ))))?;
+
+  Ok(credit_transaction.balance_of(&agent).unwrap_or(0))
+}
+
+/// Rejects the transaction if the running balance of its author does not follow from the
+/// author's previous transaction or goes below the credit limit of the DNA properties.
+pub fn validate_create_credit_transaction_balance(
+  action: Create,
+  credit_transaction: CreditTransaction,
+) -> ExternResult<ValidateCallbackResult> {
+  let previous_balance = get_balance_at(action.author.clone(), action.prev_action.clone())?;
+
+  Ok(validate_credit_transaction_balance(
+    &credit_transaction,
+    &action.author,
+    previous_balance,
+    DnaProperties::get_mutual_credit_limit()?,
+  ))
+}
+
+pub fn validate_update_credit_transaction(
+  _action: Update,
+  _credit_transaction: CreditTransaction,
+  _original_action: EntryCreationAction,
+  _original_credit_transaction: CreditTransaction,
+) -> ExternResult<ValidateCallbackResult> {
+  Ok(ValidateCallbackResult::Invalid(String::from(
+    "A CreditTransaction cannot be updated",
+  )))
+}
+
+pub fn validate_delete_credit_transaction(
+  _action: Delete,
+  _original_action: EntryCreationAction,
+  _original_credit_transaction: CreditTransaction,
+) -> ExternResult<ValidateCallbackResult> {
+  Ok(ValidateCallbackResult::Invalid(String::from(
+    "A CreditTransaction cannot be deleted",
+  )))
+}
+
+pub fn validate_create_link_agent_credit_transactions(
+  _action: CreateLink,
+  base_address: AnyLinkableHash,
+  target_address: AnyLinkableHash,
+  _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+  let action_hash = target_address
+    .into_action_hash()
+    .ok_or(UtilsError::ActionHashNotFound("credit transaction"))?;
+  let record = must_get_valid_record(action_hash)?;
+  let credit_transaction: crate::CreditTransaction = get_app_entry(&record)?.ok_or(wasm_error!(
+    WasmErrorInner::Guest(String::from("Linked action must reference an entry"))
+  ))?;
+
+  if base_address != AnyLinkableHash::from(credit_transaction.spender)
+    && base_address != AnyLinkableHash::from(credit_transaction.recipient)
+  {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "A CreditTransaction can only be linked from one of its agents",
+    )));
+  }
+
+  Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_agent_credit_transactions(
+  _action: DeleteLink,
+  _original_action: CreateLink,
+  _base: AnyLinkableHash,
+  _target: AnyLinkableHash,
+  _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+  Ok(ValidateCallbackResult::Invalid(String::from(
+    "AgentCreditTransactions links cannot be deleted",
+  )))
+}
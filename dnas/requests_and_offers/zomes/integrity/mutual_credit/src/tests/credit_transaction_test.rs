@@ -0,0 +1,127 @@
+#[cfg(test)]
+mod tests {
+  use hdi::prelude::{AgentPubKey, ValidateCallbackResult};
+
+  use crate::{
+    validate_create_credit_transaction, validate_credit_transaction,
+    validate_credit_transaction_balance, CreditTransaction,
+  };
+
+  fn spender() -> AgentPubKey {
+    AgentPubKey::from_raw_36(vec![0; 36])
+  }
+
+  fn recipient() -> AgentPubKey {
+    AgentPubKey::from_raw_36(vec![1; 36])
+  }
+
+  fn sample_credit_transaction() -> CreditTransaction {
+    CreditTransaction {
+      spender: spender(),
+      recipient: recipient(),
+      amount: 30,
+      spender_balance: -30,
+      recipient_balance: 30,
+      description: "Bread".to_string(),
+    }
+  }
+
+  #[test]
+  fn test_credit_transaction_is_valid() {
+    let result = validate_credit_transaction(sample_credit_transaction()).unwrap();
+    assert!(result == ValidateCallbackResult::Valid);
+  }
+
+  #[test]
+  fn test_credit_transaction_with_zero_amount() {
+    let credit_transaction = CreditTransaction {
+      amount: 0,
+      ..sample_credit_transaction()
+    };
+    let result = validate_credit_transaction(credit_transaction).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "CreditTransaction amount must be greater than zero"
+        ))
+    );
+  }
+
+  #[test]
+  fn test_credit_transaction_must_be_countersigned() {
+    let result = validate_create_credit_transaction(sample_credit_transaction(), None).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "A CreditTransaction must be countersigned by both agents"
+        ))
+    );
+
+    let result = validate_create_credit_transaction(
+      sample_credit_transaction(),
+      Some(vec![spender(), AgentPubKey::from_raw_36(vec![2; 36])]),
+    )
+    .unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "The signers of a CreditTransaction must be its spender and its recipient"
+        ))
+    );
+
+    let result = validate_create_credit_transaction(
+      sample_credit_transaction(),
+      Some(vec![recipient(), spender()]),
+    )
+    .unwrap();
+    assert!(result == ValidateCallbackResult::Valid);
+  }
+
+  #[test]
+  fn test_credit_transaction_running_balances() {
+    let credit_transaction = sample_credit_transaction();
+
+    let result = validate_credit_transaction_balance(&credit_transaction, &spender(), 0, 100);
+    assert!(result == ValidateCallbackResult::Valid);
+
+    let result = validate_credit_transaction_balance(&credit_transaction, &recipient(), 0, 100);
+    assert!(result == ValidateCallbackResult::Valid);
+
+    let result = validate_credit_transaction_balance(&credit_transaction, &spender(), 20, 100);
+    assert!(
+      result == ValidateCallbackResult::Invalid(String::from("The running balance should be -10"))
+    );
+
+    let result = validate_credit_transaction_balance(
+      &credit_transaction,
+      &AgentPubKey::from_raw_36(vec![2; 36]),
+      0,
+      100,
+    );
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "Only the spender or the recipient of a CreditTransaction can commit it"
+        ))
+    );
+  }
+
+  #[test]
+  fn test_credit_transaction_credit_limit() {
+    let credit_transaction = CreditTransaction {
+      amount: 30,
+      spender_balance: -110,
+      ..sample_credit_transaction()
+    };
+    let result = validate_credit_transaction_balance(&credit_transaction, &spender(), -80, 100);
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "A balance cannot go below the credit limit of -100"
+        ))
+    );
+
+    let result = validate_credit_transaction_balance(&credit_transaction, &spender(), -80, 120);
+    assert!(result == ValidateCallbackResult::Valid);
+  }
+}
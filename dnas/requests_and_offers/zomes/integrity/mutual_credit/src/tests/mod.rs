@@ -0,0 +1 @@
+mod credit_transaction_test;
@@ -0,0 +1,152 @@
+use hdi::prelude::*;
+use utils::{
+  errors::UtilsError,
+  lifecycle::LISTING_LIFECYCLE,
+  on_behalf_of::{validate_on_behalf_of, OnBehalfOf},
+};
+
+/// Represents an offer Entry published by a user, optionally on behalf of an organization.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct Offer {
+  /// A short title summarizing what is offered.
+  pub title: String,
+  /// A detailed description of the offer, such as mentoring, testing or review.
+  pub description: String,
+  /// A list of skills the offer relies on.
+  pub skills: Vec<String>,
+  /// An optional number of hours the offerer can give.
+  pub hours_available: Option<u32>,
+  /// The lifecycle state of the offer, one of 'open', 'in progress', 'fulfilled' or 'closed'.
+  pub state: String,
+  /// The organization the offer is published for, if any.
+  #[serde(default)]
+  pub on_behalf_of: Option<OnBehalfOf>,
+}
+
+pub fn validate_offer(offer: Offer) -> ExternResult<ValidateCallbackResult> {
+  if offer.title.is_empty() {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "Offer title cannot be empty",
+    )));
+  }
+
+  if offer.description.is_empty() {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "Offer description cannot be empty",
+    )));
+  }
+
+  if offer.hours_available == Some(0) {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "Hours available must be greater than zero",
+    )));
+  }
+
+  Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_create_offer(offer: Offer) -> ExternResult<ValidateCallbackResult> {
+  let result = validate_offer(offer.clone())?;
+  if result != ValidateCallbackResult::Valid {
+    return Ok(result);
+  }
+
+  LISTING_LIFECYCLE.validate_initial_state(&offer.state)
+}
+
+/// Checks that an Offer published for an organization was authored by one of its coordinators.
+pub fn validate_offer_on_behalf_of(
+  action: &Create,
+  offer: &Offer,
+) -> ExternResult<ValidateCallbackResult> {
+  match &offer.on_behalf_of {
+    Some(on_behalf_of) => validate_on_behalf_of(
+      on_behalf_of,
+      &action.author,
+      action.timestamp,
+      &action.prev_action,
+    ),
+    None => Ok(ValidateCallbackResult::Valid),
+  }
+}
+
+pub fn validate_update_offer(
+  action: Update,
+  offer: Offer,
+  original_action: EntryCreationAction,
+  original_offer: Offer,
+) -> ExternResult<ValidateCallbackResult> {
+  let is_author = action.author == *original_action.author();
+  if !is_author {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "Only the author of an Offer can update it",
+    )));
+  }
+
+  if offer.on_behalf_of != original_offer.on_behalf_of {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "The organization of an Offer cannot be changed",
+    )));
+  }
+
+  LISTING_LIFECYCLE.validate_transition(&original_offer.state, &offer.state, is_author)
+}
+
+pub fn validate_delete_offer(
+  action: Delete,
+  original_action: EntryCreationAction,
+  _original_offer: Offer,
+) -> ExternResult<ValidateCallbackResult> {
+  if action.author != *original_action.author() {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "Only the author of an Offer can delete it",
+    )));
+  }
+
+  Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_create_link_offer_updates(
+  _action: CreateLink,
+  base_address: AnyLinkableHash,
+  target_address: AnyLinkableHash,
+  _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+  let action_hash = base_address
+    .into_action_hash()
+    .ok_or(UtilsError::ActionHashNotFound("offer"))?;
+  let record = must_get_valid_record(action_hash)?;
+  let _offer: crate::Offer = record
+    .entry()
+    .to_app_option()
+    .map_err(|e| wasm_error!(e))?
+    .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+      "Linked action must reference an entry"
+    ))))?;
+  // Check the entry type for the given action hash
+  let action_hash = target_address
+    .into_action_hash()
+    .ok_or(UtilsError::ActionHashNotFound("offer"))?;
+  let record = must_get_valid_record(action_hash)?;
+  let _offer: crate::Offer = record
+    .entry()
+    .to_app_option()
+    .map_err(|e| wasm_error!(e))?
+    .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+      "Linked action must reference an entry"
+    ))))?;
+  Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_offer_updates(
+  _action: DeleteLink,
+  _original_action: CreateLink,
+  _base: AnyLinkableHash,
+  _target: AnyLinkableHash,
+  _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+  Ok(ValidateCallbackResult::Invalid(String::from(
+    "OfferUpdates links cannot be deleted",
+  )))
+}
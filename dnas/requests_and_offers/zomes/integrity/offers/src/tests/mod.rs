@@ -0,0 +1 @@
+mod offer_test;
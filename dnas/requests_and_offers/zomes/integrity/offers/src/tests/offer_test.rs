@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod tests {
+  use hdi::prelude::ValidateCallbackResult;
+
+  use crate::{validate_create_offer, validate_offer, Offer};
+
+  fn sample_offer() -> Offer {
+    Offer {
+      title: "Code reviews".to_string(),
+      description: "I can review Holochain zomes".to_string(),
+      skills: vec!["Rust".to_string(), "Holochain".to_string()],
+      hours_available: Some(4),
+      state: "open".to_string(),
+      on_behalf_of: None,
+    }
+  }
+
+  #[test]
+  fn test_offer_is_valid() {
+    let result = validate_offer(sample_offer()).unwrap();
+    assert!(result == ValidateCallbackResult::Valid);
+  }
+
+  #[test]
+  fn test_offer_with_empty_title() {
+    let offer = Offer {
+      title: "".to_string(),
+      ..sample_offer()
+    };
+    let result = validate_offer(offer).unwrap();
+    assert!(result == ValidateCallbackResult::Invalid(String::from("Offer title cannot be empty")));
+  }
+
+  #[test]
+  fn test_offer_with_empty_description() {
+    let offer = Offer {
+      description: "".to_string(),
+      ..sample_offer()
+    };
+    let result = validate_offer(offer).unwrap();
+    assert!(
+      result == ValidateCallbackResult::Invalid(String::from("Offer description cannot be empty"))
+    );
+  }
+
+  #[test]
+  fn test_offer_with_zero_hours_available() {
+    let offer = Offer {
+      hours_available: Some(0),
+      ..sample_offer()
+    };
+    let result = validate_offer(offer).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "Hours available must be greater than zero"
+        ))
+    );
+  }
+
+  #[test]
+  fn test_offer_must_be_created_open() {
+    let result = validate_create_offer(sample_offer()).unwrap();
+    assert!(result == ValidateCallbackResult::Valid);
+
+    let offer = Offer {
+      state: "closed".to_string(),
+      ..sample_offer()
+    };
+    let result = validate_create_offer(offer).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "An entry cannot be created in the 'closed' state"
+        ))
+    );
+  }
+}
@@ -0,0 +1,234 @@
+use std::{fmt::Display, str::FromStr};
+
+use hdi::prelude::*;
+use utils::{
+  errors::UtilsError,
+  lifecycle::LISTING_LIFECYCLE,
+  on_behalf_of::{validate_on_behalf_of, OnBehalfOf},
+};
+
+/// Represents a request Entry posted by a user, optionally on behalf of an organization.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct Request {
+  /// A short title summarizing what is needed.
+  pub title: String,
+  /// A detailed description of the request.
+  pub description: String,
+  /// A list of skills needed to fulfill the request.
+  pub skills: Vec<String>,
+  /// How urgent the request is, either 'low', 'medium' or 'high'.
+  pub urgency: String,
+  /// An optional estimation of the time needed, in hours.
+  pub time_estimate_hours: Option<u32>,
+  /// How the requester prefers to be contacted, either 'email', 'phone' or 'other'.
+  pub contact_preference: String,
+  /// The lifecycle state of the request, one of 'open', 'in progress', 'fulfilled' or 'closed'.
+  pub state: String,
+  /// The organization the request is published for, if any.
+  #[serde(default)]
+  pub on_behalf_of: Option<OnBehalfOf>,
+}
+
+pub enum Urgency {
+  Low,
+  Medium,
+  High,
+}
+
+impl Display for Urgency {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Low => write!(f, "low"),
+      Self::Medium => write!(f, "medium"),
+      Self::High => write!(f, "high"),
+    }
+  }
+}
+
+impl FromStr for Urgency {
+  type Err = ();
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "low" => Ok(Self::Low),
+      "medium" => Ok(Self::Medium),
+      "high" => Ok(Self::High),
+      _ => Err(()),
+    }
+  }
+}
+
+pub enum ContactPreference {
+  Email,
+  Phone,
+  Other,
+}
+
+impl Display for ContactPreference {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Email => write!(f, "email"),
+      Self::Phone => write!(f, "phone"),
+      Self::Other => write!(f, "other"),
+    }
+  }
+}
+
+impl FromStr for ContactPreference {
+  type Err = ();
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "email" => Ok(Self::Email),
+      "phone" => Ok(Self::Phone),
+      "other" => Ok(Self::Other),
+      _ => Err(()),
+    }
+  }
+}
+
+pub fn validate_request(request: Request) -> ExternResult<ValidateCallbackResult> {
+  if request.title.is_empty() {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "Request title cannot be empty",
+    )));
+  }
+
+  if request.description.is_empty() {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "Request description cannot be empty",
+    )));
+  }
+
+  if Urgency::from_str(request.urgency.as_str()).is_err() {
+    return Ok(ValidateCallbackResult::Invalid(format!(
+      "Urgency must be '{}', '{}' or '{}'.",
+      Urgency::Low,
+      Urgency::Medium,
+      Urgency::High,
+    )));
+  }
+
+  if request.time_estimate_hours == Some(0) {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "Time estimate must be greater than zero",
+    )));
+  }
+
+  if ContactPreference::from_str(request.contact_preference.as_str()).is_err() {
+    return Ok(ValidateCallbackResult::Invalid(format!(
+      "Contact preference must be '{}', '{}' or '{}'.",
+      ContactPreference::Email,
+      ContactPreference::Phone,
+      ContactPreference::Other,
+    )));
+  }
+
+  Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_create_request(request: Request) -> ExternResult<ValidateCallbackResult> {
+  let result = validate_request(request.clone())?;
+  if result != ValidateCallbackResult::Valid {
+    return Ok(result);
+  }
+
+  LISTING_LIFECYCLE.validate_initial_state(&request.state)
+}
+
+/// Checks that a Request published for an organization was authored by one of its coordinators.
+pub fn validate_request_on_behalf_of(
+  action: &Create,
+  request: &Request,
+) -> ExternResult<ValidateCallbackResult> {
+  match &request.on_behalf_of {
+    Some(on_behalf_of) => validate_on_behalf_of(
+      on_behalf_of,
+      &action.author,
+      action.timestamp,
+      &action.prev_action,
+    ),
+    None => Ok(ValidateCallbackResult::Valid),
+  }
+}
+
+pub fn validate_update_request(
+  action: Update,
+  request: Request,
+  original_action: EntryCreationAction,
+  original_request: Request,
+) -> ExternResult<ValidateCallbackResult> {
+  let is_author = action.author == *original_action.author();
+  if !is_author {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "Only the author of a Request can update it",
+    )));
+  }
+
+  if request.on_behalf_of != original_request.on_behalf_of {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "The organization of a Request cannot be changed",
+    )));
+  }
+
+  LISTING_LIFECYCLE.validate_transition(&original_request.state, &request.state, is_author)
+}
+
+pub fn validate_delete_request(
+  action: Delete,
+  original_action: EntryCreationAction,
+  _original_request: Request,
+) -> ExternResult<ValidateCallbackResult> {
+  if action.author != *original_action.author() {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "Only the author of a Request can delete it",
+    )));
+  }
+
+  Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_create_link_request_updates(
+  _action: CreateLink,
+  base_address: AnyLinkableHash,
+  target_address: AnyLinkableHash,
+  _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+  let action_hash = base_address
+    .into_action_hash()
+    .ok_or(UtilsError::ActionHashNotFound("request"))?;
+  let record = must_get_valid_record(action_hash)?;
+  let _request: crate::Request = record
+    .entry()
+    .to_app_option()
+    .map_err(|e| wasm_error!(e))?
+    .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+      "Linked action must reference an entry"
+    ))))?;
+  // Check the entry type for the given action hash
+  let action_hash = target_address
+    .into_action_hash()
+    .ok_or(UtilsError::ActionHashNotFound("request"))?;
+  let record = must_get_valid_record(action_hash)?;
+  let _request: crate::Request = record
+    .entry()
+    .to_app_option()
+    .map_err(|e| wasm_error!(e))?
+    .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+      "Linked action must reference an entry"
+    ))))?;
+  Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_request_updates(
+  _action: DeleteLink,
+  _original_action: CreateLink,
+  _base: AnyLinkableHash,
+  _target: AnyLinkableHash,
+  _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+  Ok(ValidateCallbackResult::Invalid(String::from(
+    "RequestUpdates links cannot be deleted",
+  )))
+}
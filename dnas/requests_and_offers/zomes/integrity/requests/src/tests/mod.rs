@@ -0,0 +1 @@
+mod request_test;
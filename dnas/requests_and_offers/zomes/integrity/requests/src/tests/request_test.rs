@@ -0,0 +1,155 @@
+#[cfg(test)]
+mod tests {
+  use hdi::prelude::ValidateCallbackResult;
+  use utils::lifecycle::LISTING_LIFECYCLE;
+
+  use crate::{validate_create_request, validate_request, Request};
+
+  fn sample_request() -> Request {
+    Request {
+      title: "Need a code review".to_string(),
+      description: "Looking for someone to review my first zome".to_string(),
+      skills: vec!["Rust".to_string(), "Holochain".to_string()],
+      urgency: "medium".to_string(),
+      time_estimate_hours: Some(2),
+      contact_preference: "email".to_string(),
+      state: "open".to_string(),
+      on_behalf_of: None,
+    }
+  }
+
+  #[test]
+  fn test_request_is_valid() {
+    let result = validate_request(sample_request()).unwrap();
+    assert!(result == ValidateCallbackResult::Valid);
+  }
+
+  #[test]
+  fn test_request_with_empty_title() {
+    let request = Request {
+      title: "".to_string(),
+      ..sample_request()
+    };
+    let result = validate_request(request).unwrap();
+    assert!(
+      result == ValidateCallbackResult::Invalid(String::from("Request title cannot be empty"))
+    );
+  }
+
+  #[test]
+  fn test_request_with_empty_description() {
+    let request = Request {
+      description: "".to_string(),
+      ..sample_request()
+    };
+    let result = validate_request(request).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from("Request description cannot be empty"))
+    );
+  }
+
+  #[test]
+  fn test_request_with_invalid_urgency() {
+    let request = Request {
+      urgency: "yesterday".to_string(),
+      ..sample_request()
+    };
+    let result = validate_request(request).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "Urgency must be 'low', 'medium' or 'high'."
+        ))
+    );
+  }
+
+  #[test]
+  fn test_request_with_zero_time_estimate() {
+    let request = Request {
+      time_estimate_hours: Some(0),
+      ..sample_request()
+    };
+    let result = validate_request(request).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from("Time estimate must be greater than zero"))
+    );
+  }
+
+  #[test]
+  fn test_request_with_invalid_contact_preference() {
+    let request = Request {
+      contact_preference: "pigeon".to_string(),
+      ..sample_request()
+    };
+    let result = validate_request(request).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "Contact preference must be 'email', 'phone' or 'other'."
+        ))
+    );
+  }
+
+  #[test]
+  fn test_request_must_be_created_open() {
+    let result = validate_create_request(sample_request()).unwrap();
+    assert!(result == ValidateCallbackResult::Valid);
+
+    let request = Request {
+      state: "fulfilled".to_string(),
+      ..sample_request()
+    };
+    let result = validate_create_request(request).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "An entry cannot be created in the 'fulfilled' state"
+        ))
+    );
+  }
+
+  #[test]
+  fn test_request_state_transitions() {
+    let result = LISTING_LIFECYCLE
+      .validate_transition("open", "in progress", true)
+      .unwrap();
+    assert!(result == ValidateCallbackResult::Valid);
+
+    let result = LISTING_LIFECYCLE
+      .validate_transition("in progress", "fulfilled", true)
+      .unwrap();
+    assert!(result == ValidateCallbackResult::Valid);
+
+    let result = LISTING_LIFECYCLE
+      .validate_transition("open", "fulfilled", true)
+      .unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "Cannot go from the 'open' state to the 'fulfilled' state"
+        ))
+    );
+
+    let result = LISTING_LIFECYCLE
+      .validate_transition("closed", "open", true)
+      .unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "Cannot go from the 'closed' state to the 'open' state"
+        ))
+    );
+
+    let result = LISTING_LIFECYCLE
+      .validate_transition("open", "in progress", false)
+      .unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "Only the author can go from the 'open' state to the 'in progress' state"
+        ))
+    );
+  }
+}
@@ -0,0 +1,261 @@
+use std::{fmt::Display, str::FromStr};
+
+use hdi::prelude::*;
+use utils::{
+  errors::UtilsError,
+  lifecycle::{Lifecycle, Transition, TransitionActor},
+  on_behalf_of::{validate_on_behalf_of, OnBehalfOf},
+};
+
+/// Represents a response Entry sent by a user about a published item, such as an organization.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct Response {
+  /// The original action hash of the item the response is about.
+  pub item_original_action_hash: ActionHash,
+  /// The message sent to the author of the item.
+  pub message: String,
+  /// The status of the response, either 'pending', 'accepted' or 'declined'.
+  pub status: String,
+  /// The organization the response was accepted or declined for, when a coordinator of the
+  /// item decided it rather than its author.
+  #[serde(default)]
+  pub decided_on_behalf_of: Option<OnBehalfOf>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ResponseStatus {
+  Pending,
+  Accepted,
+  Declined,
+}
+
+impl Display for ResponseStatus {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Pending => write!(f, "pending"),
+      Self::Accepted => write!(f, "accepted"),
+      Self::Declined => write!(f, "declined"),
+    }
+  }
+}
+
+impl FromStr for ResponseStatus {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "pending" => Ok(Self::Pending),
+      "accepted" => Ok(Self::Accepted),
+      "declined" => Ok(Self::Declined),
+      _ => Err(format!("Invalid response status: {}", s)),
+    }
+  }
+}
+
+/// A response is sent pending and is then accepted or declined by someone other than the
+/// responder, which `validate_response_decider` narrows to the author or coordinators of the
+/// item.
+pub const RESPONSE_LIFECYCLE: Lifecycle<ResponseStatus> = Lifecycle {
+  initial_states: &[ResponseStatus::Pending],
+  transitions: &[
+    Transition {
+      from: ResponseStatus::Pending,
+      to: ResponseStatus::Accepted,
+      actor: TransitionActor::Other,
+    },
+    Transition {
+      from: ResponseStatus::Pending,
+      to: ResponseStatus::Declined,
+      actor: TransitionActor::Other,
+    },
+  ],
+};
+
+pub fn validate_response(response: Response) -> ExternResult<ValidateCallbackResult> {
+  if response.message.is_empty() {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "Response message cannot be empty",
+    )));
+  }
+
+  if ResponseStatus::from_str(&response.status).is_err() {
+    return Ok(ValidateCallbackResult::Invalid(format!(
+      "Response status must be '{}', '{}' or '{}'.",
+      ResponseStatus::Pending,
+      ResponseStatus::Accepted,
+      ResponseStatus::Declined,
+    )));
+  }
+
+  Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_create_response(response: Response) -> ExternResult<ValidateCallbackResult> {
+  let result = validate_response(response.clone())?;
+  if result != ValidateCallbackResult::Valid {
+    return Ok(result);
+  }
+
+  if response.decided_on_behalf_of.is_some() {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "A Response cannot be created as decided",
+    )));
+  }
+
+  RESPONSE_LIFECYCLE.validate_initial_state(&response.status)
+}
+
+pub fn validate_update_response(
+  action: Update,
+  response: Response,
+  original_action: EntryCreationAction,
+  original_response: Response,
+) -> ExternResult<ValidateCallbackResult> {
+  if response.item_original_action_hash != original_response.item_original_action_hash {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "The item of a Response cannot be changed",
+    )));
+  }
+
+  let is_author = action.author == *original_action.author();
+  if response.status == original_response.status {
+    if !is_author {
+      return Ok(ValidateCallbackResult::Invalid(String::from(
+        "Only the author of a Response can edit it",
+      )));
+    }
+    if response.decided_on_behalf_of != original_response.decided_on_behalf_of {
+      return Ok(ValidateCallbackResult::Invalid(String::from(
+        "The decision of a Response cannot be changed while editing it",
+      )));
+    }
+    return Ok(ValidateCallbackResult::Valid);
+  }
+
+  if response.message != original_response.message {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "The message of a Response cannot be changed while accepting or declining it",
+    )));
+  }
+
+  let result = RESPONSE_LIFECYCLE.validate_transition(
+    &original_response.status,
+    &response.status,
+    is_author,
+  )?;
+  if result != ValidateCallbackResult::Valid {
+    return Ok(result);
+  }
+
+  validate_response_decider(&action, &response)
+}
+
+/// Validates that a Response is accepted or declined by the author of its item, or by a
+/// coordinator of the item when it is an organization.
+pub fn validate_response_decider(
+  action: &Update,
+  response: &Response,
+) -> ExternResult<ValidateCallbackResult> {
+  let item_action = must_get_action(response.item_original_action_hash.clone())?;
+  let item_author = item_action.action().author();
+
+  let result = check_response_decider(item_author, &action.author, response);
+  if result != ValidateCallbackResult::Valid {
+    return Ok(result);
+  }
+
+  match &response.decided_on_behalf_of {
+    Some(on_behalf_of) if *item_author != action.author => validate_on_behalf_of(
+      on_behalf_of,
+      &action.author,
+      action.timestamp,
+      &action.prev_action,
+    ),
+    _ => Ok(ValidateCallbackResult::Valid),
+  }
+}
+
+/// Checks who decided a Response once the author of its item was fetched. A decision made for
+/// the item's organization is only valid once its coordinator proof is validated as well.
+pub fn check_response_decider(
+  item_author: &AgentPubKey,
+  decider: &AgentPubKey,
+  response: &Response,
+) -> ValidateCallbackResult {
+  if item_author == decider {
+    return ValidateCallbackResult::Valid;
+  }
+
+  match &response.decided_on_behalf_of {
+    Some(on_behalf_of)
+      if on_behalf_of.organization_original_action_hash == response.item_original_action_hash =>
+    {
+      ValidateCallbackResult::Valid
+    }
+    Some(_) => ValidateCallbackResult::Invalid(String::from(
+      "A Response can only be decided on behalf of its item",
+    )),
+    None => ValidateCallbackResult::Invalid(String::from(
+      "Only the author or the coordinators of an item can accept or decline its responses",
+    )),
+  }
+}
+
+pub fn validate_delete_response(
+  action: Delete,
+  original_action: EntryCreationAction,
+  _original_response: Response,
+) -> ExternResult<ValidateCallbackResult> {
+  if action.author != *original_action.author() {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "Only the author of a Response can delete it",
+    )));
+  }
+
+  Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_create_link_response_updates(
+  _action: CreateLink,
+  base_address: AnyLinkableHash,
+  target_address: AnyLinkableHash,
+  _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+  let action_hash = base_address
+    .into_action_hash()
+    .ok_or(UtilsError::ActionHashNotFound("response"))?;
+  let record = must_get_valid_record(action_hash)?;
+  let _response: crate::Response = record
+    .entry()
+    .to_app_option()
+    .map_err(|e| wasm_error!(e))?
+    .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+      "Linked action must reference an entry"
+    ))))?;
+  // Check the entry type for the given action hash
+  let action_hash = target_address
+    .into_action_hash()
+    .ok_or(UtilsError::ActionHashNotFound("response"))?;
+  let record = must_get_valid_record(action_hash)?;
+  let _response: crate::Response = record
+    .entry()
+    .to_app_option()
+    .map_err(|e| wasm_error!(e))?
+    .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+      "Linked action must reference an entry"
+    ))))?;
+  Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_response_updates(
+  _action: DeleteLink,
+  _original_action: CreateLink,
+  _base: AnyLinkableHash,
+  _target: AnyLinkableHash,
+  _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+  Ok(ValidateCallbackResult::Invalid(String::from(
+    "ResponseUpdates links cannot be deleted",
+  )))
+}
@@ -0,0 +1 @@
+mod response_test;
@@ -0,0 +1,153 @@
+#[cfg(test)]
+mod tests {
+  use hdi::prelude::{ActionHash, AgentPubKey, ValidateCallbackResult};
+  use utils::on_behalf_of::OnBehalfOf;
+
+  use crate::{
+    check_response_decider, validate_create_response, validate_response, Response,
+    RESPONSE_LIFECYCLE,
+  };
+
+  fn sample_response() -> Response {
+    Response {
+      item_original_action_hash: ActionHash::from_raw_36(vec![0; 36]),
+      message: "I can help with this".to_string(),
+      status: "pending".to_string(),
+      decided_on_behalf_of: None,
+    }
+  }
+
+  fn on_behalf_of(organization_original_action_hash: ActionHash) -> OnBehalfOf {
+    OnBehalfOf {
+      organization_original_action_hash,
+      coordinator_link: ActionHash::from_raw_36(vec![1; 36]),
+      user_agent_link: ActionHash::from_raw_36(vec![2; 36]),
+    }
+  }
+
+  #[test]
+  fn test_response_is_valid() {
+    let result = validate_create_response(sample_response()).unwrap();
+    assert!(result == ValidateCallbackResult::Valid);
+  }
+
+  #[test]
+  fn test_response_with_empty_message() {
+    let response = Response {
+      message: "".to_string(),
+      ..sample_response()
+    };
+    let result = validate_response(response).unwrap();
+    assert!(
+      result == ValidateCallbackResult::Invalid(String::from("Response message cannot be empty"))
+    );
+  }
+
+  #[test]
+  fn test_response_with_invalid_status() {
+    let response = Response {
+      status: "maybe".to_string(),
+      ..sample_response()
+    };
+    let result = validate_response(response).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "Response status must be 'pending', 'accepted' or 'declined'."
+        ))
+    );
+  }
+
+  #[test]
+  fn test_response_must_be_created_pending() {
+    let response = Response {
+      status: "accepted".to_string(),
+      ..sample_response()
+    };
+    let result = validate_create_response(response).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "An entry cannot be created in the 'accepted' state"
+        ))
+    );
+  }
+
+  #[test]
+  fn test_response_decisions() {
+    let result = RESPONSE_LIFECYCLE
+      .validate_transition("pending", "accepted", false)
+      .unwrap();
+    assert!(result == ValidateCallbackResult::Valid);
+
+    let result = RESPONSE_LIFECYCLE
+      .validate_transition("pending", "declined", true)
+      .unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "The author cannot go from the 'pending' state to the 'declined' state"
+        ))
+    );
+
+    let result = RESPONSE_LIFECYCLE
+      .validate_transition("declined", "accepted", false)
+      .unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "Cannot go from the 'declined' state to the 'accepted' state"
+        ))
+    );
+  }
+
+  #[test]
+  fn test_response_deciders() {
+    let item_author = AgentPubKey::from_raw_36(vec![3; 36]);
+    let stranger = AgentPubKey::from_raw_36(vec![4; 36]);
+
+    let result = check_response_decider(&item_author, &item_author, &sample_response());
+    assert!(result == ValidateCallbackResult::Valid);
+
+    let result = check_response_decider(&item_author, &stranger, &sample_response());
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "Only the author or the coordinators of an item can accept or decline its responses"
+        ))
+    );
+
+    let response = Response {
+      decided_on_behalf_of: Some(on_behalf_of(ActionHash::from_raw_36(vec![5; 36]))),
+      ..sample_response()
+    };
+    let result = check_response_decider(&item_author, &stranger, &response);
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "A Response can only be decided on behalf of its item"
+        ))
+    );
+
+    // The coordinator proof itself is checked by `validate_on_behalf_of`
+    let response = Response {
+      decided_on_behalf_of: Some(on_behalf_of(sample_response().item_original_action_hash)),
+      ..sample_response()
+    };
+    let result = check_response_decider(&item_author, &stranger, &response);
+    assert!(result == ValidateCallbackResult::Valid);
+  }
+
+  #[test]
+  fn test_response_cannot_be_created_as_decided() {
+    let response = Response {
+      decided_on_behalf_of: Some(on_behalf_of(sample_response().item_original_action_hash)),
+      ..sample_response()
+    };
+    let result = validate_create_response(response).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from("A Response cannot be created as decided"))
+    );
+  }
+}
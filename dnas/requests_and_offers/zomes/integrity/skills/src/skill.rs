@@ -0,0 +1,157 @@
+use hdi::prelude::*;
+use utils::errors::UtilsError;
+
+/// Represents a canonical skill Entry shared by every user having it.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct Skill {
+  /// The normalized name of the skill, see [`normalize_skill_name`].
+  pub name: String,
+}
+
+/// Normalizes a skill name so that "Rust", " rust " and "RUST" resolve to the same skill.
+pub fn normalize_skill_name(name: &str) -> String {
+  name
+    .split_whitespace()
+    .collect::<Vec<&str>>()
+    .join(" ")
+    .to_lowercase()
+}
+
+pub fn validate_skill(skill: Skill) -> ExternResult<ValidateCallbackResult> {
+  if skill.name.is_empty() {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "Skill name cannot be empty",
+    )));
+  }
+
+  if skill.name != normalize_skill_name(&skill.name) {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "Skill name must be normalized",
+    )));
+  }
+
+  Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_update_skill(
+  _action: Update,
+  _skill: Skill,
+  _original_action: EntryCreationAction,
+  _original_skill: Skill,
+) -> ExternResult<ValidateCallbackResult> {
+  Ok(ValidateCallbackResult::Invalid(String::from(
+    "A Skill cannot be updated",
+  )))
+}
+
+pub fn validate_delete_skill(
+  _action: Delete,
+  _original_action: EntryCreationAction,
+  _original_skill: Skill,
+) -> ExternResult<ValidateCallbackResult> {
+  Ok(ValidateCallbackResult::Invalid(String::from(
+    "A Skill cannot be deleted",
+  )))
+}
+
+fn must_get_skill(address: AnyLinkableHash) -> ExternResult<Skill> {
+  let action_hash = address
+    .into_action_hash()
+    .ok_or(UtilsError::ActionHashNotFound("skill"))?;
+  let record = must_get_valid_record(action_hash)?;
+  record
+    .entry()
+    .to_app_option()
+    .map_err(|e| wasm_error!(e))?
+    .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+      "Linked action must reference an entry"
+    ))))
+}
+
+pub fn validate_create_link_all_skills(
+  _action: CreateLink,
+  _base_address: AnyLinkableHash,
+  target_address: AnyLinkableHash,
+  tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+  let skill = must_get_skill(target_address)?;
+
+  if tag.into_inner() != skill.name.into_bytes() {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "The tag of an AllSkills link must be the name of the skill",
+    )));
+  }
+
+  Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_all_skills(
+  _action: DeleteLink,
+  _original_action: CreateLink,
+  _base: AnyLinkableHash,
+  _target: AnyLinkableHash,
+  _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+  Ok(ValidateCallbackResult::Invalid(String::from(
+    "AllSkills links cannot be deleted",
+  )))
+}
+
+pub fn validate_create_link_skill_users(
+  _action: CreateLink,
+  base_address: AnyLinkableHash,
+  _target_address: AnyLinkableHash,
+  _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+  must_get_skill(base_address)?;
+  Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_create_link_user_skills(
+  _action: CreateLink,
+  _base_address: AnyLinkableHash,
+  target_address: AnyLinkableHash,
+  _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+  must_get_skill(target_address)?;
+  Ok(ValidateCallbackResult::Valid)
+}
+
+/// Validates the deletion of a `SkillUsers` or `UserSkills` link, which only its author can do.
+pub fn validate_delete_link_user_skill(
+  action: DeleteLink,
+  original_action: CreateLink,
+  _base: AnyLinkableHash,
+  _target: AnyLinkableHash,
+  _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+  if action.author != original_action.author {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "Only the author of a skill link can delete it",
+    )));
+  }
+
+  Ok(ValidateCallbackResult::Valid)
+}
+
+/// Validates a `SkillAliases` link from a canonical skill to one of its aliases, or a
+/// `CanonicalSkill` link back from the alias. The alias table is maintained by network
+/// administrators, which the coordinator zome checks.
+pub fn validate_create_link_skill_alias(
+  _action: CreateLink,
+  base_address: AnyLinkableHash,
+  target_address: AnyLinkableHash,
+  _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+  if base_address == target_address {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "A skill cannot be an alias of itself",
+    )));
+  }
+
+  must_get_skill(base_address)?;
+  must_get_skill(target_address)?;
+
+  Ok(ValidateCallbackResult::Valid)
+}
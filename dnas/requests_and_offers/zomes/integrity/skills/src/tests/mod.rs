@@ -0,0 +1 @@
+mod skill_test;
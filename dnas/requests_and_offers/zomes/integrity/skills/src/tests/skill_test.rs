@@ -0,0 +1,42 @@
+#[cfg(test)]
+mod tests {
+  use hdi::prelude::ValidateCallbackResult;
+
+  use crate::{normalize_skill_name, validate_skill, Skill};
+
+  #[test]
+  fn test_normalize_skill_name() {
+    assert_eq!(normalize_skill_name(" Rust "), "rust");
+    assert_eq!(normalize_skill_name("Web   Design"), "web design");
+    assert_eq!(normalize_skill_name("   "), "");
+  }
+
+  #[test]
+  fn test_skill_is_valid() {
+    let skill = Skill {
+      name: "web design".to_string(),
+    };
+    let result = validate_skill(skill).unwrap();
+    assert!(result == ValidateCallbackResult::Valid);
+  }
+
+  #[test]
+  fn test_skill_with_empty_name() {
+    let skill = Skill {
+      name: "".to_string(),
+    };
+    let result = validate_skill(skill).unwrap();
+    assert!(result == ValidateCallbackResult::Invalid(String::from("Skill name cannot be empty")));
+  }
+
+  #[test]
+  fn test_skill_with_unnormalized_name() {
+    let skill = Skill {
+      name: "Rust ".to_string(),
+    };
+    let result = validate_skill(skill).unwrap();
+    assert!(
+      result == ValidateCallbackResult::Invalid(String::from("Skill name must be normalized"))
+    );
+  }
+}
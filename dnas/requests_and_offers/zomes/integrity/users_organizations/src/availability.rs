@@ -0,0 +1,67 @@
+use hdi::prelude::*;
+
+const MINUTES_PER_DAY: i64 = 24 * 60;
+
+/// A window of time during which a user is available every week.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct AvailabilityWindow {
+  /// The day of the week, from 0 for Monday to 6 for Sunday.
+  pub weekday: u8,
+  /// The start of the window, in minutes after midnight in the user's time zone.
+  pub start_minute: u16,
+  /// The length of the window, in minutes.
+  pub duration_minutes: u16,
+}
+
+/// Represents the weekly availability schedule of a user. Its windows are interpreted in the
+/// `time_zone` of the user's profile.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct Availability {
+  pub windows: Vec<AvailabilityWindow>,
+}
+
+/// A concrete occurrence of an availability window.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct AvailabilitySlot {
+  pub start: Timestamp,
+  pub end: Timestamp,
+}
+
+pub fn validate_availability(availability: Availability) -> ExternResult<ValidateCallbackResult> {
+  for window in availability.windows {
+    if window.weekday > 6 {
+      return Ok(ValidateCallbackResult::Invalid(String::from(
+        "Availability weekday must be between 0 (Monday) and 6 (Sunday)",
+      )));
+    }
+
+    if window.start_minute as i64 >= MINUTES_PER_DAY {
+      return Ok(ValidateCallbackResult::Invalid(String::from(
+        "Availability start must be within the day",
+      )));
+    }
+
+    if window.duration_minutes == 0 || window.duration_minutes as i64 > MINUTES_PER_DAY {
+      return Ok(ValidateCallbackResult::Invalid(String::from(
+        "Availability duration must be between one minute and one day",
+      )));
+    }
+  }
+
+  Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_update_availability(
+  action: Update,
+  original_action_hash: ActionHash,
+) -> ExternResult<ValidateCallbackResult> {
+  let original_action = must_get_action(original_action_hash)?;
+  if *original_action.action().author() != action.author {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "Only the author of an Availability can update it",
+    )));
+  }
+
+  Ok(ValidateCallbackResult::Valid)
+}
@@ -0,0 +1,34 @@
+use hdi::prelude::*;
+
+use crate::{Organization, User};
+
+/// A User profile being filled in over several sessions. It stays private on the author's
+/// source chain until it is published as a `User`.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct UserDraft {
+  pub user: User,
+}
+
+/// An Organization profile being filled in over several sessions. It stays private on the
+/// author's source chain until it is published as an `Organization`.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct OrganizationDraft {
+  pub organization: Organization,
+}
+
+/// Checks that a draft is only revised or discarded by its author.
+pub fn validate_draft_author(
+  author: &AgentPubKey,
+  original_action_hash: ActionHash,
+) -> ExternResult<ValidateCallbackResult> {
+  let original_action = must_get_action(original_action_hash)?;
+  if original_action.action().author() != author {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "Only the author of a draft can modify it",
+    )));
+  }
+
+  Ok(ValidateCallbackResult::Valid)
+}
@@ -0,0 +1,128 @@
+use std::collections::HashSet;
+
+use hdi::prelude::*;
+use utils::countersigning::{app_entry_from_entry, get_user_agent_link_target};
+
+use crate::UnitEntryTypes;
+
+/// Represents the endorsement by a user of a skill listed on another user's profile.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct Endorsement {
+  /// The original action hash of the endorsing user's profile.
+  pub endorser: ActionHash,
+  /// The action hash of the `UserAgents` link from the endorser to the agent authoring the
+  /// endorsement.
+  pub endorser_agent_link: ActionHash,
+  /// The original action hash of the endorsed user's profile.
+  pub endorsed_user: ActionHash,
+  /// The canonical name of the endorsed skill.
+  pub skill: String,
+}
+
+pub fn validate_endorsement(endorsement: Endorsement) -> ExternResult<ValidateCallbackResult> {
+  if endorsement.endorser == endorsement.endorsed_user {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "A user cannot endorse their own skills",
+    )));
+  }
+
+  if endorsement.skill.trim().is_empty() {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "The endorsed skill cannot be empty",
+    )));
+  }
+
+  Ok(ValidateCallbackResult::Valid)
+}
+
+/// Rejects an endorsement of a skill its endorser already endorsed on the same user.
+pub fn validate_endorsement_is_new(
+  endorsement: &Endorsement,
+  previous_endorsements: &[Endorsement],
+) -> ValidateCallbackResult {
+  if previous_endorsements.iter().any(|previous_endorsement| {
+    previous_endorsement.endorsed_user == endorsement.endorsed_user
+      && previous_endorsement.skill == endorsement.skill
+  }) {
+    return ValidateCallbackResult::Invalid(String::from(
+      "This skill was already endorsed on this user",
+    ));
+  }
+
+  ValidateCallbackResult::Valid
+}
+
+/// Walks the chain of an agent back from an action to find the endorsements it made and did
+/// not withdraw.
+pub fn get_endorsements_at(
+  agent: AgentPubKey,
+  action_hash: ActionHash,
+) -> ExternResult<Vec<Endorsement>> {
+  let ScopedEntryDefIndex {
+    zome_index,
+    zome_type,
+  } = UnitEntryTypes::Endorsement.try_into()?;
+
+  let activity = must_get_agent_activity(agent, ChainFilter::new(action_hash))?;
+
+  let withdrawn: HashSet<ActionHash> = activity
+    .iter()
+    .filter_map(|activity| match &activity.action.hashed.content {
+      Action::Delete(delete) => Some(delete.deletes_address.clone()),
+      _ => None,
+    })
+    .collect();
+
+  activity
+    .into_iter()
+    .filter(|activity| !withdrawn.contains(&activity.action.hashed.hash))
+    .filter_map(|activity| match activity.action.hashed.content {
+      Action::Create(create) => match create.entry_type {
+        EntryType::App(app_entry_def)
+          if app_entry_def.zome_index == zome_index && app_entry_def.entry_index == zome_type =>
+        {
+          Some(create.entry_hash)
+        }
+        _ => None,
+      },
+      _ => None,
+    })
+    .map(|entry_hash| {
+      app_entry_from_entry::<Endorsement>(must_get_entry(entry_hash)?.as_content())?.ok_or(
+        wasm_error!(WasmErrorInner::Guest(String::from(
+          "Could not find a previous Endorsement"
+        ))),
+      )
+    })
+    .collect()
+}
+
+/// Checks that the endorsement is authored by an agent of its endorser, and that the author
+/// did not already endorse the same skill on the same user.
+pub fn validate_create_endorsement(
+  action: Create,
+  endorsement: Endorsement,
+) -> ExternResult<ValidateCallbackResult> {
+  let result = validate_endorsement(endorsement.clone())?;
+  if result != ValidateCallbackResult::Valid {
+    return Ok(result);
+  }
+
+  let endorser_agent = get_user_agent_link_target(
+    &endorsement.endorser,
+    endorsement.endorser_agent_link.clone(),
+  )?;
+  if endorser_agent != Some(action.author.clone()) {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "An Endorsement must be authored by an agent of its endorser",
+    )));
+  }
+
+  let previous_endorsements = get_endorsements_at(action.author, action.prev_action)?;
+
+  Ok(validate_endorsement_is_new(
+    &endorsement,
+    &previous_endorsements,
+  ))
+}
@@ -1,8 +1,20 @@
+pub mod availability;
+pub mod draft;
+pub mod endorsement;
 pub mod organization;
+pub mod organization_invitation;
+pub mod organization_join_request;
+pub mod organization_role;
 mod tests;
 pub mod user;
 
+pub use availability::*;
+pub use draft::*;
+pub use endorsement::*;
 pub use organization::*;
+pub use organization_invitation::*;
+pub use organization_join_request::*;
+pub use organization_role::*;
 pub use user::*;
 
 use hdi::prelude::*;
@@ -14,25 +26,22 @@ use hdi::prelude::*;
 pub enum EntryTypes {
   User(User),
   Organization(Organization),
+  #[entry_type(visibility = "private")]
+  UserDraft(UserDraft),
+  #[entry_type(visibility = "private")]
+  OrganizationDraft(OrganizationDraft),
+  Availability(Availability),
+  Endorsement(Endorsement),
+  OrganizationInvitation(OrganizationInvitation),
+  OrganizationJoinRequest(OrganizationJoinRequest),
+  OrganizationRole(OrganizationRole),
 }
-#[derive(Serialize, Deserialize)]
-#[hdk_link_types]
-pub enum LinkTypes {
-  // Users Links
-  UserUpdates,
-  AllUsers,
-  MyUser,
-  UserAgents,
-  UserStatus,
-  UserOrganizations,
-
-  // Organizations Links
-  OrganizationUpdates,
-  AllOrganizations,
-  OrganizationCoordinators,
-  OrganizationMembers,
-  OrganizationStatus,
-}
+// Declared in `utils` so that the other zomes resolve these link types from the same definition
+utils::users_organizations_link_types!(
+  #[derive(Serialize, Deserialize)]
+  #[hdk_link_types]
+  pub enum LinkTypes
+);
 
 #[hdk_extern]
 pub fn genesis_self_check(_data: GenesisSelfCheckData) -> ExternResult<ValidateCallbackResult> {
@@ -60,19 +69,194 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
           EntryTypes::Organization(organization) => {
             return validate_organization(organization);
           }
+          EntryTypes::Availability(availability) => {
+            return validate_availability(availability);
+          }
+          EntryTypes::Endorsement(endorsement) => {
+            return validate_endorsement(endorsement);
+          }
+          EntryTypes::OrganizationInvitation(invitation) => {
+            return validate_organization_invitation(invitation);
+          }
+          EntryTypes::OrganizationJoinRequest(join_request) => {
+            return validate_organization_join_request(join_request);
+          }
+          EntryTypes::OrganizationRole(role) => {
+            return validate_organization_role(role);
+          }
+          // Drafts may be incomplete, they are only validated once published
+          EntryTypes::UserDraft(_) | EntryTypes::OrganizationDraft(_) => {
+            return Ok(ValidateCallbackResult::Valid);
+          }
         }
       }
 
       _ => (),
     }
   }
+  if let FlatOp::RegisterCreateLink {
+    link_type,
+    base_address,
+    target_address,
+    tag,
+    action,
+  } = op.flattened::<EntryTypes, LinkTypes>()?
+  {
+    match link_type {
+      LinkTypes::UserAgents => {
+        return validate_create_link_user_agents(action, base_address, target_address, tag);
+      }
+      LinkTypes::OrganizationCoordinators => {
+        return validate_create_link_organization_coordinators(
+          action,
+          base_address,
+          target_address,
+          tag,
+        );
+      }
+      LinkTypes::RoleUsers => {
+        return validate_create_link_role_users(action, base_address, target_address, tag);
+      }
+      _ => (),
+    }
+  }
+  if let FlatOp::RegisterDeleteLink {
+    link_type,
+    base_address,
+    target_address,
+    tag,
+    original_action,
+    action,
+  } = op.flattened::<EntryTypes, LinkTypes>()?
+  {
+    match link_type {
+      LinkTypes::UserAgents => {
+        return validate_delete_link_user_agents(
+          action,
+          original_action,
+          base_address,
+          target_address,
+          tag,
+        );
+      }
+      LinkTypes::OrganizationCoordinators => {
+        return validate_delete_link_organization_coordinators(
+          action,
+          original_action,
+          base_address,
+          target_address,
+          tag,
+        );
+      }
+      _ => (),
+    }
+  }
   if let FlatOp::StoreRecord(store_record) = op.flattened::<EntryTypes, LinkTypes>()? {
     match store_record {
+      OpRecord::CreateEntry {
+        action,
+        app_entry: EntryTypes::Endorsement(endorsement),
+      } => {
+        return validate_create_endorsement(action, endorsement);
+      }
+      OpRecord::CreateEntry {
+        action,
+        app_entry: EntryTypes::OrganizationInvitation(invitation),
+      } => {
+        let result = validate_create_organization_invitation(invitation.clone())?;
+        if result != ValidateCallbackResult::Valid {
+          return Ok(result);
+        }
+
+        return validate_organization_invitation_inviter(&action, &invitation);
+      }
+      OpRecord::UpdateEntry {
+        original_action_hash,
+        action,
+        app_entry: EntryTypes::OrganizationInvitation(invitation),
+        ..
+      } => {
+        return validate_update_organization_invitation(action, invitation, original_action_hash);
+      }
+      OpRecord::CreateEntry {
+        action,
+        app_entry: EntryTypes::OrganizationJoinRequest(join_request),
+      } => {
+        let result = validate_create_organization_join_request(join_request.clone())?;
+        if result != ValidateCallbackResult::Valid {
+          return Ok(result);
+        }
+
+        return validate_organization_join_request_requester(&action, &join_request);
+      }
+      OpRecord::UpdateEntry {
+        original_action_hash,
+        action,
+        app_entry: EntryTypes::OrganizationJoinRequest(join_request),
+        ..
+      } => {
+        return validate_update_organization_join_request(
+          action,
+          join_request,
+          original_action_hash,
+        );
+      }
+      OpRecord::UpdateEntry {
+        action,
+        app_entry: EntryTypes::Organization(_),
+        ..
+      } => {
+        return validate_update_organization(action);
+      }
+      OpRecord::CreateEntry {
+        action,
+        app_entry: EntryTypes::OrganizationRole(role),
+      } => {
+        return validate_create_organization_role(action, role);
+      }
+      OpRecord::UpdateEntry {
+        original_action_hash,
+        action,
+        app_entry: EntryTypes::OrganizationRole(role),
+        ..
+      } => {
+        return validate_update_organization_role(action, role, original_action_hash);
+      }
+      OpRecord::UpdateEntry {
+        app_entry: EntryTypes::Endorsement(_),
+        ..
+      } => {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+          "An Endorsement cannot be updated",
+        )));
+      }
+      OpRecord::UpdateEntry {
+        original_action_hash,
+        action,
+        app_entry: EntryTypes::UserDraft(_) | EntryTypes::OrganizationDraft(_),
+        ..
+      }
+      | OpRecord::UpdatePrivateEntry {
+        original_action_hash,
+        action,
+        ..
+      } => {
+        return validate_draft_author(&action.author, original_action_hash);
+      }
+      OpRecord::UpdateEntry {
+        original_action_hash,
+        action,
+        app_entry: EntryTypes::Availability(_),
+        ..
+      } => {
+        return validate_update_availability(action, original_action_hash);
+      }
       OpRecord::DeleteEntry {
         original_action_hash,
+        action,
         ..
       } => {
-        let original_record = must_get_valid_record(original_action_hash)?;
+        let original_record = must_get_valid_record(original_action_hash.clone())?;
         let original_action = original_record.action().clone();
         let original_action = match original_action {
           Action::Create(create) => EntryCreationAction::Create(create),
@@ -97,7 +281,7 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                 "Original record for a delete of a public entry must contain an entry".to_string(),
               ));
             } else {
-              return Ok(ValidateCallbackResult::Valid);
+              return validate_draft_author(&action.author, original_action_hash);
             }
           }
         };
@@ -120,7 +304,39 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
             )))
           }
           EntryTypes::Organization(_original_organization) => {
-            return Ok(ValidateCallbackResult::Valid)
+            return validate_delete_organization(action);
+          }
+          EntryTypes::UserDraft(_) | EntryTypes::OrganizationDraft(_) => {
+            return validate_draft_author(&action.author, original_action_hash);
+          }
+          EntryTypes::Availability(_original_availability) => {
+            if action.author != *original_action.author() {
+              return Ok(ValidateCallbackResult::Invalid(String::from(
+                "Only the author of an Availability can delete it",
+              )));
+            }
+            return Ok(ValidateCallbackResult::Valid);
+          }
+          EntryTypes::OrganizationInvitation(_original_invitation) => {
+            return Ok(ValidateCallbackResult::Invalid(String::from(
+              "An OrganizationInvitation cannot be deleted",
+            )));
+          }
+          EntryTypes::OrganizationJoinRequest(_original_join_request) => {
+            return Ok(ValidateCallbackResult::Invalid(String::from(
+              "An OrganizationJoinRequest cannot be deleted",
+            )));
+          }
+          EntryTypes::OrganizationRole(original_role) => {
+            return validate_delete_organization_role(action, original_role);
+          }
+          EntryTypes::Endorsement(_original_endorsement) => {
+            if action.author != *original_action.author() {
+              return Ok(ValidateCallbackResult::Invalid(String::from(
+                "Only the endorser can withdraw an Endorsement",
+              )));
+            }
+            return Ok(ValidateCallbackResult::Valid);
           }
         }
       }
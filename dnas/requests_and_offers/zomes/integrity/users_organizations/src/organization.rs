@@ -1,6 +1,12 @@
 use email_address::EmailAddress;
 use hdi::prelude::*;
-use utils::{errors::UtilsError, is_image};
+use utils::{
+  errors::UtilsError,
+  is_image,
+  on_behalf_of::{validate_on_behalf_of, OnBehalfOf},
+};
+
+use crate::{InvitationRole, InvitationStatus, LinkTypes, OrganizationInvitation};
 
 #[hdk_entry_helper]
 #[derive(Clone, PartialEq)]
@@ -11,6 +17,9 @@ pub struct Organization {
   pub email: String,
   pub urls: Vec<String>,
   pub location: String,
+  /// The original action hashes of the categories of the organization.
+  #[serde(default)]
+  pub categories: Vec<ActionHash>,
 }
 
 pub fn validate_organization(organization: Organization) -> ExternResult<ValidateCallbackResult> {
@@ -31,20 +40,238 @@ pub fn validate_organization(organization: Organization) -> ExternResult<Validat
   Ok(ValidateCallbackResult::Valid)
 }
 
-pub fn validate_update_organization(
-  _action: Update,
-  _organization: Organization,
-  _original_action: EntryCreationAction,
-  _original_organization: Organization,
+/// Checks that an update of an organization is authored by one of its coordinators.
+pub fn validate_update_organization(action: Update) -> ExternResult<ValidateCallbackResult> {
+  validate_organization_coordinator_proof(
+    &action.author,
+    action.timestamp,
+    action.prev_action,
+    action.original_action_address,
+  )
+}
+
+/// Checks that a delete of an organization is authored by one of its coordinators.
+pub fn validate_delete_organization(action: Delete) -> ExternResult<ValidateCallbackResult> {
+  validate_organization_coordinator_proof(
+    &action.author,
+    action.timestamp,
+    action.prev_action,
+    action.deletes_address,
+  )
+}
+
+/// Follows the updates of an entry back to the action that created it.
+pub(crate) fn get_original_action_hash(action_hash: ActionHash) -> ExternResult<ActionHash> {
+  let mut action_hash = action_hash;
+  while let Action::Update(update) = must_get_action(action_hash.clone())?.action() {
+    action_hash = update.original_action_address.clone();
+  }
+
+  Ok(action_hash)
+}
+
+/// Validates the `OrganizationCoordinatorProofs` link committed right before an update or delete
+/// of an organization, which proves that its author is linked to the organization through
+/// `OrganizationCoordinators`.
+fn validate_organization_coordinator_proof(
+  author: &AgentPubKey,
+  timestamp: Timestamp,
+  prev_action: ActionHash,
+  organization_action_hash: ActionHash,
 ) -> ExternResult<ValidateCallbackResult> {
-  Ok(ValidateCallbackResult::Valid)
+  let organization_original_action_hash = get_original_action_hash(organization_action_hash)?;
+
+  let Action::CreateLink(proof_link) = must_get_action(prev_action.clone())?.action().clone()
+  else {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "An Organization can only be changed right after proving that its author is a coordinator",
+    )));
+  };
+
+  if !matches!(
+    LinkTypes::from_type(proof_link.zome_index, proof_link.link_type)?,
+    Some(LinkTypes::OrganizationCoordinatorProofs)
+  ) || proof_link.base_address
+    != AnyLinkableHash::from(organization_original_action_hash.clone())
+  {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "An Organization can only be changed right after proving that its author is a coordinator",
+    )));
+  }
+
+  let on_behalf_of: OnBehalfOf =
+    decode(&proof_link.tag.into_inner()).map_err(|e| wasm_error!(WasmErrorInner::Serialize(e)))?;
+  if on_behalf_of.organization_original_action_hash != organization_original_action_hash {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "The coordinator proof must be about the changed Organization",
+    )));
+  }
+
+  validate_on_behalf_of(&on_behalf_of, author, timestamp, &prev_action)
+}
+
+/// What entitles an agent to link their user as a coordinator of an organization, carried in the
+/// tag of the `OrganizationCoordinators` link.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum CoordinatorGrant {
+  /// The agent created the organization.
+  Creation,
+  /// The agent accepted an invitation to become a coordinator, sent by a coordinator of the
+  /// organization. Holds the action hash of the acceptance.
+  Invitation(ActionHash),
+}
+
+/// Checks that an `OrganizationCoordinators` link is created by the agent of the linked user,
+/// either as the creator of the organization or after accepting a coordinator invitation.
+///
+/// Each grant links the user once: a coordinator who left needs a new invitation.
+pub fn validate_create_link_organization_coordinators(
+  action: CreateLink,
+  base_address: AnyLinkableHash,
+  target_address: AnyLinkableHash,
+  tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+  let organization_original_action_hash = base_address
+    .into_action_hash()
+    .ok_or(UtilsError::ActionHashNotFound("organization"))?;
+  let organization_record = must_get_valid_record(organization_original_action_hash.clone())?;
+  let _organization: crate::Organization = organization_record
+    .entry()
+    .to_app_option()
+    .map_err(|e| wasm_error!(e))?
+    .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+      "Linked action must reference an entry"
+    ))))?;
+
+  let user_original_action_hash = target_address
+    .into_action_hash()
+    .ok_or(UtilsError::ActionHashNotFound("user"))?;
+  let user_record = must_get_valid_record(user_original_action_hash.clone())?;
+  if *user_record.action().author() != action.author {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "Agents can only link their own user as a coordinator",
+    )));
+  }
+
+  let Ok(grant) = decode::<_, CoordinatorGrant>(&tag.into_inner()) else {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "An OrganizationCoordinators link must carry the grant of the coordinator",
+    )));
+  };
+
+  let granting_action_hash = match grant {
+    CoordinatorGrant::Creation => {
+      if *organization_record.action().author() != action.author
+        || !matches!(organization_record.action(), Action::Create(_))
+      {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+          "Only the creator of an organization can link themselves without an invitation",
+        )));
+      }
+      organization_original_action_hash.clone()
+    }
+    CoordinatorGrant::Invitation(acceptance_action_hash) => {
+      let acceptance_record = must_get_valid_record(acceptance_action_hash.clone())?;
+      if !matches!(acceptance_record.action(), Action::Update(_))
+        || *acceptance_record.action().author() != action.author
+      {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+          "The coordinator invitation must be accepted by the linking agent",
+        )));
+      }
+      let Ok(Some(invitation)) = acceptance_record
+        .entry()
+        .to_app_option::<OrganizationInvitation>()
+      else {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+          "The coordinator grant must cite the acceptance of an OrganizationInvitation",
+        )));
+      };
+
+      let result = check_coordinator_invitation_grant(
+        &invitation,
+        &organization_original_action_hash,
+        &user_original_action_hash,
+      );
+      if result != ValidateCallbackResult::Valid {
+        return Ok(result);
+      }
+      acceptance_action_hash
+    }
+  };
+
+  let author_activity = must_get_agent_activity(
+    action.author.clone(),
+    ChainFilter::new(action.prev_action.clone()).until(granting_action_hash),
+  )?;
+
+  Ok(check_coordinator_grant_is_unused(&action, &author_activity))
 }
 
-pub fn validate_delete_organization(
-  _action: Delete,
-  _original_action: EntryCreationAction,
-  _original_organization: Organization,
+/// Checks that an accepted invitation makes the given user a coordinator of the organization.
+pub fn check_coordinator_invitation_grant(
+  invitation: &OrganizationInvitation,
+  organization_original_action_hash: &ActionHash,
+  user_original_action_hash: &ActionHash,
+) -> ValidateCallbackResult {
+  if invitation.organization != *organization_original_action_hash
+    || invitation.invitee != *user_original_action_hash
+  {
+    return ValidateCallbackResult::Invalid(String::from(
+      "The coordinator invitation must be sent to the linked user for the organization",
+    ));
+  }
+
+  if invitation.role != InvitationRole::Coordinator.to_string()
+    || invitation.status != InvitationStatus::Accepted.to_string()
+  {
+    return ValidateCallbackResult::Invalid(String::from(
+      "The coordinator grant must cite an accepted coordinator invitation",
+    ));
+  }
+
+  ValidateCallbackResult::Valid
+}
+
+/// Checks that the author did not already link themselves to the organization since the grant,
+/// given their activity between the grant and the new link.
+pub fn check_coordinator_grant_is_unused(
+  coordinator_link: &CreateLink,
+  author_activity: &[RegisterAgentActivity],
+) -> ValidateCallbackResult {
+  let is_used = author_activity.iter().any(|activity| {
+    matches!(
+      &activity.action.hashed.content,
+      Action::CreateLink(create_link)
+        if create_link.zome_index == coordinator_link.zome_index
+          && create_link.link_type == coordinator_link.link_type
+          && create_link.base_address == coordinator_link.base_address
+    )
+  });
+  if is_used {
+    return ValidateCallbackResult::Invalid(String::from(
+      "The coordinator grant was already used to link the author to the organization",
+    ));
+  }
+
+  ValidateCallbackResult::Valid
+}
+
+/// Checks that a coordinator link is deleted by its author, so that coordinators only ever leave
+/// by themselves and the deletes of their links stay on their own chain.
+pub fn validate_delete_link_organization_coordinators(
+  action: DeleteLink,
+  original_action: CreateLink,
+  _base: AnyLinkableHash,
+  _target: AnyLinkableHash,
+  _tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
+  if action.author != original_action.author {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "Coordinators can only remove themselves from an organization",
+    )));
+  }
+
   Ok(ValidateCallbackResult::Valid)
 }
 
@@ -0,0 +1,291 @@
+use std::{fmt::Display, str::FromStr};
+
+use hdi::prelude::*;
+use utils::{
+  countersigning::get_user_agent_link_target,
+  lifecycle::{Lifecycle, Transition, TransitionActor},
+};
+
+use crate::{validate_organization_authority, OrganizationAuthority, OrganizationPermission};
+
+/// Represents the invitation of a user to join an organization, sent by a member allowed to
+/// manage its members, or to become one of its coordinators, sent by one of its coordinators.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct OrganizationInvitation {
+  /// The original action hash of the organization.
+  pub organization: ActionHash,
+  /// The original action hash of the inviter's User profile.
+  pub inviter: ActionHash,
+  /// The original action hash of the invited user's User profile.
+  pub invitee: ActionHash,
+  /// The role the user is invited to, either 'member' or 'coordinator'.
+  pub role: String,
+  /// Either 'pending', 'accepted', 'declined' or 'cancelled'.
+  pub status: String,
+  /// The proof that the inviter could send the invitation: the 'manage_members' permission for
+  /// members, being a coordinator for coordinators.
+  pub authority: OrganizationAuthority,
+  /// The action hash of the `UserAgents` link from the invitee to the agent who accepted or
+  /// declined the invitation.
+  pub invitee_agent_link: Option<ActionHash>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InvitationRole {
+  Member,
+  Coordinator,
+}
+
+impl Display for InvitationRole {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Member => write!(f, "member"),
+      Self::Coordinator => write!(f, "coordinator"),
+    }
+  }
+}
+
+impl FromStr for InvitationRole {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "member" => Ok(Self::Member),
+      "coordinator" => Ok(Self::Coordinator),
+      _ => Err(format!("Invalid invitation role: {}", s)),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InvitationStatus {
+  Pending,
+  Accepted,
+  Declined,
+  Cancelled,
+}
+
+impl Display for InvitationStatus {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Pending => write!(f, "pending"),
+      Self::Accepted => write!(f, "accepted"),
+      Self::Declined => write!(f, "declined"),
+      Self::Cancelled => write!(f, "cancelled"),
+    }
+  }
+}
+
+impl FromStr for InvitationStatus {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "pending" => Ok(Self::Pending),
+      "accepted" => Ok(Self::Accepted),
+      "declined" => Ok(Self::Declined),
+      "cancelled" => Ok(Self::Cancelled),
+      _ => Err(format!("Invalid invitation status: {}", s)),
+    }
+  }
+}
+
+/// An invitation stays open while pending. The invitee accepts or declines it, while the
+/// agent who sent it can cancel it. Which agents act for the invitee is checked by
+/// `validate_organization_invitation_decider`.
+pub const INVITATION_STATUS_LIFECYCLE: Lifecycle<InvitationStatus> = Lifecycle {
+  initial_states: &[InvitationStatus::Pending],
+  transitions: &[
+    Transition {
+      from: InvitationStatus::Pending,
+      to: InvitationStatus::Accepted,
+      actor: TransitionActor::Other,
+    },
+    Transition {
+      from: InvitationStatus::Pending,
+      to: InvitationStatus::Declined,
+      actor: TransitionActor::Other,
+    },
+    Transition {
+      from: InvitationStatus::Pending,
+      to: InvitationStatus::Cancelled,
+      actor: TransitionActor::Author,
+    },
+  ],
+};
+
+pub fn validate_organization_invitation(
+  invitation: OrganizationInvitation,
+) -> ExternResult<ValidateCallbackResult> {
+  if invitation.inviter == invitation.invitee {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "A user cannot invite themselves to an organization",
+    )));
+  }
+
+  if InvitationRole::from_str(&invitation.role).is_err() {
+    return Ok(ValidateCallbackResult::Invalid(format!(
+      "Invitation role must be '{}' or '{}'.",
+      InvitationRole::Member,
+      InvitationRole::Coordinator,
+    )));
+  }
+
+  if InvitationStatus::from_str(&invitation.status).is_err() {
+    return Ok(ValidateCallbackResult::Invalid(format!(
+      "Invitation status must be '{}', '{}', '{}' or '{}'.",
+      InvitationStatus::Pending,
+      InvitationStatus::Accepted,
+      InvitationStatus::Declined,
+      InvitationStatus::Cancelled,
+    )));
+  }
+
+  Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_create_organization_invitation(
+  invitation: OrganizationInvitation,
+) -> ExternResult<ValidateCallbackResult> {
+  match &invitation.authority {
+    OrganizationAuthority::Coordinator(on_behalf_of) => {
+      if on_behalf_of.organization_original_action_hash != invitation.organization {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+          "An OrganizationInvitation must be sent on behalf of its organization",
+        )));
+      }
+    }
+    OrganizationAuthority::Role(_) => {
+      if invitation.role == InvitationRole::Coordinator.to_string() {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+          "Only coordinators can invite coordinators to an organization",
+        )));
+      }
+    }
+  }
+
+  if invitation.invitee_agent_link.is_some() {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "An OrganizationInvitation cannot be created as decided",
+    )));
+  }
+
+  INVITATION_STATUS_LIFECYCLE.validate_initial_state(&invitation.status)
+}
+
+/// Checks that an invitation is sent by an agent of its inviter, who must hold the
+/// 'manage_members' permission in the organization. Coordinator invitations are restricted to
+/// coordinators by `validate_create_organization_invitation`.
+pub fn validate_organization_invitation_inviter(
+  action: &Create,
+  invitation: &OrganizationInvitation,
+) -> ExternResult<ValidateCallbackResult> {
+  let inviter_agent = get_user_agent_link_target(
+    &invitation.inviter,
+    invitation.authority.user_agent_link().clone(),
+  )?;
+  if inviter_agent.as_ref() != Some(&action.author) {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "An OrganizationInvitation must be authored by an agent of its inviter",
+    )));
+  }
+
+  validate_organization_authority(
+    &invitation.authority,
+    &invitation.organization,
+    OrganizationPermission::ManageMembers,
+    &action.author,
+    action.timestamp,
+    &action.prev_action,
+  )
+}
+
+/// Checks that only the status of an invitation changes, following its lifecycle.
+pub fn validate_update_organization_invitation(
+  action: Update,
+  invitation: OrganizationInvitation,
+  original_action_hash: ActionHash,
+) -> ExternResult<ValidateCallbackResult> {
+  let original_record = must_get_valid_record(original_action_hash)?;
+  let original_invitation: OrganizationInvitation = original_record
+    .entry()
+    .to_app_option()
+    .map_err(|e| wasm_error!(e))?
+    .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+      "Could not find the original OrganizationInvitation"
+    ))))?;
+
+  let result = validate_organization_invitation_transition(
+    &original_invitation,
+    &invitation,
+    action.author == *original_record.action().author(),
+  )?;
+  if result != ValidateCallbackResult::Valid {
+    return Ok(result);
+  }
+
+  let invitee_agent = match &invitation.invitee_agent_link {
+    Some(invitee_agent_link) => {
+      get_user_agent_link_target(&invitation.invitee, invitee_agent_link.clone())?
+    }
+    None => None,
+  };
+
+  Ok(validate_organization_invitation_decider(
+    &invitation,
+    invitee_agent.as_ref(),
+    &action.author,
+  ))
+}
+
+/// Checks that an invitation is accepted or declined by an agent of the invitee, given the
+/// agent targeted by the cited `UserAgents` link of the invitee.
+pub fn validate_organization_invitation_decider(
+  invitation: &OrganizationInvitation,
+  invitee_agent: Option<&AgentPubKey>,
+  author: &AgentPubKey,
+) -> ValidateCallbackResult {
+  let is_decision = invitation.status == InvitationStatus::Accepted.to_string()
+    || invitation.status == InvitationStatus::Declined.to_string();
+
+  if !is_decision {
+    if invitation.invitee_agent_link.is_some() {
+      return ValidateCallbackResult::Invalid(String::from(
+        "Only a decided OrganizationInvitation cites an agent of the invitee",
+      ));
+    }
+    return ValidateCallbackResult::Valid;
+  }
+
+  if invitee_agent != Some(author) {
+    return ValidateCallbackResult::Invalid(String::from(
+      "Only an agent of the invited user can accept or decline an invitation",
+    ));
+  }
+
+  ValidateCallbackResult::Valid
+}
+
+pub fn validate_organization_invitation_transition(
+  original_invitation: &OrganizationInvitation,
+  invitation: &OrganizationInvitation,
+  is_author: bool,
+) -> ExternResult<ValidateCallbackResult> {
+  if invitation.organization != original_invitation.organization
+    || invitation.inviter != original_invitation.inviter
+    || invitation.invitee != original_invitation.invitee
+    || invitation.role != original_invitation.role
+    || invitation.authority != original_invitation.authority
+  {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "The organization, users and role of an OrganizationInvitation cannot be changed",
+    )));
+  }
+
+  INVITATION_STATUS_LIFECYCLE.validate_transition(
+    &original_invitation.status,
+    &invitation.status,
+    is_author,
+  )
+}
@@ -0,0 +1,211 @@
+use std::{fmt::Display, str::FromStr};
+
+use hdi::prelude::*;
+use utils::{
+  countersigning::get_user_agent_link_target,
+  lifecycle::{Lifecycle, Transition, TransitionActor},
+};
+
+use crate::{validate_organization_authority, OrganizationAuthority, OrganizationPermission};
+
+/// Represents the request of a user to join an organization, decided by an agent allowed to
+/// manage its members.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct OrganizationJoinRequest {
+  /// The original action hash of the organization.
+  pub organization: ActionHash,
+  /// The original action hash of the requesting user's User profile.
+  pub requester: ActionHash,
+  /// A short presentation of the requester to the coordinators.
+  pub message: String,
+  /// Either 'pending', 'approved' or 'rejected'.
+  pub status: String,
+  /// Why the request was rejected, set when it is rejected.
+  pub rejection_reason: Option<String>,
+  /// The action hash of the `UserAgents` link from the requester to the agent who sent the
+  /// request.
+  pub requester_agent_link: ActionHash,
+  /// The proof that the agent who approved or rejected the request held the 'manage_members'
+  /// permission in the organization.
+  pub decision_authority: Option<OrganizationAuthority>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum JoinRequestStatus {
+  Pending,
+  Approved,
+  Rejected,
+}
+
+impl Display for JoinRequestStatus {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Pending => write!(f, "pending"),
+      Self::Approved => write!(f, "approved"),
+      Self::Rejected => write!(f, "rejected"),
+    }
+  }
+}
+
+impl FromStr for JoinRequestStatus {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "pending" => Ok(Self::Pending),
+      "approved" => Ok(Self::Approved),
+      "rejected" => Ok(Self::Rejected),
+      _ => Err(format!("Invalid join request status: {}", s)),
+    }
+  }
+}
+
+/// A join request is decided once, by another agent than the requester. Which agents can decide
+/// is checked by `validate_organization_join_request_decision`.
+pub const JOIN_REQUEST_STATUS_LIFECYCLE: Lifecycle<JoinRequestStatus> = Lifecycle {
+  initial_states: &[JoinRequestStatus::Pending],
+  transitions: &[
+    Transition {
+      from: JoinRequestStatus::Pending,
+      to: JoinRequestStatus::Approved,
+      actor: TransitionActor::Other,
+    },
+    Transition {
+      from: JoinRequestStatus::Pending,
+      to: JoinRequestStatus::Rejected,
+      actor: TransitionActor::Other,
+    },
+  ],
+};
+
+pub fn validate_organization_join_request(
+  join_request: OrganizationJoinRequest,
+) -> ExternResult<ValidateCallbackResult> {
+  let Ok(status) = JoinRequestStatus::from_str(&join_request.status) else {
+    return Ok(ValidateCallbackResult::Invalid(format!(
+      "Join request status must be '{}', '{}' or '{}'.",
+      JoinRequestStatus::Pending,
+      JoinRequestStatus::Approved,
+      JoinRequestStatus::Rejected,
+    )));
+  };
+
+  let has_reason = join_request
+    .rejection_reason
+    .is_some_and(|reason| !reason.trim().is_empty());
+  if status == JoinRequestStatus::Rejected && !has_reason {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "A rejected join request must have a rejection reason",
+    )));
+  }
+  if status != JoinRequestStatus::Rejected && has_reason {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "Only a rejected join request can have a rejection reason",
+    )));
+  }
+
+  Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_create_organization_join_request(
+  join_request: OrganizationJoinRequest,
+) -> ExternResult<ValidateCallbackResult> {
+  if join_request.decision_authority.is_some() {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "An OrganizationJoinRequest cannot be created as decided",
+    )));
+  }
+
+  JOIN_REQUEST_STATUS_LIFECYCLE.validate_initial_state(&join_request.status)
+}
+
+/// Checks that a join request is sent by an agent of its requester.
+pub fn validate_organization_join_request_requester(
+  action: &Create,
+  join_request: &OrganizationJoinRequest,
+) -> ExternResult<ValidateCallbackResult> {
+  let requester_agent = get_user_agent_link_target(
+    &join_request.requester,
+    join_request.requester_agent_link.clone(),
+  )?;
+  if requester_agent.as_ref() != Some(&action.author) {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "An OrganizationJoinRequest must be authored by an agent of its requester",
+    )));
+  }
+
+  Ok(ValidateCallbackResult::Valid)
+}
+
+/// Checks that only the status and rejection reason of a join request change, following its
+/// lifecycle.
+pub fn validate_update_organization_join_request(
+  action: Update,
+  join_request: OrganizationJoinRequest,
+  original_action_hash: ActionHash,
+) -> ExternResult<ValidateCallbackResult> {
+  let original_record = must_get_valid_record(original_action_hash)?;
+  let original_join_request: OrganizationJoinRequest = original_record
+    .entry()
+    .to_app_option()
+    .map_err(|e| wasm_error!(e))?
+    .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+      "Could not find the original OrganizationJoinRequest"
+    ))))?;
+
+  let result = validate_organization_join_request_transition(
+    &original_join_request,
+    &join_request,
+    action.author == *original_record.action().author(),
+  )?;
+  if result != ValidateCallbackResult::Valid {
+    return Ok(result);
+  }
+
+  validate_organization_join_request_decision(&action, &join_request)
+}
+
+/// Checks that a join request is approved or rejected by an agent holding the 'manage_members'
+/// permission in the organization.
+pub fn validate_organization_join_request_decision(
+  action: &Update,
+  join_request: &OrganizationJoinRequest,
+) -> ExternResult<ValidateCallbackResult> {
+  let Some(decision_authority) = &join_request.decision_authority else {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "A decision on an OrganizationJoinRequest must prove the 'manage_members' permission",
+    )));
+  };
+
+  validate_organization_authority(
+    decision_authority,
+    &join_request.organization,
+    OrganizationPermission::ManageMembers,
+    &action.author,
+    action.timestamp,
+    &action.prev_action,
+  )
+}
+
+pub fn validate_organization_join_request_transition(
+  original_join_request: &OrganizationJoinRequest,
+  join_request: &OrganizationJoinRequest,
+  is_author: bool,
+) -> ExternResult<ValidateCallbackResult> {
+  if join_request.organization != original_join_request.organization
+    || join_request.requester != original_join_request.requester
+    || join_request.message != original_join_request.message
+    || join_request.requester_agent_link != original_join_request.requester_agent_link
+  {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "The organization, requester and message of an OrganizationJoinRequest cannot be changed",
+    )));
+  }
+
+  JOIN_REQUEST_STATUS_LIFECYCLE.validate_transition(
+    &original_join_request.status,
+    &join_request.status,
+    is_author,
+  )
+}
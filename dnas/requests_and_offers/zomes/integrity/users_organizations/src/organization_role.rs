@@ -0,0 +1,383 @@
+use std::{fmt::Display, str::FromStr};
+
+use hdi::prelude::*;
+use utils::{
+  countersigning::get_user_agent_link_target,
+  on_behalf_of::{validate_on_behalf_of, OnBehalfOf},
+};
+
+use crate::{get_original_action_hash, LinkTypes};
+
+/// Represents a custom role of an organization, such as 'treasurer' or 'reviewer', granting
+/// a set of permissions to the members it is assigned to.
+#[hdk_entry_helper]
+#[derive(Clone, PartialEq)]
+pub struct OrganizationRole {
+  /// The original action hash of the organization.
+  pub organization: ActionHash,
+  /// The name of the role, unique within the organization.
+  pub name: String,
+  /// The permissions granted by the role.
+  pub permissions: Vec<String>,
+}
+
+/// The permissions a role can grant. Coordinators hold every permission.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OrganizationPermission {
+  ManageMembers,
+  ManageRoles,
+  ManageFinances,
+  ReviewListings,
+  RepresentOrganization,
+}
+
+impl OrganizationPermission {
+  pub const ALL: [Self; 5] = [
+    Self::ManageMembers,
+    Self::ManageRoles,
+    Self::ManageFinances,
+    Self::ReviewListings,
+    Self::RepresentOrganization,
+  ];
+}
+
+impl Display for OrganizationPermission {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::ManageMembers => write!(f, "manage_members"),
+      Self::ManageRoles => write!(f, "manage_roles"),
+      Self::ManageFinances => write!(f, "manage_finances"),
+      Self::ReviewListings => write!(f, "review_listings"),
+      Self::RepresentOrganization => write!(f, "represent_organization"),
+    }
+  }
+}
+
+impl FromStr for OrganizationPermission {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Self::ALL
+      .into_iter()
+      .find(|permission| permission.to_string() == s)
+      .ok_or(format!("Invalid organization permission: {}", s))
+  }
+}
+
+pub fn validate_organization_role(role: OrganizationRole) -> ExternResult<ValidateCallbackResult> {
+  if role.name.trim().is_empty() {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "Organization role name cannot be empty",
+    )));
+  }
+
+  if role.permissions.is_empty() {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "An organization role must grant at least one permission",
+    )));
+  }
+
+  for (index, permission) in role.permissions.iter().enumerate() {
+    if OrganizationPermission::from_str(permission).is_err() {
+      return Ok(ValidateCallbackResult::Invalid(format!(
+        "Unknown organization permission: {}",
+        permission
+      )));
+    }
+
+    if role.permissions[..index].contains(permission) {
+      return Ok(ValidateCallbackResult::Invalid(format!(
+        "The '{}' permission is granted twice",
+        permission
+      )));
+    }
+  }
+
+  Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_create_organization_role(
+  action: Create,
+  role: OrganizationRole,
+) -> ExternResult<ValidateCallbackResult> {
+  validate_organization_authority_proof(
+    &action.author,
+    action.timestamp,
+    action.prev_action,
+    &role.organization,
+    OrganizationPermission::ManageRoles,
+  )
+}
+
+pub fn validate_update_organization_role(
+  action: Update,
+  role: OrganizationRole,
+  original_action_hash: ActionHash,
+) -> ExternResult<ValidateCallbackResult> {
+  let original_record = must_get_valid_record(original_action_hash)?;
+  let original_role: OrganizationRole = original_record
+    .entry()
+    .to_app_option()
+    .map_err(|e| wasm_error!(e))?
+    .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+      "Could not find the original OrganizationRole"
+    ))))?;
+
+  if role.organization != original_role.organization {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "The organization of an OrganizationRole cannot be changed",
+    )));
+  }
+
+  validate_organization_authority_proof(
+    &action.author,
+    action.timestamp,
+    action.prev_action,
+    &role.organization,
+    OrganizationPermission::ManageRoles,
+  )
+}
+
+pub fn validate_delete_organization_role(
+  action: Delete,
+  original_role: OrganizationRole,
+) -> ExternResult<ValidateCallbackResult> {
+  validate_organization_authority_proof(
+    &action.author,
+    action.timestamp,
+    action.prev_action,
+    &original_role.organization,
+    OrganizationPermission::ManageRoles,
+  )
+}
+
+/// Validates the assignment of a role to a user, which must be made by an agent allowed to
+/// manage the roles of the organization.
+pub fn validate_create_link_role_users(
+  action: CreateLink,
+  base_address: AnyLinkableHash,
+  _target_address: AnyLinkableHash,
+  tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+  let role_original_action_hash =
+    base_address
+      .into_action_hash()
+      .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+        "No action hash associated with link"
+      ))))?;
+  let role_record = must_get_valid_record(role_original_action_hash)?;
+  let role = match role_record.action() {
+    Action::Create(_) => role_record
+      .entry()
+      .to_app_option::<OrganizationRole>()
+      .ok()
+      .flatten(),
+    _ => None,
+  };
+  let Some(role) = role else {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "A role can only be assigned from the original action of an OrganizationRole",
+    )));
+  };
+
+  let authority: OrganizationAuthority =
+    decode(&tag.into_inner()).map_err(|e| wasm_error!(WasmErrorInner::Serialize(e)))?;
+
+  validate_organization_authority(
+    &authority,
+    &role.organization,
+    OrganizationPermission::ManageRoles,
+    &action.author,
+    action.timestamp,
+    &action.prev_action,
+  )
+}
+
+/// A role assigned to a user, cited to prove that the user's agent holds the permissions the
+/// role grants.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RoleGrant {
+  /// The action hash of the version of the role granting the permission.
+  pub role: ActionHash,
+  /// The action hash of the `RoleUsers` link assigning the role to the user.
+  pub role_assignment_link: ActionHash,
+  /// The action hash of the `UserAgents` link from the user to the author.
+  pub user_agent_link: ActionHash,
+}
+
+/// What entitles an agent to exercise a permission in an organization: being one of its
+/// coordinators, or holding one of its roles.
+///
+/// A role grant is checked against the role version and the assignment it cites. Updates,
+/// deletes and unassignments made later by other agents leave no trace on the holder's chain, so
+/// they do not invalidate it.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum OrganizationAuthority {
+  Coordinator(OnBehalfOf),
+  Role(RoleGrant),
+}
+
+impl OrganizationAuthority {
+  /// The `UserAgents` link from the user holding the authority to their agent.
+  pub fn user_agent_link(&self) -> &ActionHash {
+    match self {
+      Self::Coordinator(on_behalf_of) => &on_behalf_of.user_agent_link,
+      Self::Role(grant) => &grant.user_agent_link,
+    }
+  }
+}
+
+/// Validates that the author held a permission in an organization when committing the action
+/// following `prev_action`.
+pub fn validate_organization_authority(
+  authority: &OrganizationAuthority,
+  organization_original_action_hash: &ActionHash,
+  permission: OrganizationPermission,
+  author: &AgentPubKey,
+  timestamp: Timestamp,
+  prev_action: &ActionHash,
+) -> ExternResult<ValidateCallbackResult> {
+  match authority {
+    OrganizationAuthority::Coordinator(on_behalf_of) => {
+      if on_behalf_of.organization_original_action_hash != *organization_original_action_hash {
+        return Ok(ValidateCallbackResult::Invalid(String::from(
+          "The coordinator proof must be about the organization",
+        )));
+      }
+
+      validate_on_behalf_of(on_behalf_of, author, timestamp, prev_action)
+    }
+    OrganizationAuthority::Role(grant) => {
+      let role_record = must_get_valid_record(grant.role.clone())?;
+      let role: Option<OrganizationRole> = role_record.entry().to_app_option().ok().flatten();
+      let role_original_action_hash = get_original_action_hash(grant.role.clone())?;
+
+      let role_assignment_link = match must_get_valid_record(grant.role_assignment_link.clone())?
+        .action()
+      {
+        Action::CreateLink(create_link)
+          if matches!(
+            LinkTypes::from_type(create_link.zome_index, create_link.link_type)?,
+            Some(LinkTypes::RoleUsers)
+          ) && create_link.base_address == AnyLinkableHash::from(role_original_action_hash) =>
+        {
+          Some(create_link.clone())
+        }
+        _ => None,
+      };
+
+      let holder = match role_assignment_link
+        .as_ref()
+        .and_then(|link| link.target_address.clone().into_action_hash())
+      {
+        Some(user_original_action_hash) => {
+          get_user_agent_link_target(&user_original_action_hash, grant.user_agent_link.clone())?
+        }
+        None => None,
+      };
+
+      Ok(check_role_grant(
+        role.as_ref(),
+        role_assignment_link.as_ref(),
+        holder.as_ref(),
+        organization_original_action_hash,
+        permission,
+        author,
+        timestamp,
+      ))
+    }
+  }
+}
+
+/// Checks a role grant against the role version and the `RoleUsers` link it cites, and the agent
+/// targeted by the cited `UserAgents` link, once they were fetched.
+pub fn check_role_grant(
+  role: Option<&OrganizationRole>,
+  role_assignment_link: Option<&CreateLink>,
+  holder: Option<&AgentPubKey>,
+  organization_original_action_hash: &ActionHash,
+  permission: OrganizationPermission,
+  author: &AgentPubKey,
+  timestamp: Timestamp,
+) -> ValidateCallbackResult {
+  let Some(role) = role else {
+    return ValidateCallbackResult::Invalid(String::from(
+      "The role grant must cite an OrganizationRole",
+    ));
+  };
+
+  if role.organization != *organization_original_action_hash {
+    return ValidateCallbackResult::Invalid(String::from(
+      "The role grant must cite a role of the organization",
+    ));
+  }
+
+  if !role.permissions.contains(&permission.to_string()) {
+    return ValidateCallbackResult::Invalid(format!(
+      "The cited role does not grant the '{}' permission",
+      permission
+    ));
+  }
+
+  let Some(role_assignment_link) = role_assignment_link else {
+    return ValidateCallbackResult::Invalid(String::from(
+      "The role grant must cite the RoleUsers link assigning the role",
+    ));
+  };
+
+  if role_assignment_link.timestamp > timestamp {
+    return ValidateCallbackResult::Invalid(String::from(
+      "The role must be assigned before it is used",
+    ));
+  }
+
+  if holder != Some(author) {
+    return ValidateCallbackResult::Invalid(String::from(
+      "The role must be assigned to the user of the author",
+    ));
+  }
+
+  ValidateCallbackResult::Valid
+}
+
+/// Validates the `OrganizationAuthorityProofs` link committed right before an action that
+/// requires a permission in an organization.
+pub fn validate_organization_authority_proof(
+  author: &AgentPubKey,
+  timestamp: Timestamp,
+  prev_action: ActionHash,
+  organization_original_action_hash: &ActionHash,
+  permission: OrganizationPermission,
+) -> ExternResult<ValidateCallbackResult> {
+  let Action::CreateLink(proof_link) = must_get_action(prev_action.clone())?.action().clone()
+  else {
+    return Ok(ValidateCallbackResult::Invalid(format!(
+      "This action requires proving the '{}' permission right before it",
+      permission
+    )));
+  };
+
+  if !matches!(
+    LinkTypes::from_type(proof_link.zome_index, proof_link.link_type)?,
+    Some(LinkTypes::OrganizationAuthorityProofs)
+  ) || proof_link.base_address
+    != AnyLinkableHash::from(organization_original_action_hash.clone())
+  {
+    return Ok(ValidateCallbackResult::Invalid(format!(
+      "This action requires proving the '{}' permission right before it",
+      permission
+    )));
+  }
+
+  let authority: OrganizationAuthority =
+    decode(&proof_link.tag.into_inner()).map_err(|e| wasm_error!(WasmErrorInner::Serialize(e)))?;
+
+  validate_organization_authority(
+    &authority,
+    organization_original_action_hash,
+    permission,
+    author,
+    timestamp,
+    &prev_action,
+  )
+}
@@ -0,0 +1,68 @@
+#[cfg(test)]
+mod tests {
+  use hdi::prelude::ValidateCallbackResult;
+
+  use crate::{validate_availability, Availability, AvailabilityWindow};
+
+  fn tuesday_hour() -> AvailabilityWindow {
+    AvailabilityWindow {
+      weekday: 1,
+      start_minute: 18 * 60,
+      duration_minutes: 60,
+    }
+  }
+
+  #[test]
+  fn test_availability_is_valid() {
+    let availability = Availability {
+      windows: vec![tuesday_hour()],
+    };
+    let result = validate_availability(availability).unwrap();
+    assert!(result == ValidateCallbackResult::Valid);
+  }
+
+  #[test]
+  fn test_availability_with_invalid_windows() {
+    let availability = Availability {
+      windows: vec![AvailabilityWindow {
+        weekday: 7,
+        ..tuesday_hour()
+      }],
+    };
+    let result = validate_availability(availability).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "Availability weekday must be between 0 (Monday) and 6 (Sunday)"
+        ))
+    );
+
+    let availability = Availability {
+      windows: vec![AvailabilityWindow {
+        start_minute: 24 * 60,
+        ..tuesday_hour()
+      }],
+    };
+    let result = validate_availability(availability).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "Availability start must be within the day"
+        ))
+    );
+
+    let availability = Availability {
+      windows: vec![AvailabilityWindow {
+        duration_minutes: 0,
+        ..tuesday_hour()
+      }],
+    };
+    let result = validate_availability(availability).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "Availability duration must be between one minute and one day"
+        ))
+    );
+  }
+}
@@ -0,0 +1,57 @@
+#[cfg(test)]
+mod tests {
+  use hdi::prelude::{ActionHash, ValidateCallbackResult};
+
+  use crate::{validate_endorsement, validate_endorsement_is_new, Endorsement};
+
+  fn endorsement(endorsed_user: u8, skill: &str) -> Endorsement {
+    Endorsement {
+      endorser: ActionHash::from_raw_36(vec![0; 36]),
+      endorser_agent_link: ActionHash::from_raw_36(vec![9; 36]),
+      endorsed_user: ActionHash::from_raw_36(vec![endorsed_user; 36]),
+      skill: skill.to_string(),
+    }
+  }
+
+  #[test]
+  fn test_endorsement_is_valid() {
+    let result = validate_endorsement(endorsement(1, "rust")).unwrap();
+    assert!(result == ValidateCallbackResult::Valid);
+  }
+
+  #[test]
+  fn test_self_endorsement() {
+    let result = validate_endorsement(endorsement(0, "rust")).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from("A user cannot endorse their own skills"))
+    );
+  }
+
+  #[test]
+  fn test_endorsement_with_empty_skill() {
+    let result = validate_endorsement(endorsement(1, " ")).unwrap();
+    assert!(
+      result == ValidateCallbackResult::Invalid(String::from("The endorsed skill cannot be empty"))
+    );
+  }
+
+  #[test]
+  fn test_duplicate_endorsement() {
+    let previous_endorsements = vec![endorsement(1, "rust"), endorsement(2, "holochain")];
+
+    let result = validate_endorsement_is_new(&endorsement(1, "rust"), &previous_endorsements);
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "This skill was already endorsed on this user"
+        ))
+    );
+
+    let result = validate_endorsement_is_new(&endorsement(1, "holochain"), &previous_endorsements);
+    assert!(result == ValidateCallbackResult::Valid);
+
+    let result = validate_endorsement_is_new(&endorsement(2, "rust"), &previous_endorsements);
+    assert!(result == ValidateCallbackResult::Valid);
+  }
+}
@@ -1 +1,7 @@
+mod availability_test;
+mod endorsement_test;
+mod organization_invitation_test;
+mod organization_join_request_test;
+mod organization_role_test;
+mod organization_test;
 mod user_test;
@@ -0,0 +1,259 @@
+#[cfg(test)]
+mod tests {
+  use hdi::prelude::{ActionHash, AgentPubKey, ValidateCallbackResult};
+  use utils::on_behalf_of::OnBehalfOf;
+
+  use crate::{
+    validate_create_organization_invitation, validate_organization_invitation,
+    validate_organization_invitation_decider, validate_organization_invitation_transition,
+    OrganizationAuthority, OrganizationInvitation, RoleGrant,
+  };
+
+  fn invitation(status: &str) -> OrganizationInvitation {
+    OrganizationInvitation {
+      organization: ActionHash::from_raw_36(vec![0; 36]),
+      inviter: ActionHash::from_raw_36(vec![1; 36]),
+      invitee: ActionHash::from_raw_36(vec![2; 36]),
+      role: "member".to_string(),
+      status: status.to_string(),
+      authority: OrganizationAuthority::Coordinator(OnBehalfOf {
+        organization_original_action_hash: ActionHash::from_raw_36(vec![0; 36]),
+        coordinator_link: ActionHash::from_raw_36(vec![4; 36]),
+        user_agent_link: ActionHash::from_raw_36(vec![5; 36]),
+      }),
+      invitee_agent_link: None,
+    }
+  }
+
+  #[test]
+  fn test_organization_invitation_is_valid() {
+    let result = validate_organization_invitation(invitation("pending")).unwrap();
+    assert!(result == ValidateCallbackResult::Valid);
+  }
+
+  #[test]
+  fn test_self_invitation() {
+    let invitation = OrganizationInvitation {
+      invitee: ActionHash::from_raw_36(vec![1; 36]),
+      ..invitation("pending")
+    };
+    let result = validate_organization_invitation(invitation).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "A user cannot invite themselves to an organization"
+        ))
+    );
+  }
+
+  #[test]
+  fn test_organization_invitation_with_invalid_status() {
+    let result = validate_organization_invitation(invitation("maybe")).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "Invitation status must be 'pending', 'accepted', 'declined' or 'cancelled'."
+        ))
+    );
+  }
+
+  #[test]
+  fn test_organization_invitation_with_invalid_role() {
+    let invitation = OrganizationInvitation {
+      role: "owner".to_string(),
+      ..invitation("pending")
+    };
+    let result = validate_organization_invitation(invitation).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "Invitation role must be 'member' or 'coordinator'."
+        ))
+    );
+  }
+
+  #[test]
+  fn test_organization_invitation_created_accepted() {
+    let result = validate_create_organization_invitation(invitation("accepted")).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "An entry cannot be created in the 'accepted' state"
+        ))
+    );
+  }
+
+  #[test]
+  fn test_organization_invitation_created_for_another_organization() {
+    let other_organization = OrganizationInvitation {
+      authority: OrganizationAuthority::Coordinator(OnBehalfOf {
+        organization_original_action_hash: ActionHash::from_raw_36(vec![6; 36]),
+        coordinator_link: ActionHash::from_raw_36(vec![4; 36]),
+        user_agent_link: ActionHash::from_raw_36(vec![5; 36]),
+      }),
+      ..invitation("pending")
+    };
+    let result = validate_create_organization_invitation(other_organization).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "An OrganizationInvitation must be sent on behalf of its organization"
+        ))
+    );
+
+    let decided = OrganizationInvitation {
+      invitee_agent_link: Some(ActionHash::from_raw_36(vec![7; 36])),
+      ..invitation("pending")
+    };
+    let result = validate_create_organization_invitation(decided).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "An OrganizationInvitation cannot be created as decided"
+        ))
+    );
+  }
+  #[test]
+  fn test_organization_invitation_sent_through_a_role() {
+    let role_grant = OrganizationAuthority::Role(RoleGrant {
+      role: ActionHash::from_raw_36(vec![8; 36]),
+      role_assignment_link: ActionHash::from_raw_36(vec![9; 36]),
+      user_agent_link: ActionHash::from_raw_36(vec![5; 36]),
+    });
+    let member_invitation = OrganizationInvitation {
+      authority: role_grant.clone(),
+      ..invitation("pending")
+    };
+    let result = validate_create_organization_invitation(member_invitation).unwrap();
+    assert!(result == ValidateCallbackResult::Valid);
+
+    let coordinator_invitation = OrganizationInvitation {
+      authority: role_grant,
+      role: "coordinator".to_string(),
+      ..invitation("pending")
+    };
+    let result = validate_create_organization_invitation(coordinator_invitation).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "Only coordinators can invite coordinators to an organization"
+        ))
+    );
+  }
+
+  #[test]
+  fn test_organization_invitation_deciders() {
+    let invitee_agent = AgentPubKey::from_raw_36(vec![8; 36]);
+    let stranger = AgentPubKey::from_raw_36(vec![9; 36]);
+    let accepted = OrganizationInvitation {
+      invitee_agent_link: Some(ActionHash::from_raw_36(vec![7; 36])),
+      ..invitation("accepted")
+    };
+
+    let result =
+      validate_organization_invitation_decider(&accepted, Some(&invitee_agent), &invitee_agent);
+    assert!(result == ValidateCallbackResult::Valid);
+
+    // The cited link must target the author, and a decision must cite one
+    let result =
+      validate_organization_invitation_decider(&accepted, Some(&invitee_agent), &stranger);
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "Only an agent of the invited user can accept or decline an invitation"
+        ))
+    );
+
+    let result = validate_organization_invitation_decider(&invitation("declined"), None, &stranger);
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "Only an agent of the invited user can accept or decline an invitation"
+        ))
+    );
+
+    let result =
+      validate_organization_invitation_decider(&invitation("cancelled"), None, &stranger);
+    assert!(result == ValidateCallbackResult::Valid);
+
+    let cancelled = OrganizationInvitation {
+      invitee_agent_link: Some(ActionHash::from_raw_36(vec![7; 36])),
+      ..invitation("cancelled")
+    };
+    let result = validate_organization_invitation_decider(&cancelled, None, &stranger);
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "Only a decided OrganizationInvitation cites an agent of the invitee"
+        ))
+    );
+  }
+
+  #[test]
+  fn test_organization_invitation_transitions() {
+    let pending = invitation("pending");
+
+    // The invitee decides, the inviter cancels
+    let result =
+      validate_organization_invitation_transition(&pending, &invitation("accepted"), false)
+        .unwrap();
+    assert!(result == ValidateCallbackResult::Valid);
+
+    let result =
+      validate_organization_invitation_transition(&pending, &invitation("accepted"), true).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "The author cannot go from the 'pending' state to the 'accepted' state"
+        ))
+    );
+
+    let result =
+      validate_organization_invitation_transition(&pending, &invitation("cancelled"), false)
+        .unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "Only the author can go from the 'pending' state to the 'cancelled' state"
+        ))
+    );
+
+    // A closed invitation cannot be reopened or decided again
+    let result = validate_organization_invitation_transition(
+      &invitation("declined"),
+      &invitation("accepted"),
+      false,
+    )
+    .unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "Cannot go from the 'declined' state to the 'accepted' state"
+        ))
+    );
+
+    let moved = OrganizationInvitation {
+      invitee: ActionHash::from_raw_36(vec![3; 36]),
+      ..invitation("accepted")
+    };
+    let result = validate_organization_invitation_transition(&pending, &moved, false).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "The organization, users and role of an OrganizationInvitation cannot be changed"
+        ))
+    );
+
+    let promoted = OrganizationInvitation {
+      role: "coordinator".to_string(),
+      ..invitation("accepted")
+    };
+    let result = validate_organization_invitation_transition(&pending, &promoted, false).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "The organization, users and role of an OrganizationInvitation cannot be changed"
+        ))
+    );
+  }
+}
@@ -0,0 +1,145 @@
+#[cfg(test)]
+mod tests {
+  use hdi::prelude::{ActionHash, ValidateCallbackResult};
+
+  use crate::{
+    validate_create_organization_join_request, validate_organization_join_request,
+    validate_organization_join_request_transition, OrganizationAuthority, OrganizationJoinRequest,
+    RoleGrant,
+  };
+
+  fn join_request(status: &str, rejection_reason: Option<&str>) -> OrganizationJoinRequest {
+    OrganizationJoinRequest {
+      organization: ActionHash::from_raw_36(vec![0; 36]),
+      requester: ActionHash::from_raw_36(vec![1; 36]),
+      message: "I would like to help".to_string(),
+      status: status.to_string(),
+      rejection_reason: rejection_reason.map(String::from),
+      requester_agent_link: ActionHash::from_raw_36(vec![2; 36]),
+      decision_authority: None,
+    }
+  }
+
+  #[test]
+  fn test_organization_join_request_is_valid() {
+    let result = validate_organization_join_request(join_request("pending", None)).unwrap();
+    assert!(result == ValidateCallbackResult::Valid);
+
+    let result =
+      validate_organization_join_request(join_request("rejected", Some("No room left"))).unwrap();
+    assert!(result == ValidateCallbackResult::Valid);
+  }
+
+  #[test]
+  fn test_organization_join_request_with_invalid_status() {
+    let result = validate_organization_join_request(join_request("maybe", None)).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "Join request status must be 'pending', 'approved' or 'rejected'."
+        ))
+    );
+  }
+
+  #[test]
+  fn test_organization_join_request_rejection_reason() {
+    let result = validate_organization_join_request(join_request("rejected", Some(" "))).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "A rejected join request must have a rejection reason"
+        ))
+    );
+
+    let result =
+      validate_organization_join_request(join_request("approved", Some("Welcome"))).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "Only a rejected join request can have a rejection reason"
+        ))
+    );
+  }
+
+  #[test]
+  fn test_organization_join_request_created_approved() {
+    let result = validate_create_organization_join_request(join_request("approved", None)).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "An entry cannot be created in the 'approved' state"
+        ))
+    );
+  }
+
+  #[test]
+  fn test_organization_join_request_created_with_a_decision_authority() {
+    let decided = OrganizationJoinRequest {
+      decision_authority: Some(OrganizationAuthority::Role(RoleGrant {
+        role: ActionHash::from_raw_36(vec![3; 36]),
+        role_assignment_link: ActionHash::from_raw_36(vec![4; 36]),
+        user_agent_link: ActionHash::from_raw_36(vec![5; 36]),
+      })),
+      ..join_request("pending", None)
+    };
+    let result = validate_create_organization_join_request(decided).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "An OrganizationJoinRequest cannot be created as decided"
+        ))
+    );
+  }
+
+  #[test]
+  fn test_organization_join_request_transitions() {
+    let pending = join_request("pending", None);
+
+    let result = validate_organization_join_request_transition(
+      &pending,
+      &join_request("approved", None),
+      false,
+    )
+    .unwrap();
+    assert!(result == ValidateCallbackResult::Valid);
+
+    // Requesters cannot approve themselves
+    let result = validate_organization_join_request_transition(
+      &pending,
+      &join_request("approved", None),
+      true,
+    )
+    .unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "The author cannot go from the 'pending' state to the 'approved' state"
+        ))
+    );
+
+    let result = validate_organization_join_request_transition(
+      &join_request("rejected", Some("No room left")),
+      &join_request("approved", None),
+      false,
+    )
+    .unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "Cannot go from the 'rejected' state to the 'approved' state"
+        ))
+    );
+
+    let edited = OrganizationJoinRequest {
+      message: "Please accept me".to_string(),
+      ..join_request("approved", None)
+    };
+    let result = validate_organization_join_request_transition(&pending, &edited, false).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "The organization, requester and message of an OrganizationJoinRequest cannot be changed"
+        ))
+    );
+  }
+}
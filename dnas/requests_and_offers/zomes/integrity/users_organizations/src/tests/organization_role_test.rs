@@ -0,0 +1,192 @@
+#[cfg(test)]
+mod tests {
+  use hdi::prelude::*;
+  use utils::link_types::UsersOrganizationsLinkTypes;
+
+  use crate::{
+    check_role_grant, validate_organization_role, OrganizationPermission, OrganizationRole,
+  };
+
+  fn role(name: &str, permissions: &[&str]) -> OrganizationRole {
+    OrganizationRole {
+      organization: ActionHash::from_raw_36(vec![0; 36]),
+      name: name.to_string(),
+      permissions: permissions.iter().map(|p| p.to_string()).collect(),
+    }
+  }
+
+  #[test]
+  fn test_organization_role_is_valid() {
+    let result =
+      validate_organization_role(role("Treasurer", &["manage_finances", "manage_members"]))
+        .unwrap();
+    assert!(result == ValidateCallbackResult::Valid);
+  }
+
+  #[test]
+  fn test_organization_role_with_empty_name() {
+    let result = validate_organization_role(role(" ", &["manage_finances"])).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from("Organization role name cannot be empty"))
+    );
+  }
+
+  #[test]
+  fn test_organization_role_without_permissions() {
+    let result = validate_organization_role(role("Spokesperson", &[])).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "An organization role must grant at least one permission"
+        ))
+    );
+  }
+
+  #[test]
+  fn test_organization_role_with_invalid_permissions() {
+    let result = validate_organization_role(role("Reviewer", &["do_anything"])).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "Unknown organization permission: do_anything"
+        ))
+    );
+
+    let result =
+      validate_organization_role(role("Reviewer", &["review_listings", "review_listings"]))
+        .unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "The 'review_listings' permission is granted twice"
+        ))
+    );
+  }
+
+  fn agent(byte: u8) -> AgentPubKey {
+    AgentPubKey::from_raw_36(vec![byte; 36])
+  }
+
+  fn role_assignment_link() -> CreateLink {
+    CreateLink {
+      author: agent(10),
+      timestamp: Timestamp::from_micros(100),
+      action_seq: 5,
+      prev_action: ActionHash::from_raw_36(vec![4; 36]),
+      base_address: ActionHash::from_raw_36(vec![2; 36]).into(),
+      target_address: ActionHash::from_raw_36(vec![3; 36]).into(),
+      zome_index: ZomeIndex(0),
+      link_type: UsersOrganizationsLinkTypes::RoleUsers.into(),
+      tag: LinkTag::new(vec![]),
+      weight: RateWeight::default(),
+    }
+  }
+
+  fn check_grant(
+    role: Option<&OrganizationRole>,
+    link: Option<&CreateLink>,
+    holder: Option<&AgentPubKey>,
+    timestamp: i64,
+  ) -> ValidateCallbackResult {
+    check_role_grant(
+      role,
+      link,
+      holder,
+      &ActionHash::from_raw_36(vec![0; 36]),
+      OrganizationPermission::ManageRoles,
+      &agent(11),
+      Timestamp::from_micros(timestamp),
+    )
+  }
+
+  #[test]
+  fn test_role_grant_is_valid() {
+    let role_manager = role("Role Manager", &["manage_roles"]);
+    let result = check_grant(
+      Some(&role_manager),
+      Some(&role_assignment_link()),
+      Some(&agent(11)),
+      200,
+    );
+    assert!(result == ValidateCallbackResult::Valid);
+  }
+
+  #[test]
+  fn test_role_grant_without_the_permission() {
+    let treasurer = role("Treasurer", &["manage_finances"]);
+    let result = check_grant(
+      Some(&treasurer),
+      Some(&role_assignment_link()),
+      Some(&agent(11)),
+      200,
+    );
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "The cited role does not grant the 'manage_roles' permission"
+        ))
+    );
+
+    let mut other_organization_role = role("Role Manager", &["manage_roles"]);
+    other_organization_role.organization = ActionHash::from_raw_36(vec![1; 36]);
+    let result = check_grant(
+      Some(&other_organization_role),
+      Some(&role_assignment_link()),
+      Some(&agent(11)),
+      200,
+    );
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "The role grant must cite a role of the organization"
+        ))
+    );
+
+    let result = check_grant(None, Some(&role_assignment_link()), Some(&agent(11)), 200);
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "The role grant must cite an OrganizationRole"
+        ))
+    );
+  }
+
+  #[test]
+  fn test_role_grant_with_invalid_assignment() {
+    let role_manager = role("Role Manager", &["manage_roles"]);
+    let result = check_grant(Some(&role_manager), None, Some(&agent(11)), 200);
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "The role grant must cite the RoleUsers link assigning the role"
+        ))
+    );
+
+    let result = check_grant(
+      Some(&role_manager),
+      Some(&role_assignment_link()),
+      Some(&agent(11)),
+      50,
+    );
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "The role must be assigned before it is used"
+        ))
+    );
+
+    let result = check_grant(
+      Some(&role_manager),
+      Some(&role_assignment_link()),
+      Some(&agent(12)),
+      200,
+    );
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "The role must be assigned to the user of the author"
+        ))
+    );
+  }
+}
@@ -0,0 +1,221 @@
+#[cfg(test)]
+mod tests {
+  use hdi::prelude::*;
+  use utils::{
+    link_types::UsersOrganizationsLinkTypes,
+    on_behalf_of::{check_on_behalf_of, validate_coordinator_link_is_live, OnBehalfOf},
+  };
+
+  use crate::{
+    check_coordinator_grant_is_unused, check_coordinator_invitation_grant, OrganizationAuthority,
+    OrganizationInvitation,
+  };
+
+  fn organization() -> ActionHash {
+    ActionHash::from_raw_36(vec![1; 36])
+  }
+
+  fn coordinator_link_hash() -> ActionHash {
+    ActionHash::from_raw_36(vec![2; 36])
+  }
+
+  fn agent(byte: u8) -> AgentPubKey {
+    AgentPubKey::from_raw_36(vec![byte; 36])
+  }
+
+  fn on_behalf_of() -> OnBehalfOf {
+    OnBehalfOf {
+      organization_original_action_hash: organization(),
+      coordinator_link: coordinator_link_hash(),
+      user_agent_link: ActionHash::from_raw_36(vec![3; 36]),
+    }
+  }
+
+  fn coordinator_link() -> CreateLink {
+    CreateLink {
+      author: agent(10),
+      timestamp: Timestamp::from_micros(100),
+      action_seq: 5,
+      prev_action: ActionHash::from_raw_36(vec![4; 36]),
+      base_address: organization().into(),
+      target_address: ActionHash::from_raw_36(vec![5; 36]).into(),
+      zome_index: ZomeIndex(0),
+      link_type: UsersOrganizationsLinkTypes::OrganizationCoordinators.into(),
+      tag: LinkTag::new(vec![]),
+      weight: RateWeight::default(),
+    }
+  }
+
+  fn activity(action: Action) -> RegisterAgentActivity {
+    RegisterAgentActivity {
+      action: SignedActionHashed::with_presigned(
+        ActionHashed::with_pre_hashed(action, ActionHash::from_raw_36(vec![9; 36])),
+        Signature([0; 64]),
+      ),
+      cached_entry: None,
+    }
+  }
+
+  fn delete_link(link_add_address: ActionHash) -> RegisterAgentActivity {
+    activity(Action::DeleteLink(DeleteLink {
+      author: agent(10),
+      timestamp: Timestamp::from_micros(200),
+      action_seq: 6,
+      prev_action: ActionHash::from_raw_36(vec![6; 36]),
+      base_address: organization().into(),
+      link_add_address,
+    }))
+  }
+
+  fn coordinator_invitation() -> OrganizationInvitation {
+    OrganizationInvitation {
+      organization: organization(),
+      inviter: ActionHash::from_raw_36(vec![11; 36]),
+      invitee: ActionHash::from_raw_36(vec![5; 36]),
+      role: "coordinator".to_string(),
+      status: "accepted".to_string(),
+      authority: OrganizationAuthority::Coordinator(on_behalf_of()),
+      invitee_agent_link: Some(ActionHash::from_raw_36(vec![12; 36])),
+    }
+  }
+
+  #[test]
+  fn test_coordinator_can_change_the_organization() {
+    let result = check_on_behalf_of(
+      &on_behalf_of(),
+      Some(&coordinator_link()),
+      Some(&agent(10)),
+      &agent(10),
+      Timestamp::from_micros(300),
+    );
+    assert!(result == ValidateCallbackResult::Valid);
+  }
+
+  #[test]
+  fn test_non_coordinator_cannot_delete_the_organization() {
+    let result = check_on_behalf_of(
+      &on_behalf_of(),
+      Some(&coordinator_link()),
+      Some(&agent(10)),
+      &agent(11),
+      Timestamp::from_micros(300),
+    );
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "Only a coordinator of the organization can publish for it"
+        ))
+    );
+  }
+
+  #[test]
+  fn test_coordinator_link_of_another_organization() {
+    let mut link = coordinator_link();
+    link.base_address = ActionHash::from_raw_36(vec![7; 36]).into();
+    let result = check_on_behalf_of(
+      &on_behalf_of(),
+      Some(&link),
+      Some(&agent(10)),
+      &agent(10),
+      Timestamp::from_micros(300),
+    );
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "The coordinator link must start from the organization"
+        ))
+    );
+  }
+
+  #[test]
+  fn test_live_coordinator_link() {
+    let result = validate_coordinator_link_is_live(
+      &coordinator_link_hash(),
+      &[delete_link(ActionHash::from_raw_36(vec![8; 36]))],
+    );
+    assert!(result == ValidateCallbackResult::Valid);
+  }
+
+  #[test]
+  fn test_removed_coordinator_cannot_change_the_organization() {
+    let result = validate_coordinator_link_is_live(
+      &coordinator_link_hash(),
+      &[delete_link(coordinator_link_hash())],
+    );
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "The coordinator link was deleted before publishing for the organization"
+        ))
+    );
+  }
+
+  #[test]
+  fn test_accepted_coordinator_invitation_grants_the_invitee() {
+    let result = check_coordinator_invitation_grant(
+      &coordinator_invitation(),
+      &organization(),
+      &ActionHash::from_raw_36(vec![5; 36]),
+    );
+    assert!(result == ValidateCallbackResult::Valid);
+  }
+
+  #[test]
+  fn test_coordinator_invitation_grants_only_the_invitee() {
+    let result = check_coordinator_invitation_grant(
+      &coordinator_invitation(),
+      &organization(),
+      &ActionHash::from_raw_36(vec![13; 36]),
+    );
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "The coordinator invitation must be sent to the linked user for the organization"
+        ))
+    );
+  }
+
+  #[test]
+  fn test_member_invitation_does_not_grant_coordination() {
+    let invitation = OrganizationInvitation {
+      role: "member".to_string(),
+      ..coordinator_invitation()
+    };
+    let result = check_coordinator_invitation_grant(
+      &invitation,
+      &organization(),
+      &ActionHash::from_raw_36(vec![5; 36]),
+    );
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "The coordinator grant must cite an accepted coordinator invitation"
+        ))
+    );
+  }
+
+  #[test]
+  fn test_coordinator_grant_is_unused() {
+    let mut other_organization_link = coordinator_link();
+    other_organization_link.base_address = ActionHash::from_raw_36(vec![7; 36]).into();
+    let result = check_coordinator_grant_is_unused(
+      &coordinator_link(),
+      &[activity(Action::CreateLink(other_organization_link))],
+    );
+    assert!(result == ValidateCallbackResult::Valid);
+  }
+
+  #[test]
+  fn test_coordinator_grant_cannot_be_reused() {
+    let result = check_coordinator_grant_is_unused(
+      &coordinator_link(),
+      &[activity(Action::CreateLink(coordinator_link()))],
+    );
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "The coordinator grant was already used to link the author to the organization"
+        ))
+    );
+  }
+}
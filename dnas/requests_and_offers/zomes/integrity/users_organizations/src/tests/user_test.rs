@@ -1,8 +1,29 @@
 #[cfg(test)]
 mod tests {
-  use hdi::prelude::{SerializedBytes, UnsafeBytes, ValidateCallbackResult};
+  use hdi::prelude::{
+    decode, encode, AgentPubKey, SerializedBytes, UnsafeBytes, ValidateCallbackResult,
+  };
 
-  use crate::{validate_user, User};
+  use std::str::FromStr;
+
+  use utils::time_zone::{parse_utc_offset, UserTimeZone};
+
+  use crate::{check_user_agent_link, validate_user, User, UserSkill};
+
+  fn sample_skills() -> Vec<UserSkill> {
+    vec![
+      UserSkill {
+        name: "Rust".to_string(),
+        proficiency: Some("expert".to_string()),
+        years_of_experience: Some(5),
+      },
+      UserSkill {
+        name: "Holo".to_string(),
+        proficiency: Some("learning".to_string()),
+        years_of_experience: None,
+      },
+    ]
+  }
 
   #[test]
   fn test_user_is_valid() {
@@ -12,7 +33,7 @@ mod tests {
       bio: "I am a software developer".to_string(),
       picture: None,
       user_type: "advocate".to_string(),
-      skills: vec!["Rust".to_string(), "Holo".to_string()],
+      skills: sample_skills(),
       email: "example@example.com".to_string(),
       phone: None,
       time_zone: "UTC".to_string(),
@@ -31,7 +52,7 @@ mod tests {
       bio: "I am a software developer".to_string(),
       picture: None,
       user_type: "advocate".to_string(),
-      skills: vec!["Rust".to_string(), "Holo".to_string()],
+      skills: sample_skills(),
       email: "x9e0r@example.com".to_string(),
       phone: None,
       time_zone: "UTC".to_string(),
@@ -41,6 +62,61 @@ mod tests {
     assert!(result == ValidateCallbackResult::Invalid(String::from("User name cannot be empty")));
   }
 
+  #[test]
+  fn test_user_time_zone() {
+    let user = |time_zone: &str| User {
+      name: "John Doe".to_string(),
+      nickname: "John".to_string(),
+      bio: "I am a software developer".to_string(),
+      picture: None,
+      user_type: "advocate".to_string(),
+      skills: sample_skills(),
+      email: "example@example.com".to_string(),
+      phone: None,
+      time_zone: time_zone.to_string(),
+      location: "New York".to_string(),
+    };
+
+    for time_zone in ["Europe/Paris", "UTC+02:00", "GMT-5"] {
+      let result = validate_user(user(time_zone)).unwrap();
+      assert!(result == ValidateCallbackResult::Valid);
+    }
+
+    for time_zone in ["Mars/Olympus_Mons", "UTC+15", ""] {
+      let result = validate_user(user(time_zone)).unwrap();
+      assert!(
+        result
+          == ValidateCallbackResult::Invalid(String::from(
+            "User time zone must be an IANA name such as 'Europe/Paris' or an offset from UTC such as 'UTC+02:00'"
+          ))
+      );
+    }
+  }
+
+  #[test]
+  fn test_parse_utc_offset() {
+    assert_eq!(parse_utc_offset("UTC"), Some(0));
+    assert_eq!(parse_utc_offset("UTC+2"), Some(120));
+    assert_eq!(parse_utc_offset("GMT-05:30"), Some(-330));
+    assert_eq!(parse_utc_offset("+0100"), Some(60));
+    assert_eq!(parse_utc_offset("Europe/Paris"), None);
+    assert_eq!(parse_utc_offset("UTC+15"), None);
+    assert_eq!(parse_utc_offset(""), None);
+  }
+
+  #[test]
+  fn test_parse_user_time_zone() {
+    assert_eq!(
+      UserTimeZone::from_str("UTC+2"),
+      Ok(UserTimeZone::FixedOffset(120))
+    );
+    assert!(matches!(
+      UserTimeZone::from_str("Europe/Paris"),
+      Ok(UserTimeZone::Iana(_))
+    ));
+    assert!(UserTimeZone::from_str("Mars/Olympus_Mons").is_err());
+  }
+
   #[test]
   fn test_user_with_invalid_picture() {
     let user = User {
@@ -49,7 +125,7 @@ mod tests {
       bio: "I am a software developer".to_string(),
       picture: Some(SerializedBytes::from(UnsafeBytes::from(vec![1, 2, 3]))),
       user_type: "advocate".to_string(),
-      skills: vec!["Rust".to_string(), "Holo".to_string()],
+      skills: sample_skills(),
       email: "x9e0r@example.com".to_string(),
       phone: None,
       time_zone: "UTC".to_string(),
@@ -69,7 +145,7 @@ mod tests {
       bio: "I am a software developer".to_string(),
       picture: None,
       user_type: "invalid".to_string(),
-      skills: vec!["Rust".to_string(), "Holo".to_string()],
+      skills: sample_skills(),
       email: "x9e0r@example.com".to_string(),
       phone: None,
       time_zone: "UTC".to_string(),
@@ -93,7 +169,7 @@ mod tests {
       bio: "I am a software developer".to_string(),
       picture: None,
       user_type: "advocate".to_string(),
-      skills: vec!["Rust".to_string(), "Holo".to_string()],
+      skills: sample_skills(),
       email: "invalid_email".to_string(),
       phone: None,
       time_zone: "UTC".to_string(),
@@ -103,4 +179,89 @@ mod tests {
     println!("result: {:#?}", result);
     assert!(result == ValidateCallbackResult::Invalid(String::from("Email is not valid")));
   }
+
+  #[test]
+  fn test_user_with_invalid_skill_proficiency() {
+    let user = User {
+      name: "John Doe".to_string(),
+      nickname: "John".to_string(),
+      bio: "I am a software developer".to_string(),
+      picture: None,
+      user_type: "advocate".to_string(),
+      skills: vec![UserSkill {
+        name: "Rust".to_string(),
+        proficiency: Some("guru".to_string()),
+        years_of_experience: Some(10),
+      }],
+      email: "example@example.com".to_string(),
+      phone: None,
+      time_zone: "UTC".to_string(),
+      location: "New York".to_string(),
+    };
+    let result = validate_user(user).unwrap();
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "Skill proficiency must be 'learning', 'intermediate' or 'expert'."
+        ))
+    );
+  }
+
+  #[test]
+  fn test_skills_listed_as_plain_names() {
+    let bytes = encode(&vec!["Rust", "Holo"]).unwrap();
+    let skills: Vec<UserSkill> = decode(&bytes).unwrap();
+    assert!(
+      skills
+        == vec![
+          UserSkill {
+            name: "Rust".to_string(),
+            proficiency: None,
+            years_of_experience: None,
+          },
+          UserSkill {
+            name: "Holo".to_string(),
+            proficiency: None,
+            years_of_experience: None,
+          },
+        ]
+    );
+
+    let bytes = encode(&sample_skills()).unwrap();
+    let skills: Vec<UserSkill> = decode(&bytes).unwrap();
+    assert!(skills == sample_skills());
+  }
+
+  #[test]
+  fn test_user_agent_link_is_created_by_the_user_author() {
+    let author = AgentPubKey::from_raw_36(vec![1; 36]);
+    let result = check_user_agent_link(&author, Some(&author), &author);
+    assert!(result == ValidateCallbackResult::Valid);
+  }
+
+  #[test]
+  fn test_user_agent_link_must_target_its_author() {
+    let author = AgentPubKey::from_raw_36(vec![1; 36]);
+    let other_agent = AgentPubKey::from_raw_36(vec![2; 36]);
+    let result = check_user_agent_link(&author, Some(&other_agent), &author);
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "A UserAgents link must target the agent creating it"
+        ))
+    );
+  }
+
+  #[test]
+  fn test_user_agent_link_must_be_created_by_the_user_author() {
+    let user_author = AgentPubKey::from_raw_36(vec![1; 36]);
+    let author = AgentPubKey::from_raw_36(vec![2; 36]);
+    let result = check_user_agent_link(&user_author, Some(&author), &author);
+    assert!(
+      result
+        == ValidateCallbackResult::Invalid(String::from(
+          "Only the author of a User profile can link agents to it"
+        ))
+    );
+  }
 }
@@ -2,7 +2,7 @@ use std::{fmt::Display, str::FromStr};
 
 use email_address::EmailAddress;
 use hdi::prelude::*;
-use utils::{errors::UtilsError, is_image};
+use utils::{errors::UtilsError, is_image, time_zone::UserTimeZone};
 
 /// Represents a user Entry with various attributes such as name, nickname, bio, etc.
 #[hdk_entry_helper]
@@ -18,8 +18,9 @@ pub struct User {
   pub picture: Option<SerializedBytes>,
   /// The type of user, either 'advocate' or 'creator'.
   pub user_type: String,
-  /// A list of skills associated with the user.
-  pub skills: Vec<String>,
+  /// A list of skills associated with the user, resolved to canonical skills by the skills
+  /// zome.
+  pub skills: Vec<UserSkill>,
   /// The user's email address.
   pub email: String,
   /// An optional phone number for the user.
@@ -30,6 +31,89 @@ pub struct User {
   pub location: String,
 }
 
+impl User {
+  /// Returns the names of the user's skills.
+  pub fn skill_names(&self) -> Vec<String> {
+    self.skills.iter().map(|skill| skill.name.clone()).collect()
+  }
+}
+
+/// A skill listed on a user's profile, with how well the user masters it.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(from = "SerializedUserSkill")]
+pub struct UserSkill {
+  /// The name of the skill.
+  pub name: String,
+  /// Either 'learning', 'intermediate' or 'expert'. Skills listed before proficiency levels
+  /// existed have none.
+  pub proficiency: Option<String>,
+  /// An optional number of years of experience with the skill.
+  pub years_of_experience: Option<u8>,
+}
+
+/// The serialized forms of a user skill. Profiles created before proficiency levels existed
+/// list their skills as plain names.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SerializedUserSkill {
+  Name(String),
+  Detailed {
+    name: String,
+    proficiency: Option<String>,
+    years_of_experience: Option<u8>,
+  },
+}
+
+impl From<SerializedUserSkill> for UserSkill {
+  fn from(skill: SerializedUserSkill) -> Self {
+    match skill {
+      SerializedUserSkill::Name(name) => Self {
+        name,
+        proficiency: None,
+        years_of_experience: None,
+      },
+      SerializedUserSkill::Detailed {
+        name,
+        proficiency,
+        years_of_experience,
+      } => Self {
+        name,
+        proficiency,
+        years_of_experience,
+      },
+    }
+  }
+}
+
+pub enum ProficiencyLevel {
+  Learning,
+  Intermediate,
+  Expert,
+}
+
+impl Display for ProficiencyLevel {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Learning => write!(f, "learning"),
+      Self::Intermediate => write!(f, "intermediate"),
+      Self::Expert => write!(f, "expert"),
+    }
+  }
+}
+
+impl FromStr for ProficiencyLevel {
+  type Err = ();
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "learning" => Ok(Self::Learning),
+      "intermediate" => Ok(Self::Intermediate),
+      "expert" => Ok(Self::Expert),
+      _ => Err(()),
+    }
+  }
+}
+
 enum AllowedTypes {
   Advocate,
   Creator,
@@ -71,6 +155,31 @@ pub fn validate_user(user: User) -> ExternResult<ValidateCallbackResult> {
     )));
   };
 
+  if UserTimeZone::from_str(&user.time_zone).is_err() {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "User time zone must be an IANA name such as 'Europe/Paris' or an offset from UTC such as 'UTC+02:00'",
+    )));
+  }
+
+  for skill in &user.skills {
+    if skill.name.trim().is_empty() {
+      return Ok(ValidateCallbackResult::Invalid(String::from(
+        "Skill name cannot be empty",
+      )));
+    }
+
+    if let Some(proficiency) = &skill.proficiency {
+      if ProficiencyLevel::from_str(proficiency).is_err() {
+        return Ok(ValidateCallbackResult::Invalid(format!(
+          "Skill proficiency must be '{}', '{}' or '{}'.",
+          ProficiencyLevel::Learning,
+          ProficiencyLevel::Intermediate,
+          ProficiencyLevel::Expert,
+        )));
+      }
+    }
+  }
+
   if let Some(bytes) = user.picture {
     if !is_image(bytes) {
       return Ok(ValidateCallbackResult::Invalid(String::from(
@@ -150,3 +259,69 @@ pub fn validate_delete_link_user_updates(
     "UserUpdates links cannot be deleted",
   )))
 }
+
+/// Checks that a `UserAgents` link is created by the agent it targets, who must also be the
+/// author of the user profile.
+pub fn validate_create_link_user_agents(
+  action: CreateLink,
+  base_address: AnyLinkableHash,
+  target_address: AnyLinkableHash,
+  _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+  let action_hash = base_address
+    .into_action_hash()
+    .ok_or(UtilsError::ActionHashNotFound("user"))?;
+  let record = must_get_valid_record(action_hash)?;
+  let _user: crate::User = record
+    .entry()
+    .to_app_option()
+    .map_err(|e| wasm_error!(e))?
+    .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+      "Linked action must reference an entry"
+    ))))?;
+  if !matches!(record.action(), Action::Create(_)) {
+    return Ok(ValidateCallbackResult::Invalid(String::from(
+      "A UserAgents link must start from the original User profile",
+    )));
+  }
+
+  Ok(check_user_agent_link(
+    record.action().author(),
+    target_address.into_agent_pub_key().as_ref(),
+    &action.author,
+  ))
+}
+
+/// Checks the author of a `UserAgents` link against the agent it targets and the author of the
+/// user profile it starts from.
+pub fn check_user_agent_link(
+  user_author: &AgentPubKey,
+  target_agent: Option<&AgentPubKey>,
+  author: &AgentPubKey,
+) -> ValidateCallbackResult {
+  if target_agent != Some(author) {
+    return ValidateCallbackResult::Invalid(String::from(
+      "A UserAgents link must target the agent creating it",
+    ));
+  }
+
+  if user_author != author {
+    return ValidateCallbackResult::Invalid(String::from(
+      "Only the author of a User profile can link agents to it",
+    ));
+  }
+
+  ValidateCallbackResult::Valid
+}
+
+pub fn validate_delete_link_user_agents(
+  _action: DeleteLink,
+  _original_action: CreateLink,
+  _base: AnyLinkableHash,
+  _target: AnyLinkableHash,
+  _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+  Ok(ValidateCallbackResult::Invalid(String::from(
+    "UserAgents links cannot be deleted",
+  )))
+}
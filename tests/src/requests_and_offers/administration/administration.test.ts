@@ -28,7 +28,16 @@ import {
   updateEntityStatus,
   getAllRevisionsForStatus,
   AdministrationEntity,
+  registerEntityExpiry,
+  sweepExpiredEntities,
+  deleteStatus,
 } from "./common";
+import {
+  createOrganization,
+  getAcceptedOrganizationsLinks,
+  getLatestOrganizationRecord,
+  sampleOrganization,
+} from "../organizations/common";
 
 test("create a User, register administrator and remove administrator", async () => {
   await runScenarioWithTwoAgents(async (scenario, alice, bob) => {
@@ -345,3 +354,188 @@ test.only("update User status", async () => {
     assert.equal(suspensionHistory.length, 5);
   });
 });
+
+test("register an entity expiry and sweep expired entities", async () => {
+  await runScenarioWithTwoAgents(async (_scenario, alice, bob) => {
+    await createUser(alice.cells[0], sampleUser({ name: "Alice" }));
+    await createUser(bob.cells[0], sampleUser({ name: "Bob" }));
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    const aliceUserLink = (
+      await getAgentUser(alice.cells[0], alice.agentPubKey)
+    )[0];
+    const bobUserLink = (await getAgentUser(alice.cells[0], bob.agentPubKey))[0];
+
+    await registerNetworkAdministrator(alice.cells[0], aliceUserLink.target, [
+      alice.agentPubKey,
+    ]);
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    // Alice accepts Bob
+    const bobStatusOriginalActionHash = (
+      await getUserStatusLink(alice.cells[0], bobUserLink.target)
+    ).target;
+    const bobLatestStatusRecord = await getLatestStatusRecordForEntity(
+      alice.cells[0],
+      AdministrationEntity.Users,
+      bobUserLink.target
+    );
+
+    await updateEntityStatus(
+      alice.cells[0],
+      AdministrationEntity.Users,
+      bobUserLink.target,
+      bobStatusOriginalActionHash,
+      bobLatestStatusRecord.signed_action.hashed.hash,
+      { status_type: "accepted" }
+    );
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+    assert.lengthOf(await getAcceptedUsersLinks(bob.cells[0]), 1);
+
+    // Bob tries to set an expiry on Alice's profile
+    const oneDayInMicros = 24 * 60 * 60 * 1000 * 1000;
+    const yesterday = Date.now() * 1000 - oneDayInMicros;
+    await expect(
+      registerEntityExpiry(
+        bob.cells[0],
+        AdministrationEntity.Users,
+        aliceUserLink.target,
+        yesterday
+      )
+    ).rejects.toThrow();
+
+    // Bob's profile expired yesterday and another one expires tomorrow
+    await registerEntityExpiry(
+      bob.cells[0],
+      AdministrationEntity.Users,
+      bobUserLink.target,
+      yesterday
+    );
+    await registerEntityExpiry(
+      alice.cells[0],
+      AdministrationEntity.Users,
+      aliceUserLink.target,
+      yesterday + 2 * oneDayInMicros
+    );
+
+    // Alice's profile also expired yesterday, but it was never accepted
+    await registerEntityExpiry(
+      alice.cells[0],
+      AdministrationEntity.Users,
+      aliceUserLink.target,
+      yesterday
+    );
+
+    await dhtSync([alice, bob], bob.cells[0].cell_id[0]);
+
+    // Bob is not an administrator and cannot run the sweep
+    await expect(sweepExpiredEntities(bob.cells[0])).rejects.toThrow();
+
+    // Alice runs the sweep, which only expires accepted profiles
+    const expiredEntities = await sweepExpiredEntities(alice.cells[0]);
+    assert.lengthOf(expiredEntities, 1);
+    assert.deepEqual(expiredEntities[0], bobUserLink.target);
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    const bobStatus = await getLatestStatusForEntity(
+      alice.cells[0],
+      AdministrationEntity.Users,
+      bobUserLink.target
+    );
+    assert.equal(bobStatus.status_type, "expired");
+    assert.lengthOf(await getAcceptedUsersLinks(alice.cells[0]), 0);
+
+    const aliceStatus = await getLatestStatusForEntity(
+      bob.cells[0],
+      AdministrationEntity.Users,
+      aliceUserLink.target
+    );
+    assert.equal(aliceStatus.status_type, "pending");
+
+    // A second sweep has nothing left to expire
+    assert.lengthOf(await sweepExpiredEntities(alice.cells[0]), 0);
+  });
+});
+
+test("administrators delete the Status of an organization without deleting it", async () => {
+  await runScenarioWithTwoAgents(async (_scenario, alice, bob) => {
+    await createUser(alice.cells[0], sampleUser({ name: "Alice" }));
+    await createUser(bob.cells[0], sampleUser({ name: "Bob" }));
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    const aliceUserLink = (
+      await getAgentUser(alice.cells[0], alice.agentPubKey)
+    )[0];
+    await registerNetworkAdministrator(alice.cells[0], aliceUserLink.target, [
+      alice.agentPubKey,
+    ]);
+
+    // Bob creates an organization and Alice accepts it
+    const organizationRecord = await createOrganization(
+      bob.cells[0],
+      sampleOrganization({ name: "Bob's Organization" })
+    );
+    const organizationOriginalActionHash =
+      organizationRecord.signed_action.hashed.hash;
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    const statusRecord = await getLatestStatusRecordForEntity(
+      alice.cells[0],
+      AdministrationEntity.Organizations,
+      organizationOriginalActionHash
+    );
+    await updateEntityStatus(
+      alice.cells[0],
+      AdministrationEntity.Organizations,
+      organizationOriginalActionHash,
+      statusRecord.signed_action.hashed.hash,
+      statusRecord.signed_action.hashed.hash,
+      { status_type: "accepted" }
+    );
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+    assert.lengthOf(await getAcceptedOrganizationsLinks(alice.cells[0]), 1);
+
+    // Bob is not an administrator
+    await expect(
+      deleteStatus(
+        bob.cells[0],
+        AdministrationEntity.Organizations,
+        organizationOriginalActionHash
+      )
+    ).rejects.toThrow();
+
+    assert.ok(
+      await deleteStatus(
+        alice.cells[0],
+        AdministrationEntity.Organizations,
+        organizationOriginalActionHash
+      )
+    );
+
+    await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+    assert.isNull(
+      await getLatestStatusForEntity(
+        alice.cells[0],
+        AdministrationEntity.Organizations,
+        organizationOriginalActionHash
+      )
+    );
+    assert.lengthOf(await getAcceptedOrganizationsLinks(alice.cells[0]), 0);
+
+    // The organization itself is left to its coordinators
+    assert.ok(
+      await getLatestOrganizationRecord(
+        alice.cells[0],
+        organizationOriginalActionHash
+      )
+    );
+  });
+});
@@ -6,7 +6,8 @@ export type StatusType =
   | "accepted"
   | "rejected"
   | "suspended temporarily"
-  | "suspended indefinitely";
+  | "suspended indefinitely"
+  | "expired";
 
 export enum AdministrationEntity {
   Network = "network",
@@ -97,6 +98,62 @@ export async function removeAdministrator(
   });
 }
 
+export async function addMatchmaker(
+  cell: CallableCell,
+  entity_original_action_hash: ActionHash,
+  agent_pubkeys: AgentPubKey[]
+): Promise<boolean> {
+  return cell.callZome({
+    zome_name: "administration",
+    fn_name: "add_matchmaker",
+    payload: {
+      entity: AdministrationEntity.Network,
+      entity_original_action_hash,
+      agent_pubkeys,
+    },
+  });
+}
+
+export async function getAllMatchmakersLinks(
+  cell: CallableCell
+): Promise<Link[]> {
+  return cell.callZome({
+    zome_name: "administration",
+    fn_name: "get_all_matchmakers_links",
+    payload: "network",
+  });
+}
+
+export async function checkIfAgentIsMatchmaker(
+  cell: CallableCell,
+  agent_pubkey: AgentPubKey
+): Promise<boolean> {
+  return cell.callZome({
+    zome_name: "administration",
+    fn_name: "check_if_agent_is_matchmaker",
+    payload: {
+      entity: AdministrationEntity.Network,
+      agent_pubkey,
+    },
+  });
+}
+
+export async function removeMatchmaker(
+  cell: CallableCell,
+  entity_original_action_hash: ActionHash,
+  agent_pubkeys: AgentPubKey[]
+): Promise<boolean> {
+  return cell.callZome({
+    zome_name: "administration",
+    fn_name: "remove_matchmaker",
+    payload: {
+      entity: AdministrationEntity.Network,
+      entity_original_action_hash,
+      agent_pubkeys,
+    },
+  });
+}
+
 export async function getLatestStatusRecordForEntity(
   cell: CallableCell,
   entity: AdministrationEntity,
@@ -240,3 +297,45 @@ export async function getAllRevisionsForStatus(
     payload: status_original_action_hash,
   });
 }
+
+export async function registerEntityExpiry(
+  cell: CallableCell,
+  entity: AdministrationEntity,
+  entity_original_action_hash: ActionHash,
+  expires_at: number
+): Promise<boolean> {
+  return cell.callZome({
+    zome_name: "administration",
+    fn_name: "register_entity_expiry",
+    payload: {
+      entity,
+      entity_original_action_hash,
+      expires_at,
+    },
+  });
+}
+
+export async function sweepExpiredEntities(
+  cell: CallableCell
+): Promise<ActionHash[]> {
+  return cell.callZome({
+    zome_name: "administration",
+    fn_name: "sweep_expired_entities",
+    payload: null,
+  });
+}
+
+export async function deleteStatus(
+  cell: CallableCell,
+  entity: AdministrationEntity,
+  entity_original_action_hash: ActionHash
+): Promise<boolean> {
+  return cell.callZome({
+    zome_name: "administration",
+    fn_name: "delete_status",
+    payload: {
+      entity,
+      entity_original_action_hash,
+    },
+  });
+}
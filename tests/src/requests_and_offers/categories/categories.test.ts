@@ -0,0 +1,122 @@
+import { assert, expect, test } from "vitest";
+
+import { Scenario, Player, dhtSync } from "@holochain/tryorama";
+import { Record } from "@holochain/client";
+
+import { runScenarioWithTwoAgents } from "../utils.js";
+import { createUser, sampleUser } from "../users/common";
+import { registerNetworkAdministrator } from "../administration/common";
+import {
+  archiveCategory,
+  createCategory,
+  getAllCategoriesLinks,
+  getCategoryByPath,
+  getCategoryChildren,
+  getCategoryPath,
+  getLatestCategory,
+  renameCategory,
+} from "./common";
+
+test("administrators manage a tree of categories", async () => {
+  await runScenarioWithTwoAgents(
+    async (_scenario: Scenario, alice: Player, bob: Player) => {
+      const aliceUserRecord: Record = await createUser(
+        alice.cells[0],
+        sampleUser({ name: "Alice" })
+      );
+      await registerNetworkAdministrator(
+        alice.cells[0],
+        aliceUserRecord.signed_action.hashed.hash,
+        [alice.agentPubKey]
+      );
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      // Only administrators can create categories
+      await expect(
+        createCategory(bob.cells[0], { name: "Development" })
+      ).rejects.toThrow();
+
+      const developmentRecord = await createCategory(alice.cells[0], {
+        name: "Development",
+      });
+      const developmentHash = developmentRecord.signed_action.hashed.hash;
+      const testingRecord = await createCategory(alice.cells[0], {
+        name: "Testing",
+        parent: developmentHash,
+      });
+      const testingHash = testingRecord.signed_action.hashed.hash;
+
+      // Names must be unique among siblings
+      await expect(
+        createCategory(alice.cells[0], {
+          name: "testing",
+          parent: developmentHash,
+        })
+      ).rejects.toThrow();
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      assert.lengthOf(await getAllCategoriesLinks(bob.cells[0]), 2);
+      const roots = await getCategoryChildren(bob.cells[0], null);
+      assert.lengthOf(roots, 1);
+      assert.deepEqual(roots[0].target, developmentHash);
+      const children = await getCategoryChildren(bob.cells[0], developmentHash);
+      assert.lengthOf(children, 1);
+      assert.deepEqual(children[0].target, testingHash);
+
+      assert.equal(
+        await getCategoryPath(bob.cells[0], testingHash),
+        "development.testing"
+      );
+      const testingLink = await getCategoryByPath(
+        bob.cells[0],
+        "development.testing"
+      );
+      assert.deepEqual(testingLink!.target, testingHash);
+
+      // Renaming a category moves its descendants in the path tree
+      const renamedRecord = await renameCategory(
+        alice.cells[0],
+        developmentHash,
+        developmentHash,
+        "Software Development"
+      );
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      assert.isNull(
+        await getCategoryByPath(bob.cells[0], "development.testing")
+      );
+      const movedLink = await getCategoryByPath(
+        bob.cells[0],
+        "software-development.testing"
+      );
+      assert.deepEqual(movedLink!.target, testingHash);
+
+      // Archived categories are hidden from navigation
+      await archiveCategory(
+        alice.cells[0],
+        developmentHash,
+        renamedRecord.signed_action.hashed.hash
+      );
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      assert.lengthOf(await getCategoryChildren(bob.cells[0], null), 0);
+      const development = await getLatestCategory(
+        bob.cells[0],
+        developmentHash
+      );
+      assert.equal(development.name, "Software Development");
+      assert.isTrue(development.archived);
+
+      await expect(
+        createCategory(alice.cells[0], {
+          name: "Linting",
+          parent: developmentHash,
+        })
+      ).rejects.toThrow();
+    }
+  );
+});
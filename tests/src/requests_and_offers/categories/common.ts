@@ -0,0 +1,102 @@
+import { CallableCell } from "@holochain/tryorama";
+import { ActionHash, Record, Link } from "@holochain/client";
+
+export type Category = {
+  name: string;
+  parent?: ActionHash;
+  archived: boolean;
+  administrator_link?: ActionHash;
+};
+
+export type CategoryInput = {
+  name: string;
+  parent?: ActionHash;
+};
+
+export function createCategory(
+  cell: CallableCell,
+  input: CategoryInput
+): Promise<Record> {
+  return cell.callZome({
+    zome_name: "categories",
+    fn_name: "create_category",
+    payload: input,
+  });
+}
+
+export function getLatestCategory(
+  cell: CallableCell,
+  original_action_hash: ActionHash
+): Promise<Category> {
+  return cell.callZome({
+    zome_name: "categories",
+    fn_name: "get_latest_category",
+    payload: original_action_hash,
+  });
+}
+
+export function getAllCategoriesLinks(cell: CallableCell): Promise<Link[]> {
+  return cell.callZome({
+    zome_name: "categories",
+    fn_name: "get_all_categories_links",
+    payload: null,
+  });
+}
+
+export function getCategoryChildren(
+  cell: CallableCell,
+  parent: ActionHash | null
+): Promise<Link[]> {
+  return cell.callZome({
+    zome_name: "categories",
+    fn_name: "get_category_children",
+    payload: parent,
+  });
+}
+
+export function getCategoryPath(
+  cell: CallableCell,
+  original_action_hash: ActionHash
+): Promise<string> {
+  return cell.callZome({
+    zome_name: "categories",
+    fn_name: "get_category_path",
+    payload: original_action_hash,
+  });
+}
+
+export function getCategoryByPath(
+  cell: CallableCell,
+  category_path: string
+): Promise<Link | null> {
+  return cell.callZome({
+    zome_name: "categories",
+    fn_name: "get_category_by_path",
+    payload: category_path,
+  });
+}
+
+export function renameCategory(
+  cell: CallableCell,
+  original_action_hash: ActionHash,
+  previous_action_hash: ActionHash,
+  name: string
+): Promise<Record> {
+  return cell.callZome({
+    zome_name: "categories",
+    fn_name: "rename_category",
+    payload: { original_action_hash, previous_action_hash, name },
+  });
+}
+
+export function archiveCategory(
+  cell: CallableCell,
+  original_action_hash: ActionHash,
+  previous_action_hash: ActionHash
+): Promise<Record> {
+  return cell.callZome({
+    zome_name: "categories",
+    fn_name: "archive_category",
+    payload: { original_action_hash, previous_action_hash },
+  });
+}
@@ -0,0 +1,92 @@
+import { CallableCell } from "@holochain/tryorama";
+import { ActionHash, Record, Link } from "@holochain/client";
+
+export type ExchangeStatus = "proposed" | "agreed" | "completed";
+
+export type Exchange = {
+  provider: ActionHash;
+  receiver: ActionHash;
+  terms: string;
+  reference?: ActionHash;
+  provider_confirmed: boolean;
+  receiver_confirmed: boolean;
+  provider_completed: boolean;
+  receiver_completed: boolean;
+  status: ExchangeStatus;
+  party_agent_link: ActionHash;
+};
+
+export type ExchangeInput = {
+  provider: ActionHash;
+  receiver: ActionHash;
+  terms: string;
+  reference?: ActionHash;
+};
+
+export function createExchange(
+  cell: CallableCell,
+  input: ExchangeInput
+): Promise<Record> {
+  return cell.callZome({
+    zome_name: "exchanges",
+    fn_name: "create_exchange",
+    payload: input,
+  });
+}
+
+export function getLatestExchange(
+  cell: CallableCell,
+  original_action_hash: ActionHash
+): Promise<Exchange> {
+  return cell.callZome({
+    zome_name: "exchanges",
+    fn_name: "get_latest_exchange",
+    payload: original_action_hash,
+  });
+}
+
+export function getLatestExchangeRecord(
+  cell: CallableCell,
+  original_action_hash: ActionHash
+): Promise<Record | null> {
+  return cell.callZome({
+    zome_name: "exchanges",
+    fn_name: "get_latest_exchange_record",
+    payload: original_action_hash,
+  });
+}
+
+export function getUserExchangesLinks(
+  cell: CallableCell,
+  user_original_action_hash: ActionHash
+): Promise<Link[]> {
+  return cell.callZome({
+    zome_name: "exchanges",
+    fn_name: "get_user_exchanges_links",
+    payload: user_original_action_hash,
+  });
+}
+
+export function confirmExchange(
+  cell: CallableCell,
+  original_action_hash: ActionHash,
+  previous_action_hash: ActionHash
+): Promise<Record> {
+  return cell.callZome({
+    zome_name: "exchanges",
+    fn_name: "confirm_exchange",
+    payload: { original_action_hash, previous_action_hash },
+  });
+}
+
+export function completeExchange(
+  cell: CallableCell,
+  original_action_hash: ActionHash,
+  previous_action_hash: ActionHash
+): Promise<Record> {
+  return cell.callZome({
+    zome_name: "exchanges",
+    fn_name: "complete_exchange",
+    payload: { original_action_hash, previous_action_hash },
+  });
+}
@@ -0,0 +1,100 @@
+import { assert, expect, test } from "vitest";
+
+import { Scenario, Player, dhtSync } from "@holochain/tryorama";
+import { ActionHash, Record } from "@holochain/client";
+
+import { runScenarioWithTwoAgents } from "../utils.js";
+import { createUser, sampleUser } from "../users/common";
+import {
+  completeExchange,
+  confirmExchange,
+  createExchange,
+  getLatestExchange,
+  getLatestExchangeRecord,
+  getUserExchangesLinks,
+} from "./common";
+
+test("bilateral confirmation of an Exchange", async () => {
+  await runScenarioWithTwoAgents(
+    async (_scenario: Scenario, alice: Player, bob: Player) => {
+      const aliceUserRecord: Record = await createUser(
+        alice.cells[0],
+        sampleUser({ name: "Alice" })
+      );
+      const bobUserRecord: Record = await createUser(
+        bob.cells[0],
+        sampleUser({ name: "Bob" })
+      );
+      const aliceUserHash = aliceUserRecord.signed_action.hashed.hash;
+      const bobUserHash = bobUserRecord.signed_action.hashed.hash;
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      // Alice proposes an Exchange as the provider
+      const exchangeRecord: Record = await createExchange(alice.cells[0], {
+        provider: aliceUserHash,
+        receiver: bobUserHash,
+        terms: "Two hours of code review",
+      });
+      assert.ok(exchangeRecord);
+      const exchangeHash = exchangeRecord.signed_action.hashed.hash;
+
+      let exchange = await getLatestExchange(alice.cells[0], exchangeHash);
+      assert.equal(exchange.status, "proposed");
+      assert.isTrue(exchange.provider_confirmed);
+      assert.isFalse(exchange.receiver_confirmed);
+
+      // Alice cannot confirm twice nor mark it as done before Bob agrees
+      await expect(
+        confirmExchange(alice.cells[0], exchangeHash, exchangeHash)
+      ).rejects.toThrow();
+      await expect(
+        completeExchange(alice.cells[0], exchangeHash, exchangeHash)
+      ).rejects.toThrow();
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      const links = await getUserExchangesLinks(bob.cells[0], bobUserHash);
+      assert.lengthOf(links, 1);
+
+      // Bob confirms the Exchange, which becomes agreed
+      let record = await confirmExchange(
+        bob.cells[0],
+        exchangeHash,
+        exchangeHash
+      );
+      let previousHash: ActionHash = record.signed_action.hashed.hash;
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      exchange = await getLatestExchange(alice.cells[0], exchangeHash);
+      assert.equal(exchange.status, "agreed");
+
+      // Both parties mark the Exchange as done
+      record = await completeExchange(
+        alice.cells[0],
+        exchangeHash,
+        previousHash
+      );
+      previousHash = record.signed_action.hashed.hash;
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      exchange = await getLatestExchange(bob.cells[0], exchangeHash);
+      assert.equal(exchange.status, "agreed");
+
+      const latestRecord = await getLatestExchangeRecord(
+        bob.cells[0],
+        exchangeHash
+      );
+      assert.deepEqual(latestRecord?.signed_action.hashed.hash, previousHash);
+
+      await completeExchange(bob.cells[0], exchangeHash, previousHash);
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      exchange = await getLatestExchange(alice.cells[0], exchangeHash);
+      assert.equal(exchange.status, "completed");
+    }
+  );
+});
@@ -0,0 +1,90 @@
+import { CallableCell } from "@holochain/tryorama";
+import { ActionHash, Record, Link } from "@holochain/client";
+
+export type IntroductionDecision = "pending" | "accepted" | "declined";
+
+export type Introduction = {
+  matchmaker: ActionHash;
+  first_user: ActionHash;
+  second_user: ActionHash;
+  note: string;
+  first_user_decision: IntroductionDecision;
+  second_user_decision: IntroductionDecision;
+  matchmaker_link: ActionHash;
+  first_user_agent_link: ActionHash | null;
+  second_user_agent_link: ActionHash | null;
+};
+
+export type IntroductionInput = {
+  first_user: ActionHash;
+  second_user: ActionHash;
+  note: string;
+};
+
+export function createIntroduction(
+  cell: CallableCell,
+  input: IntroductionInput
+): Promise<Record> {
+  return cell.callZome({
+    zome_name: "introductions",
+    fn_name: "create_introduction",
+    payload: input,
+  });
+}
+
+export function getLatestIntroduction(
+  cell: CallableCell,
+  original_action_hash: ActionHash
+): Promise<Introduction> {
+  return cell.callZome({
+    zome_name: "introductions",
+    fn_name: "get_latest_introduction",
+    payload: original_action_hash,
+  });
+}
+
+export function getLatestIntroductionRecord(
+  cell: CallableCell,
+  original_action_hash: ActionHash
+): Promise<Record | null> {
+  return cell.callZome({
+    zome_name: "introductions",
+    fn_name: "get_latest_introduction_record",
+    payload: original_action_hash,
+  });
+}
+
+export function getUserIntroductionsLinks(
+  cell: CallableCell,
+  user_original_action_hash: ActionHash
+): Promise<Link[]> {
+  return cell.callZome({
+    zome_name: "introductions",
+    fn_name: "get_user_introductions_links",
+    payload: user_original_action_hash,
+  });
+}
+
+export function acceptIntroduction(
+  cell: CallableCell,
+  original_action_hash: ActionHash,
+  previous_action_hash: ActionHash
+): Promise<Record> {
+  return cell.callZome({
+    zome_name: "introductions",
+    fn_name: "accept_introduction",
+    payload: { original_action_hash, previous_action_hash },
+  });
+}
+
+export function declineIntroduction(
+  cell: CallableCell,
+  original_action_hash: ActionHash,
+  previous_action_hash: ActionHash
+): Promise<Record> {
+  return cell.callZome({
+    zome_name: "introductions",
+    fn_name: "decline_introduction",
+    payload: { original_action_hash, previous_action_hash },
+  });
+}
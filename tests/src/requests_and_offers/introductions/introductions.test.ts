@@ -0,0 +1,137 @@
+import { assert, expect, test } from "vitest";
+
+import { Scenario, Player, dhtSync } from "@holochain/tryorama";
+import { Record } from "@holochain/client";
+
+import { runScenarioWithTwoAgents } from "../utils.js";
+import { createUser, sampleUser } from "../users/common";
+import {
+  addMatchmaker,
+  checkIfAgentIsMatchmaker,
+  registerNetworkAdministrator,
+} from "../administration/common";
+import {
+  acceptIntroduction,
+  createIntroduction,
+  declineIntroduction,
+  getLatestIntroduction,
+  getUserIntroductionsLinks,
+} from "./common";
+
+const hAppPath = process.cwd() + "/../workdir/requests_and_offers.happ";
+const appSource = { appBundleSource: { path: hAppPath } };
+
+test("a matchmaker introduces two users who each decide", async () => {
+  await runScenarioWithTwoAgents(
+    async (scenario: Scenario, alice: Player, bob: Player) => {
+      const [carol] = await scenario.addPlayersWithApps([appSource]);
+      await scenario.shareAllAgents();
+      const players = [alice, bob, carol];
+
+      const aliceUserRecord: Record = await createUser(
+        alice.cells[0],
+        sampleUser({ name: "Alice" })
+      );
+      const bobUserRecord: Record = await createUser(
+        bob.cells[0],
+        sampleUser({ name: "Bob" })
+      );
+      const carolUserRecord: Record = await createUser(
+        carol.cells[0],
+        sampleUser({ name: "Carol" })
+      );
+      const aliceUserHash = aliceUserRecord.signed_action.hashed.hash;
+      const bobUserHash = bobUserRecord.signed_action.hashed.hash;
+      const carolUserHash = carolUserRecord.signed_action.hashed.hash;
+
+      await registerNetworkAdministrator(alice.cells[0], aliceUserHash, [
+        alice.agentPubKey,
+      ]);
+
+      await dhtSync(players, alice.cells[0].cell_id[0]);
+
+      // Only administrators can make someone a matchmaker
+      await expect(
+        addMatchmaker(bob.cells[0], bobUserHash, [bob.agentPubKey])
+      ).rejects.toThrow();
+
+      await addMatchmaker(alice.cells[0], aliceUserHash, [alice.agentPubKey]);
+
+      await dhtSync(players, alice.cells[0].cell_id[0]);
+
+      assert.isTrue(
+        await checkIfAgentIsMatchmaker(bob.cells[0], alice.agentPubKey)
+      );
+      assert.isFalse(
+        await checkIfAgentIsMatchmaker(alice.cells[0], bob.agentPubKey)
+      );
+
+      // Only matchmakers can introduce users
+      await expect(
+        createIntroduction(bob.cells[0], {
+          first_user: aliceUserHash,
+          second_user: carolUserHash,
+          note: "You should meet",
+        })
+      ).rejects.toThrow();
+
+      const introductionRecord: Record = await createIntroduction(
+        alice.cells[0],
+        {
+          first_user: bobUserHash,
+          second_user: carolUserHash,
+          note: "You both work on community gardens",
+        }
+      );
+      assert.ok(introductionRecord);
+      const introductionHash = introductionRecord.signed_action.hashed.hash;
+
+      await dhtSync(players, alice.cells[0].cell_id[0]);
+
+      const links = await getUserIntroductionsLinks(bob.cells[0], bobUserHash);
+      assert.lengthOf(links, 1);
+
+      // The matchmaker cannot decide for the introduced users
+      await expect(
+        acceptIntroduction(alice.cells[0], introductionHash, introductionHash)
+      ).rejects.toThrow();
+
+      const bobDecision = await acceptIntroduction(
+        bob.cells[0],
+        introductionHash,
+        introductionHash
+      );
+
+      await dhtSync(players, alice.cells[0].cell_id[0]);
+
+      // Bob cannot change his mind
+      await expect(
+        declineIntroduction(
+          bob.cells[0],
+          introductionHash,
+          bobDecision.signed_action.hashed.hash
+        )
+      ).rejects.toThrow();
+
+      await declineIntroduction(
+        carol.cells[0],
+        introductionHash,
+        bobDecision.signed_action.hashed.hash
+      );
+
+      await dhtSync(players, alice.cells[0].cell_id[0]);
+
+      // Both decisions are visible to the matchmaker
+      const introduction = await getLatestIntroduction(
+        alice.cells[0],
+        introductionHash
+      );
+      assert.deepEqual(introduction.matchmaker, aliceUserHash);
+      assert.equal(introduction.first_user_decision, "accepted");
+      assert.equal(introduction.second_user_decision, "declined");
+      // Each decision cites the UserAgents link of the deciding user
+      assert.ok(introduction.first_user_agent_link);
+      assert.ok(introduction.second_user_agent_link);
+    }
+  );
+});
@@ -0,0 +1,65 @@
+import { CallableCell } from "@holochain/tryorama";
+import { ActionHash, Record, Link } from "@holochain/client";
+
+export type JointRecord = {
+  first_user: ActionHash;
+  first_user_agent_link: ActionHash;
+  second_user: ActionHash;
+  second_user_agent_link: ActionHash;
+  content: string;
+  reference?: ActionHash;
+};
+
+export function createJointRecord(
+  cell: CallableCell,
+  other_user: ActionHash,
+  content: string,
+  reference?: ActionHash
+): Promise<Record> {
+  return cell.callZome({
+    zome_name: "joint_records",
+    fn_name: "create_joint_record",
+    payload: { other_user, content, reference },
+  });
+}
+
+export function getJointRecord(
+  cell: CallableCell,
+  joint_record_hash: ActionHash
+): Promise<JointRecord | null> {
+  return cell.callZome({
+    zome_name: "joint_records",
+    fn_name: "get_joint_record",
+    payload: joint_record_hash,
+  });
+}
+
+export function indexJointRecord(
+  cell: CallableCell,
+  joint_record_hash: ActionHash
+): Promise<boolean> {
+  return cell.callZome({
+    zome_name: "joint_records",
+    fn_name: "index_joint_record",
+    payload: joint_record_hash,
+  });
+}
+
+export function getUserJointRecordsLinks(
+  cell: CallableCell,
+  user_original_action_hash: ActionHash
+): Promise<Link[]> {
+  return cell.callZome({
+    zome_name: "joint_records",
+    fn_name: "get_user_joint_records_links",
+    payload: user_original_action_hash,
+  });
+}
+
+export function getMyJointRecords(cell: CallableCell): Promise<Record[]> {
+  return cell.callZome({
+    zome_name: "joint_records",
+    fn_name: "get_my_joint_records",
+    payload: null,
+  });
+}
@@ -0,0 +1,69 @@
+import { assert, expect, test } from "vitest";
+
+import { Scenario, Player, dhtSync } from "@holochain/tryorama";
+import { Record } from "@holochain/client";
+
+import { runScenarioWithTwoAgents } from "../utils.js";
+import { createUser, sampleUser } from "../users/common";
+import {
+  createJointRecord,
+  getJointRecord,
+  getMyJointRecords,
+  getUserJointRecordsLinks,
+  indexJointRecord,
+} from "./common";
+
+test("countersign a JointRecord between two users", async () => {
+  await runScenarioWithTwoAgents(
+    async (_scenario: Scenario, alice: Player, bob: Player) => {
+      const aliceUserRecord: Record = await createUser(
+        alice.cells[0],
+        sampleUser({ name: "Alice" })
+      );
+      const bobUserRecord: Record = await createUser(
+        bob.cells[0],
+        sampleUser({ name: "Bob" })
+      );
+      const aliceUserHash = aliceUserRecord.signed_action.hashed.hash;
+      const bobUserHash = bobUserRecord.signed_action.hashed.hash;
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      // Alice cannot create a JointRecord with herself
+      await expect(
+        createJointRecord(alice.cells[0], aliceUserHash, "Self agreement")
+      ).rejects.toThrow();
+
+      // Alice and Bob countersign a JointRecord
+      const jointRecord: Record = await createJointRecord(
+        alice.cells[0],
+        bobUserHash,
+        "The bike was handed over"
+      );
+      assert.ok(jointRecord);
+      const jointRecordHash = jointRecord.signed_action.hashed.hash;
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      // The JointRecord is on both source chains
+      assert.lengthOf(await getMyJointRecords(alice.cells[0]), 1);
+      assert.lengthOf(await getMyJointRecords(bob.cells[0]), 1);
+
+      const entry = await getJointRecord(bob.cells[0], jointRecordHash);
+      assert.equal(entry?.content, "The bike was handed over");
+      assert.deepEqual(entry?.first_user, aliceUserHash);
+      assert.deepEqual(entry?.second_user, bobUserHash);
+
+      // Both users index the JointRecord under their profile
+      await indexJointRecord(alice.cells[0], jointRecordHash);
+      await indexJointRecord(bob.cells[0], jointRecordHash);
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      let links = await getUserJointRecordsLinks(bob.cells[0], aliceUserHash);
+      assert.lengthOf(links, 1);
+      links = await getUserJointRecordsLinks(alice.cells[0], bobUserHash);
+      assert.lengthOf(links, 1);
+    }
+  );
+});
@@ -0,0 +1,86 @@
+import { CallableCell } from "@holochain/tryorama";
+import { ActionHash, Link, Record } from "@holochain/client";
+
+export type LedgerLine = {
+  user: ActionHash;
+  user_agent_link: ActionHash;
+  kind: "credit" | "debit";
+  minutes: number;
+};
+
+export type HourTransaction = {
+  lines: LedgerLine[];
+  description: string;
+  reference?: ActionHash;
+};
+
+export type UserLedger = {
+  balance_minutes: number;
+  transactions: Record[];
+};
+
+export function recordHours(
+  cell: CallableCell,
+  receiver: ActionHash,
+  minutes: number,
+  description: string,
+  reference?: ActionHash
+): Promise<Record> {
+  return cell.callZome({
+    zome_name: "ledger",
+    fn_name: "record_hours",
+    payload: { receiver, minutes, description, reference },
+  });
+}
+
+export function getHourTransaction(
+  cell: CallableCell,
+  hour_transaction_hash: ActionHash
+): Promise<HourTransaction | null> {
+  return cell.callZome({
+    zome_name: "ledger",
+    fn_name: "get_hour_transaction",
+    payload: hour_transaction_hash,
+  });
+}
+
+export function getMyHourTransactions(cell: CallableCell): Promise<Record[]> {
+  return cell.callZome({
+    zome_name: "ledger",
+    fn_name: "get_my_hour_transactions",
+    payload: null,
+  });
+}
+
+export function indexHourTransaction(
+  cell: CallableCell,
+  hour_transaction_hash: ActionHash
+): Promise<boolean> {
+  return cell.callZome({
+    zome_name: "ledger",
+    fn_name: "index_hour_transaction",
+    payload: hour_transaction_hash,
+  });
+}
+
+export function getUserHourTransactionsLinks(
+  cell: CallableCell,
+  user_original_action_hash: ActionHash
+): Promise<Link[]> {
+  return cell.callZome({
+    zome_name: "ledger",
+    fn_name: "get_user_hour_transactions_links",
+    payload: user_original_action_hash,
+  });
+}
+
+export function getUserLedger(
+  cell: CallableCell,
+  user_original_action_hash: ActionHash
+): Promise<UserLedger> {
+  return cell.callZome({
+    zome_name: "ledger",
+    fn_name: "get_user_ledger",
+    payload: user_original_action_hash,
+  });
+}
@@ -0,0 +1,138 @@
+import { assert, test } from "vitest";
+
+import { Scenario, Player, dhtSync } from "@holochain/tryorama";
+import { Record } from "@holochain/client";
+
+import { runScenarioWithTwoAgents } from "../utils.js";
+import { createUser, sampleUser } from "../users/common";
+import {
+  getHourTransaction,
+  getMyHourTransactions,
+  getUserHourTransactionsLinks,
+  getUserLedger,
+  indexHourTransaction,
+  recordHours,
+} from "./common";
+
+test("record hours between two users and compute their balances", async () => {
+  await runScenarioWithTwoAgents(
+    async (_scenario: Scenario, alice: Player, bob: Player) => {
+      const aliceUserRecord: Record = await createUser(
+        alice.cells[0],
+        sampleUser({ name: "Alice" })
+      );
+      const bobUserRecord: Record = await createUser(
+        bob.cells[0],
+        sampleUser({ name: "Bob" })
+      );
+      const aliceUserHash = aliceUserRecord.signed_action.hashed.hash;
+      const bobUserHash = bobUserRecord.signed_action.hashed.hash;
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      // Alice gives 90 minutes of garden help to Bob
+      const transactionRecord: Record = await recordHours(
+        alice.cells[0],
+        bobUserHash,
+        90,
+        "Garden help"
+      );
+      const transactionHash = transactionRecord.signed_action.hashed.hash;
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      const transaction = await getHourTransaction(
+        bob.cells[0],
+        transactionHash
+      );
+      assert.lengthOf(transaction!.lines, 2);
+
+      await indexHourTransaction(alice.cells[0], transactionHash);
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      // Bob indexes the same transaction with the action committed on his own chain
+      const bobTransactionRecords = await getMyHourTransactions(bob.cells[0]);
+      assert.lengthOf(bobTransactionRecords, 1);
+      const bobTransactionHash =
+        bobTransactionRecords[0].signed_action.hashed.hash;
+      assert.notDeepEqual(bobTransactionHash, transactionHash);
+      await indexHourTransaction(bob.cells[0], bobTransactionHash);
+
+      // Bob gives 30 minutes of tutoring back to Alice
+      const secondTransactionRecord: Record = await recordHours(
+        bob.cells[0],
+        aliceUserHash,
+        30,
+        "Tutoring"
+      );
+      await indexHourTransaction(
+        bob.cells[0],
+        secondTransactionRecord.signed_action.hashed.hash
+      );
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      const aliceLedger = await getUserLedger(bob.cells[0], aliceUserHash);
+      assert.equal(aliceLedger.balance_minutes, 60);
+      assert.lengthOf(aliceLedger.transactions, 2);
+
+      const bobLedger = await getUserLedger(alice.cells[0], bobUserHash);
+      assert.equal(bobLedger.balance_minutes, -60);
+      assert.lengthOf(bobLedger.transactions, 2);
+    }
+  );
+});
+
+test("index an hour transaction once in the ledger of each user", async () => {
+  await runScenarioWithTwoAgents(
+    async (_scenario: Scenario, alice: Player, bob: Player) => {
+      const aliceUserRecord: Record = await createUser(
+        alice.cells[0],
+        sampleUser({ name: "Alice" })
+      );
+      const bobUserRecord: Record = await createUser(
+        bob.cells[0],
+        sampleUser({ name: "Bob" })
+      );
+      const aliceUserHash = aliceUserRecord.signed_action.hashed.hash;
+      const bobUserHash = bobUserRecord.signed_action.hashed.hash;
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      const transactionRecord: Record = await recordHours(
+        alice.cells[0],
+        bobUserHash,
+        45,
+        "Bike repair"
+      );
+      const transactionHash = transactionRecord.signed_action.hashed.hash;
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      // Alice indexes the transaction twice
+      await indexHourTransaction(alice.cells[0], transactionHash);
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+      await indexHourTransaction(alice.cells[0], transactionHash);
+
+      // Bob indexes it again with his own action
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+      const bobTransactionRecords = await getMyHourTransactions(bob.cells[0]);
+      await indexHourTransaction(
+        bob.cells[0],
+        bobTransactionRecords[0].signed_action.hashed.hash
+      );
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      assert.lengthOf(
+        await getUserHourTransactionsLinks(bob.cells[0], aliceUserHash),
+        1
+      );
+      assert.lengthOf(
+        await getUserHourTransactionsLinks(alice.cells[0], bobUserHash),
+        1
+      );
+    }
+  );
+});
@@ -0,0 +1,62 @@
+import { CallableCell } from "@holochain/tryorama";
+import { ActionHash, AgentPubKey, Record } from "@holochain/client";
+
+export type CreditTransaction = {
+  spender: AgentPubKey;
+  recipient: AgentPubKey;
+  amount: number;
+  spender_balance: number;
+  recipient_balance: number;
+  description: string;
+};
+
+export function spendCredits(
+  cell: CallableCell,
+  recipient: AgentPubKey,
+  amount: number,
+  description: string
+): Promise<Record> {
+  return cell.callZome({
+    zome_name: "mutual_credit",
+    fn_name: "spend_credits",
+    payload: { recipient, amount, description },
+  });
+}
+
+export function getMyBalance(cell: CallableCell): Promise<number> {
+  return cell.callZome({
+    zome_name: "mutual_credit",
+    fn_name: "get_my_balance",
+    payload: null,
+  });
+}
+
+export function getCreditLimit(cell: CallableCell): Promise<number> {
+  return cell.callZome({
+    zome_name: "mutual_credit",
+    fn_name: "get_credit_limit",
+    payload: null,
+  });
+}
+
+export function indexCreditTransaction(
+  cell: CallableCell,
+  credit_transaction_hash: ActionHash
+): Promise<boolean> {
+  return cell.callZome({
+    zome_name: "mutual_credit",
+    fn_name: "index_credit_transaction",
+    payload: credit_transaction_hash,
+  });
+}
+
+export function getAgentBalance(
+  cell: CallableCell,
+  agent: AgentPubKey
+): Promise<number> {
+  return cell.callZome({
+    zome_name: "mutual_credit",
+    fn_name: "get_agent_balance",
+    payload: agent,
+  });
+}
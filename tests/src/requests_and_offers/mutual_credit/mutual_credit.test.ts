@@ -0,0 +1,53 @@
+import { assert, expect, test } from "vitest";
+
+import { Scenario, Player, dhtSync } from "@holochain/tryorama";
+import { Record } from "@holochain/client";
+
+import { runScenarioWithTwoAgents } from "../utils.js";
+import {
+  getAgentBalance,
+  getCreditLimit,
+  getMyBalance,
+  indexCreditTransaction,
+  spendCredits,
+} from "./common";
+
+test("spend mutual credits within the credit limit", async () => {
+  await runScenarioWithTwoAgents(
+    async (_scenario: Scenario, alice: Player, bob: Player) => {
+      const creditLimit = await getCreditLimit(alice.cells[0]);
+      assert.isAbove(creditLimit, 30);
+
+      // Alice spends 30 credits to Bob
+      const transactionRecord: Record = await spendCredits(
+        alice.cells[0],
+        bob.agentPubKey,
+        30,
+        "Bread"
+      );
+      await indexCreditTransaction(
+        alice.cells[0],
+        transactionRecord.signed_action.hashed.hash
+      );
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      assert.equal(await getMyBalance(alice.cells[0]), -30);
+      assert.equal(await getMyBalance(bob.cells[0]), 30);
+      assert.equal(await getAgentBalance(bob.cells[0], alice.agentPubKey), -30);
+
+      // Alice cannot go below the credit limit
+      await expect(
+        spendCredits(alice.cells[0], bob.agentPubKey, creditLimit, "Too much")
+      ).rejects.toThrow();
+
+      // Bob spends credits back to Alice
+      await spendCredits(bob.cells[0], alice.agentPubKey, 10, "Eggs");
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      assert.equal(await getMyBalance(alice.cells[0]), -20);
+      assert.equal(await getMyBalance(bob.cells[0]), 20);
+    }
+  );
+});
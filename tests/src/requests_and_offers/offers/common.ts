@@ -0,0 +1,114 @@
+import { CallableCell } from "@holochain/tryorama";
+import { ActionHash, Record, Link } from "@holochain/client";
+import { OnBehalfOf } from "../utils.js";
+import { ListingState } from "../requests/common";
+
+export type Offer = {
+  title: string;
+  description: string;
+  skills: string[];
+  hours_available?: number;
+  state: ListingState;
+  on_behalf_of?: OnBehalfOf;
+};
+
+export function sampleOffer(partialOffer: Partial<Offer>): Offer {
+  return {
+    ...{
+      title: "Offer",
+      description: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
+      skills: ["rust", "holochain"],
+      hours_available: 4,
+      state: "open",
+    },
+    ...partialOffer,
+  };
+}
+
+export function createOffer(
+  cell: CallableCell,
+  offer: Offer,
+  organization_hash?: ActionHash
+): Promise<Record> {
+  return cell.callZome({
+    zome_name: "offers",
+    fn_name: "create_offer",
+    payload: { offer, organization_hash },
+  });
+}
+
+export function getLatestOffer(
+  cell: CallableCell,
+  original_action_hash: ActionHash
+): Promise<Offer> {
+  return cell.callZome({
+    zome_name: "offers",
+    fn_name: "get_latest_offer",
+    payload: original_action_hash,
+  });
+}
+
+export function getAllRevisionsForOffer(
+  cell: CallableCell,
+  original_action_hash: ActionHash
+): Promise<Record[]> {
+  return cell.callZome({
+    zome_name: "offers",
+    fn_name: "get_all_revisions_for_offer",
+    payload: original_action_hash,
+  });
+}
+
+export function getAllOffersLinks(cell: CallableCell): Promise<Link[]> {
+  return cell.callZome({
+    zome_name: "offers",
+    fn_name: "get_all_offers_links",
+    payload: null,
+  });
+}
+
+export function getUserOffersLinks(
+  cell: CallableCell,
+  user_original_action_hash: ActionHash
+): Promise<Link[]> {
+  return cell.callZome({
+    zome_name: "offers",
+    fn_name: "get_user_offers_links",
+    payload: user_original_action_hash,
+  });
+}
+
+export function getOrganizationOffersLinks(
+  cell: CallableCell,
+  organization_original_action_hash: ActionHash
+): Promise<Link[]> {
+  return cell.callZome({
+    zome_name: "offers",
+    fn_name: "get_organization_offers_links",
+    payload: organization_original_action_hash,
+  });
+}
+
+export function updateOffer(
+  cell: CallableCell,
+  original_action_hash: ActionHash,
+  previous_action_hash: ActionHash,
+  updated_offer: Offer
+): Promise<Record> {
+  return cell.callZome({
+    zome_name: "offers",
+    fn_name: "update_offer",
+    payload: { original_action_hash, previous_action_hash, updated_offer },
+  });
+}
+
+export function deleteOffer(
+  cell: CallableCell,
+  offer_original_action_hash: ActionHash
+): Promise<boolean> {
+  return cell.callZome({
+    zome_name: "offers",
+    fn_name: "delete_offer",
+    payload: offer_original_action_hash,
+  });
+}
@@ -0,0 +1,133 @@
+import { assert, expect, test } from "vitest";
+
+import { Scenario, Player, dhtSync } from "@holochain/tryorama";
+import { Record } from "@holochain/client";
+
+import { runScenarioWithTwoAgents } from "../utils.js";
+import {
+  createUser,
+  getAgentUser,
+  getUserStatusLink,
+  sampleUser,
+} from "../users/common";
+import {
+  AdministrationEntity,
+  getLatestStatusRecordForEntity,
+  registerNetworkAdministrator,
+  updateEntityStatus,
+} from "../administration/common";
+import {
+  createOffer,
+  deleteOffer,
+  getAllOffersLinks,
+  getAllRevisionsForOffer,
+  getLatestOffer,
+  getUserOffersLinks,
+  sampleOffer,
+  updateOffer,
+} from "./common";
+
+test("create, update and delete Offers", async () => {
+  await runScenarioWithTwoAgents(
+    async (_scenario: Scenario, alice: Player, bob: Player) => {
+      // Alice and Bob create their Users
+      await createUser(alice.cells[0], sampleUser({ name: "Alice" }));
+      const bobUserRecord: Record = await createUser(
+        bob.cells[0],
+        sampleUser({ name: "Bob" })
+      );
+      const bobUserHash = bobUserRecord.signed_action.hashed.hash;
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      // Bob tries to create an Offer while his User is still pending
+      await expect(createOffer(bob.cells[0], sampleOffer({}))).rejects.toThrow();
+
+      // Alice becomes a network administrator and accepts Bob
+      const aliceUserLink = (
+        await getAgentUser(alice.cells[0], alice.agentPubKey)
+      )[0];
+      await registerNetworkAdministrator(alice.cells[0], aliceUserLink.target, [
+        alice.agentPubKey,
+      ]);
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      const bobStatusOriginalActionHash = (
+        await getUserStatusLink(alice.cells[0], bobUserHash)
+      ).target;
+      const bobLatestStatusActionHash = (
+        await getLatestStatusRecordForEntity(
+          alice.cells[0],
+          AdministrationEntity.Users,
+          bobUserHash
+        )
+      ).signed_action.hashed.hash;
+
+      await updateEntityStatus(
+        alice.cells[0],
+        AdministrationEntity.Users,
+        bobUserHash,
+        bobLatestStatusActionHash,
+        bobStatusOriginalActionHash,
+        {
+          status_type: "accepted",
+        }
+      );
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      // Bob creates an Offer
+      const offerRecord: Record = await createOffer(
+        bob.cells[0],
+        sampleOffer({ title: "Mentoring" })
+      );
+      assert.ok(offerRecord);
+      const offerHash = offerRecord.signed_action.hashed.hash;
+
+      await dhtSync([alice, bob], bob.cells[0].cell_id[0]);
+
+      assert.lengthOf(await getAllOffersLinks(alice.cells[0]), 1);
+      assert.lengthOf(await getUserOffersLinks(alice.cells[0], bobUserHash), 1);
+
+      // Alice tries to update Bob's Offer
+      await expect(
+        updateOffer(alice.cells[0], offerHash, offerHash, sampleOffer({}))
+      ).rejects.toThrow();
+
+      // Bob tries to mark his open Offer as fulfilled directly
+      await expect(
+        updateOffer(
+          bob.cells[0],
+          offerHash,
+          offerHash,
+          sampleOffer({ state: "fulfilled" })
+        )
+      ).rejects.toThrow();
+
+      // Bob updates his Offer and starts working on it
+      await updateOffer(
+        bob.cells[0],
+        offerHash,
+        offerHash,
+        sampleOffer({ title: "Mentoring and reviews", state: "in progress" })
+      );
+
+      await dhtSync([alice, bob], bob.cells[0].cell_id[0]);
+
+      const latestOffer = await getLatestOffer(alice.cells[0], offerHash);
+      assert.equal(latestOffer.title, "Mentoring and reviews");
+      assert.equal(latestOffer.state, "in progress");
+
+      const revisions = await getAllRevisionsForOffer(alice.cells[0], offerHash);
+      assert.lengthOf(revisions, 2);
+
+      // Bob deletes his Offer
+      await deleteOffer(bob.cells[0], offerHash);
+
+      await dhtSync([alice, bob], bob.cells[0].cell_id[0]);
+
+      assert.lengthOf(await getAllOffersLinks(alice.cells[0]), 0);
+    }
+  );
+});
@@ -0,0 +1,147 @@
+import { assert, expect, test } from "vitest";
+
+import { Scenario, Player, dhtSync } from "@holochain/tryorama";
+import { ActionHash, Record } from "@holochain/client";
+
+import { runScenarioWithTwoAgents } from "../utils.js";
+import { createUser, sampleUser } from "../users/common";
+import {
+  AdministrationEntity,
+  getLatestStatusRecordForEntity,
+  registerNetworkAdministrator,
+  updateEntityStatus,
+} from "../administration/common";
+import { archiveCategory, createCategory } from "../categories/common";
+import {
+  createOrganization,
+  getAcceptedOrganizationsByCategory,
+  getOrganizationCategoriesLinks,
+  getOrganizationStatusLink,
+  sampleOrganization,
+  updateOrganization,
+} from "./common";
+
+test("list accepted Organizations by category", async () => {
+  await runScenarioWithTwoAgents(
+    async (_scenario: Scenario, alice: Player, bob: Player) => {
+      const aliceUserRecord: Record = await createUser(
+        alice.cells[0],
+        sampleUser({ name: "Alice" })
+      );
+      await createUser(bob.cells[0], sampleUser({ name: "Bob" }));
+      await registerNetworkAdministrator(
+        alice.cells[0],
+        aliceUserRecord.signed_action.hashed.hash,
+        [alice.agentPubKey]
+      );
+
+      const educationHash: ActionHash = (
+        await createCategory(alice.cells[0], { name: "Education" })
+      ).signed_action.hashed.hash;
+      const healthRecord = await createCategory(alice.cells[0], {
+        name: "Health",
+      });
+      const healthHash: ActionHash = healthRecord.signed_action.hashed.hash;
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      const aliceOrganizationHash: ActionHash = (
+        await createOrganization(
+          alice.cells[0],
+          sampleOrganization({ name: "School", categories: [educationHash] })
+        )
+      ).signed_action.hashed.hash;
+      const bobOrganizationHash: ActionHash = (
+        await createOrganization(
+          bob.cells[0],
+          sampleOrganization({ name: "Clinic", categories: [educationHash] })
+        )
+      ).signed_action.hashed.hash;
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      // Pending organizations are not listed
+      assert.lengthOf(
+        await getAcceptedOrganizationsByCategory(bob.cells[0], educationHash),
+        0
+      );
+
+      for (const organizationHash of [
+        aliceOrganizationHash,
+        bobOrganizationHash,
+      ]) {
+        const statusOriginalActionHash: ActionHash = (
+          await getOrganizationStatusLink(alice.cells[0], organizationHash)
+        ).target;
+        const latestStatusActionHash = (
+          await getLatestStatusRecordForEntity(
+            alice.cells[0],
+            AdministrationEntity.Organizations,
+            organizationHash
+          )
+        ).signed_action.hashed.hash;
+
+        await updateEntityStatus(
+          alice.cells[0],
+          AdministrationEntity.Organizations,
+          organizationHash,
+          latestStatusActionHash,
+          statusOriginalActionHash,
+          {
+            status_type: "accepted",
+          }
+        );
+      }
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      assert.lengthOf(
+        await getAcceptedOrganizationsByCategory(bob.cells[0], educationHash),
+        2
+      );
+
+      // Bob moves his organization to the Health category
+      await updateOrganization(
+        bob.cells[0],
+        bobOrganizationHash,
+        bobOrganizationHash,
+        sampleOrganization({ name: "Clinic", categories: [healthHash] })
+      );
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      const educationOrganizations = await getAcceptedOrganizationsByCategory(
+        alice.cells[0],
+        educationHash
+      );
+      assert.lengthOf(educationOrganizations, 1);
+      assert.deepEqual(educationOrganizations[0].target, aliceOrganizationHash);
+      const healthOrganizations = await getAcceptedOrganizationsByCategory(
+        alice.cells[0],
+        healthHash
+      );
+      assert.lengthOf(healthOrganizations, 1);
+      assert.deepEqual(healthOrganizations[0].target, bobOrganizationHash);
+      assert.lengthOf(
+        await getOrganizationCategoriesLinks(alice.cells[0], bobOrganizationHash),
+        1
+      );
+
+      // Archived categories cannot be used
+      await archiveCategory(
+        alice.cells[0],
+        healthHash,
+        healthRecord.signed_action.hashed.hash
+      );
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      await expect(
+        createOrganization(
+          alice.cells[0],
+          sampleOrganization({ name: "Hospital", categories: [healthHash] })
+        )
+      ).rejects.toThrow();
+    }
+  );
+});
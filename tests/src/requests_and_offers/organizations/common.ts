@@ -1,6 +1,7 @@
 import { CallableCell } from "@holochain/tryorama";
 import { ActionHash, Record, Link } from "@holochain/client";
 import { User } from "../users/common";
+import { OnBehalfOf } from "../utils.js";
 
 export type Organization = {
   name: string;
@@ -9,6 +10,7 @@ export type Organization = {
   email: string;
   urls: string[];
   location: string;
+  categories?: ActionHash[];
 };
 
 export function sampleOrganization(
@@ -22,6 +24,7 @@ export function sampleOrganization(
       email: "abc@abc.com",
       urls: ["https://example.com"],
       location: "here",
+      categories: [],
     },
     ...partialOrganization,
   };
@@ -163,21 +166,6 @@ export function getUserOrganizations(
   });
 }
 
-export function addCoordinatorToOrganization(
-  cell: CallableCell,
-  organization_original_action_hash: ActionHash,
-  user_original_action_hash: ActionHash
-): Promise<boolean> {
-  return cell.callZome({
-    zome_name: "users_organizations",
-    fn_name: "add_coordinator_to_organization",
-    payload: {
-      organization_original_action_hash,
-      user_original_action_hash,
-    },
-  });
-}
-
 export function getOrganizationCoordinatorsLinks(
   cell: CallableCell,
   organization_original_action_hash: ActionHash
@@ -294,3 +282,372 @@ export function deleteOrganization(
     payload: organization_original_action_hash,
   });
 }
+
+export function saveOrganizationDraft(
+  cell: CallableCell,
+  organization: Organization,
+  previous_draft_action_hash?: ActionHash
+): Promise<Record> {
+  return cell.callZome({
+    zome_name: "users_organizations",
+    fn_name: "save_organization_draft",
+    payload: { previous_draft_action_hash, organization },
+  });
+}
+
+export function getMyOrganizationDrafts(cell: CallableCell): Promise<Record[]> {
+  return cell.callZome({
+    zome_name: "users_organizations",
+    fn_name: "get_my_organization_drafts",
+    payload: null,
+  });
+}
+
+export function discardOrganizationDraft(
+  cell: CallableCell,
+  draft_action_hash: ActionHash
+): Promise<ActionHash> {
+  return cell.callZome({
+    zome_name: "users_organizations",
+    fn_name: "discard_organization_draft",
+    payload: draft_action_hash,
+  });
+}
+
+export function publishOrganizationDraft(
+  cell: CallableCell,
+  draft_action_hash: ActionHash
+): Promise<Record> {
+  return cell.callZome({
+    zome_name: "users_organizations",
+    fn_name: "publish_organization_draft",
+    payload: draft_action_hash,
+  });
+}
+
+export function getOrganizationCategoriesLinks(
+  cell: CallableCell,
+  organization_original_action_hash: ActionHash
+): Promise<Link[]> {
+  return cell.callZome({
+    zome_name: "users_organizations",
+    fn_name: "get_organization_categories_links",
+    payload: organization_original_action_hash,
+  });
+}
+
+export function getAcceptedOrganizationsByCategory(
+  cell: CallableCell,
+  category_original_action_hash: ActionHash
+): Promise<Link[]> {
+  return cell.callZome({
+    zome_name: "users_organizations",
+    fn_name: "get_accepted_organizations_by_category",
+    payload: category_original_action_hash,
+  });
+}
+
+export type InvitationRole = "member" | "coordinator";
+
+export type InvitationStatus = "pending" | "accepted" | "declined" | "cancelled";
+
+export type RoleGrant = {
+  role: ActionHash;
+  role_assignment_link: ActionHash;
+  user_agent_link: ActionHash;
+};
+
+export type OrganizationAuthority =
+  | { Coordinator: OnBehalfOf }
+  | { Role: RoleGrant };
+
+export type OrganizationInvitation = {
+  organization: ActionHash;
+  inviter: ActionHash;
+  invitee: ActionHash;
+  role: InvitationRole;
+  status: InvitationStatus;
+  authority: OrganizationAuthority;
+  invitee_agent_link?: ActionHash | null;
+};
+
+export function inviteMemberToOrganization(
+  cell: CallableCell,
+  organization_original_action_hash: ActionHash,
+  user_original_action_hash: ActionHash
+): Promise<Record> {
+  return cell.callZome({
+    zome_name: "users_organizations",
+    fn_name: "invite_member_to_organization",
+    payload: {
+      organization_original_action_hash,
+      user_original_action_hash,
+    },
+  });
+}
+
+export function inviteCoordinatorToOrganization(
+  cell: CallableCell,
+  organization_original_action_hash: ActionHash,
+  user_original_action_hash: ActionHash
+): Promise<Record> {
+  return cell.callZome({
+    zome_name: "users_organizations",
+    fn_name: "invite_coordinator_to_organization",
+    payload: {
+      organization_original_action_hash,
+      user_original_action_hash,
+    },
+  });
+}
+
+export function getLatestOrganizationInvitation(
+  cell: CallableCell,
+  original_action_hash: ActionHash
+): Promise<OrganizationInvitation> {
+  return cell.callZome({
+    zome_name: "users_organizations",
+    fn_name: "get_latest_organization_invitation",
+    payload: original_action_hash,
+  });
+}
+
+export function getUserInvitationsLinks(
+  cell: CallableCell,
+  user_original_action_hash: ActionHash
+): Promise<Link[]> {
+  return cell.callZome({
+    zome_name: "users_organizations",
+    fn_name: "get_user_invitations_links",
+    payload: user_original_action_hash,
+  });
+}
+
+export function getMyPendingInvitationsLinks(
+  cell: CallableCell
+): Promise<Link[]> {
+  return cell.callZome({
+    zome_name: "users_organizations",
+    fn_name: "get_my_pending_invitations_links",
+    payload: null,
+  });
+}
+
+export function acceptOrganizationInvitation(
+  cell: CallableCell,
+  original_action_hash: ActionHash
+): Promise<boolean> {
+  return cell.callZome({
+    zome_name: "users_organizations",
+    fn_name: "accept_organization_invitation",
+    payload: original_action_hash,
+  });
+}
+
+export function declineOrganizationInvitation(
+  cell: CallableCell,
+  original_action_hash: ActionHash
+): Promise<boolean> {
+  return cell.callZome({
+    zome_name: "users_organizations",
+    fn_name: "decline_organization_invitation",
+    payload: original_action_hash,
+  });
+}
+
+export function cancelOrganizationInvitation(
+  cell: CallableCell,
+  original_action_hash: ActionHash
+): Promise<boolean> {
+  return cell.callZome({
+    zome_name: "users_organizations",
+    fn_name: "cancel_organization_invitation",
+    payload: original_action_hash,
+  });
+}
+
+export type JoinRequestStatus = "pending" | "approved" | "rejected";
+
+export type OrganizationJoinRequest = {
+  organization: ActionHash;
+  requester: ActionHash;
+  message: string;
+  status: JoinRequestStatus;
+  rejection_reason: string | null;
+  requester_agent_link: ActionHash;
+  decision_authority: OrganizationAuthority | null;
+};
+
+export function requestToJoinOrganization(
+  cell: CallableCell,
+  organization_original_action_hash: ActionHash,
+  message: string
+): Promise<Record> {
+  return cell.callZome({
+    zome_name: "users_organizations",
+    fn_name: "request_to_join_organization",
+    payload: { organization_original_action_hash, message },
+  });
+}
+
+export function getLatestOrganizationJoinRequest(
+  cell: CallableCell,
+  original_action_hash: ActionHash
+): Promise<OrganizationJoinRequest> {
+  return cell.callZome({
+    zome_name: "users_organizations",
+    fn_name: "get_latest_organization_join_request",
+    payload: original_action_hash,
+  });
+}
+
+export function getPendingJoinRequestsLinks(
+  cell: CallableCell,
+  organization_original_action_hash: ActionHash
+): Promise<Link[]> {
+  return cell.callZome({
+    zome_name: "users_organizations",
+    fn_name: "get_pending_join_requests_links",
+    payload: organization_original_action_hash,
+  });
+}
+
+export function approveJoinRequest(
+  cell: CallableCell,
+  original_action_hash: ActionHash
+): Promise<boolean> {
+  return cell.callZome({
+    zome_name: "users_organizations",
+    fn_name: "approve_join_request",
+    payload: original_action_hash,
+  });
+}
+
+export function rejectJoinRequest(
+  cell: CallableCell,
+  original_action_hash: ActionHash,
+  reason: string
+): Promise<boolean> {
+  return cell.callZome({
+    zome_name: "users_organizations",
+    fn_name: "reject_join_request",
+    payload: { original_action_hash, reason },
+  });
+}
+
+export type OrganizationPermission =
+  | "manage_members"
+  | "manage_roles"
+  | "manage_finances"
+  | "review_listings"
+  | "represent_organization";
+
+export type OrganizationRole = {
+  organization: ActionHash;
+  name: string;
+  permissions: OrganizationPermission[];
+};
+
+export function createOrganizationRole(
+  cell: CallableCell,
+  role: OrganizationRole
+): Promise<Record> {
+  return cell.callZome({
+    zome_name: "users_organizations",
+    fn_name: "create_organization_role",
+    payload: role,
+  });
+}
+
+export function getOrganizationRolesLinks(
+  cell: CallableCell,
+  organization_original_action_hash: ActionHash
+): Promise<Link[]> {
+  return cell.callZome({
+    zome_name: "users_organizations",
+    fn_name: "get_organization_roles_links",
+    payload: organization_original_action_hash,
+  });
+}
+
+export function getLatestOrganizationRole(
+  cell: CallableCell,
+  original_action_hash: ActionHash
+): Promise<OrganizationRole | null> {
+  return cell.callZome({
+    zome_name: "users_organizations",
+    fn_name: "get_latest_organization_role",
+    payload: original_action_hash,
+  });
+}
+
+export function updateOrganizationRole(
+  cell: CallableCell,
+  original_action_hash: ActionHash,
+  previous_action_hash: ActionHash,
+  updated_role: OrganizationRole
+): Promise<Record> {
+  return cell.callZome({
+    zome_name: "users_organizations",
+    fn_name: "update_organization_role",
+    payload: { original_action_hash, previous_action_hash, updated_role },
+  });
+}
+
+export function deleteOrganizationRole(
+  cell: CallableCell,
+  original_action_hash: ActionHash
+): Promise<boolean> {
+  return cell.callZome({
+    zome_name: "users_organizations",
+    fn_name: "delete_organization_role",
+    payload: original_action_hash,
+  });
+}
+
+export function assignOrganizationRole(
+  cell: CallableCell,
+  role_original_action_hash: ActionHash,
+  user_original_action_hash: ActionHash
+): Promise<boolean> {
+  return cell.callZome({
+    zome_name: "users_organizations",
+    fn_name: "assign_organization_role",
+    payload: { role_original_action_hash, user_original_action_hash },
+  });
+}
+
+export function unassignOrganizationRole(
+  cell: CallableCell,
+  role_original_action_hash: ActionHash,
+  user_original_action_hash: ActionHash
+): Promise<boolean> {
+  return cell.callZome({
+    zome_name: "users_organizations",
+    fn_name: "unassign_organization_role",
+    payload: { role_original_action_hash, user_original_action_hash },
+  });
+}
+
+export function getUserRolesLinks(
+  cell: CallableCell,
+  user_original_action_hash: ActionHash
+): Promise<Link[]> {
+  return cell.callZome({
+    zome_name: "users_organizations",
+    fn_name: "get_user_roles_links",
+    payload: user_original_action_hash,
+  });
+}
+
+export function checkIfAgentHasOrganizationPermission(
+  cell: CallableCell,
+  organization_original_action_hash: ActionHash,
+  permission: OrganizationPermission
+): Promise<boolean> {
+  return cell.callZome({
+    zome_name: "users_organizations",
+    fn_name: "check_if_agent_has_organization_permission",
+    payload: { organization_original_action_hash, permission },
+  });
+}
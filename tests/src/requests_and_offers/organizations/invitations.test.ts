@@ -0,0 +1,322 @@
+import { assert, expect, test } from "vitest";
+
+import { Scenario, Player, dhtSync } from "@holochain/tryorama";
+import { ActionHash, Record } from "@holochain/client";
+
+import { OnBehalfOf, runScenarioWithTwoAgents } from "../utils.js";
+import { createUser, getUserStatusLink, sampleUser } from "../users/common";
+import {
+  AdministrationEntity,
+  getLatestStatusRecordForEntity,
+  registerNetworkAdministrator,
+  updateEntityStatus,
+} from "../administration/common";
+import {
+  acceptOrganizationInvitation,
+  cancelOrganizationInvitation,
+  createOrganization,
+  declineOrganizationInvitation,
+  getLatestOrganizationInvitation,
+  getMyPendingInvitationsLinks,
+  getOrganizationStatusLink,
+  getUserInvitationsLinks,
+  inviteCoordinatorToOrganization,
+  inviteMemberToOrganization,
+  isOrganizationCoordinator,
+  isOrganizationMember,
+  sampleOrganization,
+} from "./common";
+
+test("invite a User to become an Organization member", async () => {
+  await runScenarioWithTwoAgents(
+    async (_scenario: Scenario, alice: Player, bob: Player) => {
+      const aliceUserRecord: Record = await createUser(
+        alice.cells[0],
+        sampleUser({ name: "Alice" })
+      );
+      const aliceUserHash = aliceUserRecord.signed_action.hashed.hash;
+      const bobUserRecord: Record = await createUser(
+        bob.cells[0],
+        sampleUser({ name: "Bob" })
+      );
+      const bobUserHash = bobUserRecord.signed_action.hashed.hash;
+      await registerNetworkAdministrator(alice.cells[0], aliceUserHash, [
+        alice.agentPubKey,
+      ]);
+
+      const organizationHash: ActionHash = (
+        await createOrganization(
+          alice.cells[0],
+          sampleOrganization({ name: "Org" })
+        )
+      ).signed_action.hashed.hash;
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      // Members cannot be invited to a pending organization
+      await expect(
+        inviteMemberToOrganization(
+          alice.cells[0],
+          organizationHash,
+          bobUserHash
+        )
+      ).rejects.toThrow();
+
+      const statusOriginalActionHash: ActionHash = (
+        await getOrganizationStatusLink(alice.cells[0], organizationHash)
+      ).target;
+      const latestStatusActionHash = (
+        await getLatestStatusRecordForEntity(
+          alice.cells[0],
+          AdministrationEntity.Organizations,
+          organizationHash
+        )
+      ).signed_action.hashed.hash;
+      await updateEntityStatus(
+        alice.cells[0],
+        AdministrationEntity.Organizations,
+        organizationHash,
+        latestStatusActionHash,
+        statusOriginalActionHash,
+        {
+          status_type: "accepted",
+        }
+      );
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      // Only coordinators can invite members
+      await expect(
+        inviteMemberToOrganization(bob.cells[0], organizationHash, bobUserHash)
+      ).rejects.toThrow();
+
+      let invitationHash: ActionHash = (
+        await inviteMemberToOrganization(
+          alice.cells[0],
+          organizationHash,
+          bobUserHash
+        )
+      ).signed_action.hashed.hash;
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      // A user has at most one pending invitation per organization
+      await expect(
+        inviteMemberToOrganization(
+          alice.cells[0],
+          organizationHash,
+          bobUserHash
+        )
+      ).rejects.toThrow();
+
+      let pendingLinks = await getMyPendingInvitationsLinks(bob.cells[0]);
+      assert.lengthOf(pendingLinks, 1);
+      assert.deepEqual(pendingLinks[0].target, invitationHash);
+
+      // Only the invitee can decide
+      await expect(
+        acceptOrganizationInvitation(alice.cells[0], invitationHash)
+      ).rejects.toThrow();
+
+      await declineOrganizationInvitation(bob.cells[0], invitationHash);
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      const declinedInvitation = await getLatestOrganizationInvitation(
+        alice.cells[0],
+        invitationHash
+      );
+      assert.equal(declinedInvitation.status, "declined");
+      // The decision cites the UserAgents link of Bob's agent
+      assert.ok(declinedInvitation.invitee_agent_link);
+      assert.deepEqual(
+        (declinedInvitation.authority as { Coordinator: OnBehalfOf })
+          .Coordinator.organization_original_action_hash,
+        organizationHash
+      );
+      assert.lengthOf(await getMyPendingInvitationsLinks(bob.cells[0]), 0);
+
+      // A cancelled invitation can no longer be accepted
+      invitationHash = (
+        await inviteMemberToOrganization(
+          alice.cells[0],
+          organizationHash,
+          bobUserHash
+        )
+      ).signed_action.hashed.hash;
+      await cancelOrganizationInvitation(alice.cells[0], invitationHash);
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      await expect(
+        acceptOrganizationInvitation(bob.cells[0], invitationHash)
+      ).rejects.toThrow();
+      assert.isFalse(
+        await isOrganizationMember(bob.cells[0], organizationHash, bobUserHash)
+      );
+
+      invitationHash = (
+        await inviteMemberToOrganization(
+          alice.cells[0],
+          organizationHash,
+          bobUserHash
+        )
+      ).signed_action.hashed.hash;
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      await acceptOrganizationInvitation(bob.cells[0], invitationHash);
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      assert.isTrue(
+        await isOrganizationMember(alice.cells[0], organizationHash, bobUserHash)
+      );
+      assert.lengthOf(
+        await getUserInvitationsLinks(alice.cells[0], bobUserHash),
+        3
+      );
+      assert.lengthOf(await getMyPendingInvitationsLinks(bob.cells[0]), 0);
+
+      // Members cannot be invited again
+      await expect(
+        inviteMemberToOrganization(
+          alice.cells[0],
+          organizationHash,
+          bobUserHash
+        )
+      ).rejects.toThrow();
+    }
+  );
+});
+
+test("invite a User to become an Organization coordinator", async () => {
+  await runScenarioWithTwoAgents(
+    async (_scenario: Scenario, alice: Player, bob: Player) => {
+      const aliceUserRecord: Record = await createUser(
+        alice.cells[0],
+        sampleUser({ name: "Alice" })
+      );
+      const aliceUserHash = aliceUserRecord.signed_action.hashed.hash;
+      const bobUserRecord: Record = await createUser(
+        bob.cells[0],
+        sampleUser({ name: "Bob" })
+      );
+      const bobUserHash = bobUserRecord.signed_action.hashed.hash;
+      await registerNetworkAdministrator(alice.cells[0], aliceUserHash, [
+        alice.agentPubKey,
+      ]);
+
+      const organizationHash: ActionHash = (
+        await createOrganization(
+          alice.cells[0],
+          sampleOrganization({ name: "Org" })
+        )
+      ).signed_action.hashed.hash;
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      const organizationStatusHash: ActionHash = (
+        await getOrganizationStatusLink(alice.cells[0], organizationHash)
+      ).target;
+      await updateEntityStatus(
+        alice.cells[0],
+        AdministrationEntity.Organizations,
+        organizationHash,
+        (
+          await getLatestStatusRecordForEntity(
+            alice.cells[0],
+            AdministrationEntity.Organizations,
+            organizationHash
+          )
+        ).signed_action.hashed.hash,
+        organizationStatusHash,
+        {
+          status_type: "accepted",
+        }
+      );
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      // Only accepted users can be invited to coordinate
+      await expect(
+        inviteCoordinatorToOrganization(
+          alice.cells[0],
+          organizationHash,
+          bobUserHash
+        )
+      ).rejects.toThrow();
+
+      const userStatusHash: ActionHash = (
+        await getUserStatusLink(alice.cells[0], bobUserHash)
+      ).target;
+      await updateEntityStatus(
+        alice.cells[0],
+        AdministrationEntity.Users,
+        bobUserHash,
+        (
+          await getLatestStatusRecordForEntity(
+            alice.cells[0],
+            AdministrationEntity.Users,
+            bobUserHash
+          )
+        ).signed_action.hashed.hash,
+        userStatusHash,
+        {
+          status_type: "accepted",
+        }
+      );
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      const invitationHash: ActionHash = (
+        await inviteCoordinatorToOrganization(
+          alice.cells[0],
+          organizationHash,
+          bobUserHash
+        )
+      ).signed_action.hashed.hash;
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      // Bob is not promoted until accepting
+      assert.isFalse(
+        await isOrganizationCoordinator(
+          alice.cells[0],
+          organizationHash,
+          bobUserHash
+        )
+      );
+      const invitation = await getLatestOrganizationInvitation(
+        bob.cells[0],
+        invitationHash
+      );
+      assert.equal(invitation.role, "coordinator");
+      assert.equal(invitation.status, "pending");
+
+      await acceptOrganizationInvitation(bob.cells[0], invitationHash);
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      assert.isTrue(
+        await isOrganizationCoordinator(
+          alice.cells[0],
+          organizationHash,
+          bobUserHash
+        )
+      );
+      assert.isTrue(
+        await isOrganizationMember(alice.cells[0], organizationHash, bobUserHash)
+      );
+
+      // Coordinators cannot be invited again
+      await expect(
+        inviteCoordinatorToOrganization(
+          alice.cells[0],
+          organizationHash,
+          bobUserHash
+        )
+      ).rejects.toThrow();
+    }
+  );
+});
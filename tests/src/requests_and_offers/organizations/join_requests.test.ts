@@ -0,0 +1,162 @@
+import { assert, expect, test } from "vitest";
+
+import { Scenario, Player, dhtSync } from "@holochain/tryorama";
+import { ActionHash, Record } from "@holochain/client";
+
+import { runScenarioWithTwoAgents } from "../utils.js";
+import { createUser, sampleUser } from "../users/common";
+import {
+  AdministrationEntity,
+  getLatestStatusRecordForEntity,
+  registerNetworkAdministrator,
+  updateEntityStatus,
+} from "../administration/common";
+import {
+  approveJoinRequest,
+  createOrganization,
+  getLatestOrganizationJoinRequest,
+  getOrganizationStatusLink,
+  getPendingJoinRequestsLinks,
+  isOrganizationMember,
+  rejectJoinRequest,
+  requestToJoinOrganization,
+  sampleOrganization,
+} from "./common";
+
+test("Users request to join Organizations", async () => {
+  await runScenarioWithTwoAgents(
+    async (_scenario: Scenario, alice: Player, bob: Player) => {
+      const aliceUserRecord: Record = await createUser(
+        alice.cells[0],
+        sampleUser({ name: "Alice" })
+      );
+      const aliceUserHash = aliceUserRecord.signed_action.hashed.hash;
+      const bobUserRecord: Record = await createUser(
+        bob.cells[0],
+        sampleUser({ name: "Bob" })
+      );
+      const bobUserHash = bobUserRecord.signed_action.hashed.hash;
+      await registerNetworkAdministrator(alice.cells[0], aliceUserHash, [
+        alice.agentPubKey,
+      ]);
+
+      const organizationHash: ActionHash = (
+        await createOrganization(
+          alice.cells[0],
+          sampleOrganization({ name: "Org" })
+        )
+      ).signed_action.hashed.hash;
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      // Pending organizations cannot be joined
+      await expect(
+        requestToJoinOrganization(bob.cells[0], organizationHash, "Hello")
+      ).rejects.toThrow();
+
+      const statusOriginalActionHash: ActionHash = (
+        await getOrganizationStatusLink(alice.cells[0], organizationHash)
+      ).target;
+      const latestStatusActionHash = (
+        await getLatestStatusRecordForEntity(
+          alice.cells[0],
+          AdministrationEntity.Organizations,
+          organizationHash
+        )
+      ).signed_action.hashed.hash;
+      await updateEntityStatus(
+        alice.cells[0],
+        AdministrationEntity.Organizations,
+        organizationHash,
+        latestStatusActionHash,
+        statusOriginalActionHash,
+        {
+          status_type: "accepted",
+        }
+      );
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      let joinRequestHash: ActionHash = (
+        await requestToJoinOrganization(
+          bob.cells[0],
+          organizationHash,
+          "I would like to help"
+        )
+      ).signed_action.hashed.hash;
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      await expect(
+        requestToJoinOrganization(bob.cells[0], organizationHash, "Again")
+      ).rejects.toThrow();
+
+      // Only coordinators list and decide on join requests
+      await expect(
+        getPendingJoinRequestsLinks(bob.cells[0], organizationHash)
+      ).rejects.toThrow();
+      await expect(
+        approveJoinRequest(bob.cells[0], joinRequestHash)
+      ).rejects.toThrow();
+
+      let pendingLinks = await getPendingJoinRequestsLinks(
+        alice.cells[0],
+        organizationHash
+      );
+      assert.lengthOf(pendingLinks, 1);
+      assert.deepEqual(pendingLinks[0].target, joinRequestHash);
+
+      await rejectJoinRequest(
+        alice.cells[0],
+        joinRequestHash,
+        "We are not recruiting yet"
+      );
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      const rejectedJoinRequest = await getLatestOrganizationJoinRequest(
+        bob.cells[0],
+        joinRequestHash
+      );
+      assert.equal(rejectedJoinRequest.status, "rejected");
+      assert.equal(
+        rejectedJoinRequest.rejection_reason,
+        "We are not recruiting yet"
+      );
+      // The decision carries the coordinator proof of Alice
+      assert.ok(rejectedJoinRequest.decision_authority);
+      assert.ok("Coordinator" in rejectedJoinRequest.decision_authority);
+      assert.isFalse(
+        await isOrganizationMember(bob.cells[0], organizationHash, bobUserHash)
+      );
+
+      // A decided join request cannot be decided again
+      await expect(
+        approveJoinRequest(alice.cells[0], joinRequestHash)
+      ).rejects.toThrow();
+
+      joinRequestHash = (
+        await requestToJoinOrganization(
+          bob.cells[0],
+          organizationHash,
+          "Still eager to help"
+        )
+      ).signed_action.hashed.hash;
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      await approveJoinRequest(alice.cells[0], joinRequestHash);
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      assert.isTrue(
+        await isOrganizationMember(bob.cells[0], organizationHash, bobUserHash)
+      );
+      pendingLinks = await getPendingJoinRequestsLinks(
+        alice.cells[0],
+        organizationHash
+      );
+      assert.lengthOf(pendingLinks, 0);
+    }
+  );
+});
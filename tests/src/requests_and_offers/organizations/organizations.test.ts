@@ -20,7 +20,7 @@ import {
   updateEntityStatus,
 } from "../administration/common";
 import {
-  addCoordinatorToOrganization,
+  acceptOrganizationInvitation,
   addMemberToOrganization,
   checkIfAgentIsOrganizationCoordinator,
   createOrganization,
@@ -32,6 +32,7 @@ import {
   getOrganizationMembersLinks,
   getOrganizationStatusLink,
   getUserOrganizationsLinks,
+  inviteCoordinatorToOrganization,
   leaveOrganization,
   removeOrganizationCoordinator,
   removeOrganizationMember,
@@ -209,16 +210,16 @@ test("create and manage Organizations", async () => {
       );
 
       await expect(
-        addCoordinatorToOrganization(
+        inviteCoordinatorToOrganization(
           alice.cells[0],
           bobOrganizationOriginalActionHash,
           aliceUserLink.target
         )
       ).rejects.toThrow();
 
-      // Bob try to add Alice as a coordinator of the Organization
+      // Bob try to invite Alice as a coordinator of the Organization
       expect(
-        addCoordinatorToOrganization(
+        inviteCoordinatorToOrganization(
           bob.cells[0],
           bobOrganizationOriginalActionHash,
           aliceUserLink.target
@@ -367,12 +368,19 @@ test("create and manage Organizations", async () => {
       assert.lengthOf(organizationMembers, 2);
       assert.deepEqual(organizationMembers[1].target, bobUserLink.target);
 
-      // Alice add Bob as a coordinator of the Organization
+      // Alice invites Bob to become a coordinator of the Organization and Bob accepts
+      const bobCoordinatorInvitation = await inviteCoordinatorToOrganization(
+        alice.cells[0],
+        aliceOrganizationOriginalActionHash,
+        bobUserLink.target
+      );
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
       assert.ok(
-        await addCoordinatorToOrganization(
-          alice.cells[0],
-          aliceOrganizationOriginalActionHash,
-          bobUserLink.target
+        await acceptOrganizationInvitation(
+          bob.cells[0],
+          bobCoordinatorInvitation.signed_action.hashed.hash
         )
       );
 
@@ -391,16 +399,25 @@ test("create and manage Organizations", async () => {
         )
       );
 
-      // Bob remove Alice as a coordinator of the Organization
+      // Bob can not remove Alice as a coordinator, coordinators can only remove themselves
+      await expect(
+        removeOrganizationCoordinator(
+          bob.cells[0],
+          aliceOrganizationOriginalActionHash,
+          aliceUserLink.target
+        )
+      ).rejects.toThrow();
+
+      // Alice steps down as a coordinator of the Organization
       assert.ok(
         await removeOrganizationCoordinator(
-          bob.cells[0],
+          alice.cells[0],
           aliceOrganizationOriginalActionHash,
           aliceUserLink.target
         )
       );
 
-      await dhtSync([alice, bob], bob.cells[0].cell_id[0]);
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
 
       // Verify that Alice is not a coordinator of the Organization
       assert.notOk(
@@ -443,12 +460,19 @@ test("create and manage Organizations", async () => {
 
       await dhtSync([alice, bob], bob.cells[0].cell_id[0]);
 
-      // Bob add Alice as a coordinator of the Organization
+      // Bob invites Alice to become a coordinator of the Organization again and Alice accepts
+      const aliceCoordinatorInvitation = await inviteCoordinatorToOrganization(
+        bob.cells[0],
+        aliceOrganizationOriginalActionHash,
+        aliceUserLink.target
+      );
+
+      await dhtSync([alice, bob], bob.cells[0].cell_id[0]);
+
       assert.ok(
-        await addCoordinatorToOrganization(
-          bob.cells[0],
-          aliceOrganizationOriginalActionHash,
-          aliceUserLink.target
+        await acceptOrganizationInvitation(
+          alice.cells[0],
+          aliceCoordinatorInvitation.signed_action.hashed.hash
         )
       );
 
@@ -0,0 +1,395 @@
+import { assert, expect, test } from "vitest";
+
+import { Scenario, Player, dhtSync } from "@holochain/tryorama";
+import { ActionHash, Record } from "@holochain/client";
+
+import { decodeRecords, runScenarioWithTwoAgents } from "../utils.js";
+import { createUser, sampleUser } from "../users/common";
+import {
+  AdministrationEntity,
+  getLatestStatusRecordForEntity,
+  registerNetworkAdministrator,
+  updateEntityStatus,
+} from "../administration/common";
+import {
+  acceptOrganizationInvitation,
+  addMemberToOrganization,
+  assignOrganizationRole,
+  checkIfAgentHasOrganizationPermission,
+  createOrganization,
+  createOrganizationRole,
+  deleteOrganizationRole,
+  getLatestOrganizationRole,
+  getOrganizationRolesLinks,
+  getOrganizationStatusLink,
+  getPendingJoinRequestsLinks,
+  getUserRolesLinks,
+  inviteCoordinatorToOrganization,
+  inviteMemberToOrganization,
+  isOrganizationMember,
+  OrganizationInvitation,
+  sampleOrganization,
+  unassignOrganizationRole,
+  updateOrganizationRole,
+} from "./common";
+
+const hAppPath = process.cwd() + "/../workdir/requests_and_offers.happ";
+const appSource = { appBundleSource: { path: hAppPath } };
+
+test("Organization roles grant permissions to members", async () => {
+  await runScenarioWithTwoAgents(
+    async (_scenario: Scenario, alice: Player, bob: Player) => {
+      const aliceUserRecord: Record = await createUser(
+        alice.cells[0],
+        sampleUser({ name: "Alice" })
+      );
+      const aliceUserHash = aliceUserRecord.signed_action.hashed.hash;
+      const bobUserRecord: Record = await createUser(
+        bob.cells[0],
+        sampleUser({ name: "Bob" })
+      );
+      const bobUserHash = bobUserRecord.signed_action.hashed.hash;
+      await registerNetworkAdministrator(alice.cells[0], aliceUserHash, [
+        alice.agentPubKey,
+      ]);
+
+      const organizationHash: ActionHash = (
+        await createOrganization(
+          alice.cells[0],
+          sampleOrganization({ name: "Org" })
+        )
+      ).signed_action.hashed.hash;
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      const statusOriginalActionHash: ActionHash = (
+        await getOrganizationStatusLink(alice.cells[0], organizationHash)
+      ).target;
+      const latestStatusActionHash = (
+        await getLatestStatusRecordForEntity(
+          alice.cells[0],
+          AdministrationEntity.Organizations,
+          organizationHash
+        )
+      ).signed_action.hashed.hash;
+      await updateEntityStatus(
+        alice.cells[0],
+        AdministrationEntity.Organizations,
+        organizationHash,
+        latestStatusActionHash,
+        statusOriginalActionHash,
+        {
+          status_type: "accepted",
+        }
+      );
+      await addMemberToOrganization(
+        alice.cells[0],
+        organizationHash,
+        bobUserHash
+      );
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      // Coordinators hold every permission, plain members none
+      assert.isTrue(
+        await checkIfAgentHasOrganizationPermission(
+          alice.cells[0],
+          organizationHash,
+          "manage_finances"
+        )
+      );
+      assert.isFalse(
+        await checkIfAgentHasOrganizationPermission(
+          bob.cells[0],
+          organizationHash,
+          "manage_finances"
+        )
+      );
+      await expect(
+        createOrganizationRole(bob.cells[0], {
+          organization: organizationHash,
+          name: "Treasurer",
+          permissions: ["manage_finances"],
+        })
+      ).rejects.toThrow();
+
+      const treasurerRecord: Record = await createOrganizationRole(
+        alice.cells[0],
+        {
+          organization: organizationHash,
+          name: "Treasurer",
+          permissions: ["manage_finances"],
+        }
+      );
+      const treasurerHash = treasurerRecord.signed_action.hashed.hash;
+      const recruiterHash: ActionHash = (
+        await createOrganizationRole(alice.cells[0], {
+          organization: organizationHash,
+          name: "Recruiter",
+          permissions: ["manage_members"],
+        })
+      ).signed_action.hashed.hash;
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      // Role names are unique within an organization
+      await expect(
+        createOrganizationRole(alice.cells[0], {
+          organization: organizationHash,
+          name: "treasurer",
+          permissions: ["review_listings"],
+        })
+      ).rejects.toThrow();
+      assert.lengthOf(
+        await getOrganizationRolesLinks(bob.cells[0], organizationHash),
+        2
+      );
+
+      await assignOrganizationRole(alice.cells[0], treasurerHash, bobUserHash);
+      await assignOrganizationRole(alice.cells[0], recruiterHash, bobUserHash);
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      await expect(
+        assignOrganizationRole(alice.cells[0], treasurerHash, bobUserHash)
+      ).rejects.toThrow();
+      assert.lengthOf(await getUserRolesLinks(bob.cells[0], bobUserHash), 2);
+      assert.isTrue(
+        await checkIfAgentHasOrganizationPermission(
+          bob.cells[0],
+          organizationHash,
+          "manage_finances"
+        )
+      );
+      assert.isFalse(
+        await checkIfAgentHasOrganizationPermission(
+          bob.cells[0],
+          organizationHash,
+          "manage_roles"
+        )
+      );
+
+      // The recruiter role lets Bob manage join requests
+      assert.lengthOf(
+        await getPendingJoinRequestsLinks(bob.cells[0], organizationHash),
+        0
+      );
+
+      await unassignOrganizationRole(
+        alice.cells[0],
+        recruiterHash,
+        bobUserHash
+      );
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      await expect(
+        getPendingJoinRequestsLinks(bob.cells[0], organizationHash)
+      ).rejects.toThrow();
+
+      // Updating a role changes the permissions of its members
+      await updateOrganizationRole(
+        alice.cells[0],
+        treasurerHash,
+        treasurerHash,
+        {
+          organization: organizationHash,
+          name: "Treasurer",
+          permissions: ["review_listings"],
+        }
+      );
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      const updatedTreasurer = await getLatestOrganizationRole(
+        bob.cells[0],
+        treasurerHash
+      );
+      assert.deepEqual(updatedTreasurer?.permissions, ["review_listings"]);
+      assert.isFalse(
+        await checkIfAgentHasOrganizationPermission(
+          bob.cells[0],
+          organizationHash,
+          "manage_finances"
+        )
+      );
+      assert.isTrue(
+        await checkIfAgentHasOrganizationPermission(
+          bob.cells[0],
+          organizationHash,
+          "review_listings"
+        )
+      );
+
+      await deleteOrganizationRole(alice.cells[0], treasurerHash);
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      assert.lengthOf(await getUserRolesLinks(bob.cells[0], bobUserHash), 0);
+      assert.lengthOf(
+        await getOrganizationRolesLinks(bob.cells[0], organizationHash),
+        1
+      );
+      assert.isFalse(
+        await checkIfAgentHasOrganizationPermission(
+          bob.cells[0],
+          organizationHash,
+          "review_listings"
+        )
+      );
+
+      // Members holding a role granting manage_roles prove it with the role they were assigned
+      const roleManagerHash: ActionHash = (
+        await createOrganizationRole(alice.cells[0], {
+          organization: organizationHash,
+          name: "Role Manager",
+          permissions: ["manage_roles"],
+        })
+      ).signed_action.hashed.hash;
+      await assignOrganizationRole(
+        alice.cells[0],
+        roleManagerHash,
+        bobUserHash
+      );
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      const reviewerHash: ActionHash = (
+        await createOrganizationRole(bob.cells[0], {
+          organization: organizationHash,
+          name: "Reviewer",
+          permissions: ["review_listings"],
+        })
+      ).signed_action.hashed.hash;
+      await assignOrganizationRole(bob.cells[0], reviewerHash, bobUserHash);
+      await updateOrganizationRole(bob.cells[0], reviewerHash, reviewerHash, {
+        organization: organizationHash,
+        name: "Listings Reviewer",
+        permissions: ["review_listings"],
+      });
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      assert.isTrue(
+        await checkIfAgentHasOrganizationPermission(
+          bob.cells[0],
+          organizationHash,
+          "review_listings"
+        )
+      );
+      assert.lengthOf(await getUserRolesLinks(alice.cells[0], bobUserHash), 2);
+
+      await deleteOrganizationRole(bob.cells[0], reviewerHash);
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      assert.lengthOf(
+        await getOrganizationRolesLinks(alice.cells[0], organizationHash),
+        2
+      );
+    }
+  );
+});
+
+test("Members holding a role granting manage_members invite members", async () => {
+  await runScenarioWithTwoAgents(
+    async (scenario: Scenario, alice: Player, bob: Player) => {
+      const [carol] = await scenario.addPlayersWithApps([appSource]);
+      await scenario.shareAllAgents();
+      const players = [alice, bob, carol];
+
+      const aliceUserHash: ActionHash = (
+        await createUser(alice.cells[0], sampleUser({ name: "Alice" }))
+      ).signed_action.hashed.hash;
+      const bobUserHash: ActionHash = (
+        await createUser(bob.cells[0], sampleUser({ name: "Bob" }))
+      ).signed_action.hashed.hash;
+      const carolUserHash: ActionHash = (
+        await createUser(carol.cells[0], sampleUser({ name: "Carol" }))
+      ).signed_action.hashed.hash;
+      await registerNetworkAdministrator(alice.cells[0], aliceUserHash, [
+        alice.agentPubKey,
+      ]);
+
+      const organizationHash: ActionHash = (
+        await createOrganization(
+          alice.cells[0],
+          sampleOrganization({ name: "Org" })
+        )
+      ).signed_action.hashed.hash;
+
+      await dhtSync(players, alice.cells[0].cell_id[0]);
+
+      const statusOriginalActionHash: ActionHash = (
+        await getOrganizationStatusLink(alice.cells[0], organizationHash)
+      ).target;
+      const latestStatusActionHash = (
+        await getLatestStatusRecordForEntity(
+          alice.cells[0],
+          AdministrationEntity.Organizations,
+          organizationHash
+        )
+      ).signed_action.hashed.hash;
+      await updateEntityStatus(
+        alice.cells[0],
+        AdministrationEntity.Organizations,
+        organizationHash,
+        latestStatusActionHash,
+        statusOriginalActionHash,
+        {
+          status_type: "accepted",
+        }
+      );
+      await addMemberToOrganization(
+        alice.cells[0],
+        organizationHash,
+        bobUserHash
+      );
+      const recruiterHash: ActionHash = (
+        await createOrganizationRole(alice.cells[0], {
+          organization: organizationHash,
+          name: "Recruiter",
+          permissions: ["manage_members"],
+        })
+      ).signed_action.hashed.hash;
+      await assignOrganizationRole(alice.cells[0], recruiterHash, bobUserHash);
+
+      await dhtSync(players, alice.cells[0].cell_id[0]);
+
+      // The invitation carries the Recruiter role of Bob as its authority
+      const invitationRecord: Record = await inviteMemberToOrganization(
+        bob.cells[0],
+        organizationHash,
+        carolUserHash
+      );
+      const invitationHash = invitationRecord.signed_action.hashed.hash;
+      const invitation = decodeRecords([
+        invitationRecord,
+      ])[0] as OrganizationInvitation;
+      assert.ok("Role" in invitation.authority);
+
+      // Only coordinators invite coordinators
+      await expect(
+        inviteCoordinatorToOrganization(
+          bob.cells[0],
+          organizationHash,
+          carolUserHash
+        )
+      ).rejects.toThrow();
+
+      await dhtSync(players, alice.cells[0].cell_id[0]);
+
+      await acceptOrganizationInvitation(carol.cells[0], invitationHash);
+
+      await dhtSync(players, alice.cells[0].cell_id[0]);
+
+      assert.isTrue(
+        await isOrganizationMember(
+          alice.cells[0],
+          organizationHash,
+          carolUserHash
+        )
+      );
+    }
+  );
+});
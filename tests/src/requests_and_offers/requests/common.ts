@@ -0,0 +1,112 @@
+import { CallableCell } from "@holochain/tryorama";
+import { ActionHash, Record, Link } from "@holochain/client";
+import { OnBehalfOf } from "../utils.js";
+
+export type Urgency = "low" | "medium" | "high";
+
+export type ContactPreference = "email" | "phone" | "other";
+
+export type ListingState = "open" | "in progress" | "fulfilled" | "closed";
+
+export type Request = {
+  title: string;
+  description: string;
+  skills: string[];
+  urgency: Urgency;
+  time_estimate_hours?: number;
+  contact_preference: ContactPreference;
+  state: ListingState;
+  on_behalf_of?: OnBehalfOf;
+};
+
+export function sampleRequest(partialRequest: Partial<Request>): Request {
+  return {
+    ...{
+      title: "Request",
+      description: "Lorem ipsum dolor sit amet, consectetur adipiscing elit.",
+      skills: ["rust", "holochain"],
+      urgency: "medium",
+      time_estimate_hours: 2,
+      contact_preference: "email",
+      state: "open",
+    },
+    ...partialRequest,
+  };
+}
+
+export function createRequest(
+  cell: CallableCell,
+  request: Request,
+  organization_hash?: ActionHash
+): Promise<Record> {
+  return cell.callZome({
+    zome_name: "requests",
+    fn_name: "create_request",
+    payload: { request, organization_hash },
+  });
+}
+
+export function getLatestRequest(
+  cell: CallableCell,
+  original_action_hash: ActionHash
+): Promise<Request> {
+  return cell.callZome({
+    zome_name: "requests",
+    fn_name: "get_latest_request",
+    payload: original_action_hash,
+  });
+}
+
+export function getAllRequestsLinks(cell: CallableCell): Promise<Link[]> {
+  return cell.callZome({
+    zome_name: "requests",
+    fn_name: "get_all_requests_links",
+    payload: null,
+  });
+}
+
+export function getUserRequestsLinks(
+  cell: CallableCell,
+  user_original_action_hash: ActionHash
+): Promise<Link[]> {
+  return cell.callZome({
+    zome_name: "requests",
+    fn_name: "get_user_requests_links",
+    payload: user_original_action_hash,
+  });
+}
+
+export function getOrganizationRequestsLinks(
+  cell: CallableCell,
+  organization_original_action_hash: ActionHash
+): Promise<Link[]> {
+  return cell.callZome({
+    zome_name: "requests",
+    fn_name: "get_organization_requests_links",
+    payload: organization_original_action_hash,
+  });
+}
+
+export function updateRequest(
+  cell: CallableCell,
+  original_action_hash: ActionHash,
+  previous_action_hash: ActionHash,
+  updated_request: Request
+): Promise<Record> {
+  return cell.callZome({
+    zome_name: "requests",
+    fn_name: "update_request",
+    payload: { original_action_hash, previous_action_hash, updated_request },
+  });
+}
+
+export function deleteRequest(
+  cell: CallableCell,
+  request_original_action_hash: ActionHash
+): Promise<boolean> {
+  return cell.callZome({
+    zome_name: "requests",
+    fn_name: "delete_request",
+    payload: request_original_action_hash,
+  });
+}
@@ -0,0 +1,214 @@
+import { assert, expect, test } from "vitest";
+
+import { Scenario, Player, dhtSync } from "@holochain/tryorama";
+import { Record } from "@holochain/client";
+
+import { runScenarioWithTwoAgents } from "../utils.js";
+import { createUser, getAgentUser, sampleUser } from "../users/common";
+import {
+  AdministrationEntity,
+  getLatestStatusRecordForEntity,
+  registerNetworkAdministrator,
+  updateEntityStatus,
+} from "../administration/common";
+import {
+  createOrganization,
+  getOrganizationStatusLink,
+  sampleOrganization,
+} from "../organizations/common";
+import {
+  createRequest,
+  deleteRequest,
+  getAllRequestsLinks,
+  getLatestRequest,
+  getUserRequestsLinks,
+  sampleRequest,
+  updateRequest,
+} from "./common";
+
+test("create, update and delete Requests", async () => {
+  await runScenarioWithTwoAgents(
+    async (_scenario: Scenario, alice: Player, bob: Player) => {
+      // Alice tries to create a Request without a User profile
+      await expect(
+        createRequest(alice.cells[0], sampleRequest({}))
+      ).rejects.toThrow();
+
+      // Alice creates a User
+      const aliceUserRecord: Record = await createUser(
+        alice.cells[0],
+        sampleUser({ name: "Alice" })
+      );
+      assert.ok(aliceUserRecord);
+
+      // Alice creates a Request with an invalid urgency
+      await expect(
+        createRequest(
+          alice.cells[0],
+          sampleRequest({ urgency: "yesterday" as any })
+        )
+      ).rejects.toThrow();
+
+      // Alice creates a Request
+      const requestRecord: Record = await createRequest(
+        alice.cells[0],
+        sampleRequest({ title: "Need a code review" })
+      );
+      assert.ok(requestRecord);
+      const requestHash = requestRecord.signed_action.hashed.hash;
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      // Bob sees the Request in the global and user indexes
+      let links = await getAllRequestsLinks(bob.cells[0]);
+      assert.lengthOf(links, 1);
+
+      links = await getUserRequestsLinks(
+        bob.cells[0],
+        aliceUserRecord.signed_action.hashed.hash
+      );
+      assert.lengthOf(links, 1);
+
+      // Bob creates a User and tries to update Alice's Request
+      await createUser(bob.cells[0], sampleUser({ name: "Bob" }));
+      await expect(
+        updateRequest(
+          bob.cells[0],
+          requestHash,
+          requestHash,
+          sampleRequest({ title: "Hijacked" })
+        )
+      ).rejects.toThrow();
+
+      // Alice tries to mark her open Request as fulfilled directly
+      await expect(
+        updateRequest(
+          alice.cells[0],
+          requestHash,
+          requestHash,
+          sampleRequest({ state: "fulfilled" })
+        )
+      ).rejects.toThrow();
+
+      // Alice updates her Request and starts working on it
+      await updateRequest(
+        alice.cells[0],
+        requestHash,
+        requestHash,
+        sampleRequest({ title: "Need two code reviews", state: "in progress" })
+      );
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      const latestRequest = await getLatestRequest(bob.cells[0], requestHash);
+      assert.equal(latestRequest.title, "Need two code reviews");
+      assert.equal(latestRequest.state, "in progress");
+
+      // Bob tries to delete Alice's Request
+      await expect(deleteRequest(bob.cells[0], requestHash)).rejects.toThrow();
+
+      // Alice deletes her Request
+      await deleteRequest(alice.cells[0], requestHash);
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      links = await getAllRequestsLinks(bob.cells[0]);
+      assert.lengthOf(links, 0);
+    }
+  );
+});
+
+test("create Requests on behalf of an organization", async () => {
+  await runScenarioWithTwoAgents(
+    async (_scenario: Scenario, alice: Player, bob: Player) => {
+      await createUser(alice.cells[0], sampleUser({ name: "Alice" }));
+      await createUser(bob.cells[0], sampleUser({ name: "Bob" }));
+
+      // Alice creates an Organization and becomes its coordinator
+      const organizationRecord: Record = await createOrganization(
+        alice.cells[0],
+        sampleOrganization({ name: "Org" })
+      );
+      const organizationHash = organizationRecord.signed_action.hashed.hash;
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      // Alice cannot publish for the Organization while it is pending
+      await expect(
+        createRequest(alice.cells[0], sampleRequest({}), organizationHash)
+      ).rejects.toThrow();
+
+      // Alice becomes a network administrator and accepts the Organization
+      const aliceUserLink = (
+        await getAgentUser(alice.cells[0], alice.agentPubKey)
+      )[0];
+      await registerNetworkAdministrator(alice.cells[0], aliceUserLink.target, [
+        alice.agentPubKey,
+      ]);
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      const organizationStatusOriginalActionHash = (
+        await getOrganizationStatusLink(alice.cells[0], organizationHash)
+      ).target;
+      const organizationLatestStatusActionHash = (
+        await getLatestStatusRecordForEntity(
+          alice.cells[0],
+          AdministrationEntity.Organizations,
+          organizationHash
+        )
+      ).signed_action.hashed.hash;
+
+      await updateEntityStatus(
+        alice.cells[0],
+        AdministrationEntity.Organizations,
+        organizationHash,
+        organizationLatestStatusActionHash,
+        organizationStatusOriginalActionHash,
+        {
+          status_type: "accepted",
+        }
+      );
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      // Bob is not a coordinator of the Organization
+      await expect(
+        createRequest(bob.cells[0], sampleRequest({}), organizationHash)
+      ).rejects.toThrow();
+
+      // Alice publishes a Request for the Organization
+      const requestRecord: Record = await createRequest(
+        alice.cells[0],
+        sampleRequest({ title: "Organization request" }),
+        organizationHash
+      );
+      const requestHash = requestRecord.signed_action.hashed.hash;
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      const request = await getLatestRequest(bob.cells[0], requestHash);
+      assert.deepEqual(
+        request.on_behalf_of.organization_original_action_hash,
+        organizationHash
+      );
+
+      // The organization is kept when Alice updates the Request
+      await updateRequest(
+        alice.cells[0],
+        requestHash,
+        requestHash,
+        sampleRequest({ title: "Updated organization request" })
+      );
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      const updatedRequest = await getLatestRequest(bob.cells[0], requestHash);
+      assert.equal(updatedRequest.title, "Updated organization request");
+      assert.deepEqual(
+        updatedRequest.on_behalf_of.organization_original_action_hash,
+        organizationHash
+      );
+    }
+  );
+});
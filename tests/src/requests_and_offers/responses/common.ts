@@ -0,0 +1,100 @@
+import { CallableCell } from "@holochain/tryorama";
+import { ActionHash, Record, Link } from "@holochain/client";
+
+import { OnBehalfOf } from "../utils.js";
+
+export type ResponseStatus = "pending" | "accepted" | "declined";
+
+export type Response = {
+  item_original_action_hash: ActionHash;
+  message: string;
+  status: ResponseStatus;
+  decided_on_behalf_of?: OnBehalfOf | null;
+};
+
+export type ResponseWithUser = {
+  response_original_action_hash: ActionHash;
+  response: Response;
+  user_original_action_hash: ActionHash;
+  user_record?: Record;
+};
+
+export function createResponse(
+  cell: CallableCell,
+  item_original_action_hash: ActionHash,
+  message: string
+): Promise<Record> {
+  return cell.callZome({
+    zome_name: "responses",
+    fn_name: "create_response",
+    payload: { item_original_action_hash, message },
+  });
+}
+
+export function getLatestResponse(
+  cell: CallableCell,
+  original_action_hash: ActionHash
+): Promise<Response> {
+  return cell.callZome({
+    zome_name: "responses",
+    fn_name: "get_latest_response",
+    payload: original_action_hash,
+  });
+}
+
+export function getItemResponses(
+  cell: CallableCell,
+  item_original_action_hash: ActionHash
+): Promise<ResponseWithUser[]> {
+  return cell.callZome({
+    zome_name: "responses",
+    fn_name: "get_item_responses",
+    payload: item_original_action_hash,
+  });
+}
+
+export function getUserResponsesLinks(
+  cell: CallableCell,
+  user_original_action_hash: ActionHash
+): Promise<Link[]> {
+  return cell.callZome({
+    zome_name: "responses",
+    fn_name: "get_user_responses_links",
+    payload: user_original_action_hash,
+  });
+}
+
+export function acceptResponse(
+  cell: CallableCell,
+  original_action_hash: ActionHash,
+  previous_action_hash: ActionHash
+): Promise<Record> {
+  return cell.callZome({
+    zome_name: "responses",
+    fn_name: "accept_response",
+    payload: { original_action_hash, previous_action_hash },
+  });
+}
+
+export function declineResponse(
+  cell: CallableCell,
+  original_action_hash: ActionHash,
+  previous_action_hash: ActionHash
+): Promise<Record> {
+  return cell.callZome({
+    zome_name: "responses",
+    fn_name: "decline_response",
+    payload: { original_action_hash, previous_action_hash },
+  });
+}
+
+export function deleteResponse(
+  cell: CallableCell,
+  response_original_action_hash: ActionHash
+): Promise<boolean> {
+  return cell.callZome({
+    zome_name: "responses",
+    fn_name: "delete_response",
+    payload: response_original_action_hash,
+  });
+}
@@ -0,0 +1,89 @@
+import { assert, expect, test } from "vitest";
+
+import { Scenario, Player, dhtSync } from "@holochain/tryorama";
+import { Record } from "@holochain/client";
+
+import { runScenarioWithTwoAgents } from "../utils.js";
+import { createUser, sampleUser } from "../users/common";
+import { createRequest, sampleRequest } from "../requests/common";
+import {
+  acceptResponse,
+  createResponse,
+  declineResponse,
+  getItemResponses,
+  getLatestResponse,
+  getUserResponsesLinks,
+} from "./common";
+
+test("respond to a Request and accept the Response", async () => {
+  await runScenarioWithTwoAgents(
+    async (_scenario: Scenario, alice: Player, bob: Player) => {
+      await createUser(alice.cells[0], sampleUser({ name: "Alice" }));
+      const bobUserRecord: Record = await createUser(
+        bob.cells[0],
+        sampleUser({ name: "Bob" })
+      );
+
+      // Alice creates a Request
+      const requestRecord: Record = await createRequest(
+        alice.cells[0],
+        sampleRequest({})
+      );
+      const requestHash = requestRecord.signed_action.hashed.hash;
+
+      // Alice cannot respond to her own Request
+      await expect(
+        createResponse(alice.cells[0], requestHash, "I can do it myself")
+      ).rejects.toThrow();
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      // Bob responds to the Request
+      const responseRecord: Record = await createResponse(
+        bob.cells[0],
+        requestHash,
+        "I can help you with that"
+      );
+      assert.ok(responseRecord);
+      const responseHash = responseRecord.signed_action.hashed.hash;
+
+      // Bob cannot accept his own Response
+      await expect(
+        acceptResponse(bob.cells[0], responseHash, responseHash)
+      ).rejects.toThrow();
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      // Alice sees the Response along with Bob's profile
+      const responses = await getItemResponses(alice.cells[0], requestHash);
+      assert.lengthOf(responses, 1);
+      assert.equal(responses[0].response.status, "pending");
+      assert.deepEqual(
+        responses[0].user_original_action_hash,
+        bobUserRecord.signed_action.hashed.hash
+      );
+      assert.ok(responses[0].user_record);
+
+      const links = await getUserResponsesLinks(
+        alice.cells[0],
+        bobUserRecord.signed_action.hashed.hash
+      );
+      assert.lengthOf(links, 1);
+
+      // Alice accepts the Response
+      await acceptResponse(alice.cells[0], responseHash, responseHash);
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      const response = await getLatestResponse(bob.cells[0], responseHash);
+      assert.equal(response.status, "accepted");
+      // Alice authored the Request, so she decided for herself
+      assert.isNull(response.decided_on_behalf_of);
+
+      // An answered Response cannot be declined anymore
+      await expect(
+        declineResponse(alice.cells[0], responseHash, responseHash)
+      ).rejects.toThrow();
+    }
+  );
+});
@@ -0,0 +1,92 @@
+import { CallableCell } from "@holochain/tryorama";
+import { ActionHash, Record, Link } from "@holochain/client";
+
+export type Skill = {
+  name: string;
+};
+
+export function getAllSkillsLinks(cell: CallableCell): Promise<Link[]> {
+  return cell.callZome({
+    zome_name: "skills",
+    fn_name: "get_all_skills_links",
+    payload: null,
+  });
+}
+
+export function getSkillLink(
+  cell: CallableCell,
+  name: string
+): Promise<Link | null> {
+  return cell.callZome({
+    zome_name: "skills",
+    fn_name: "get_skill_link",
+    payload: name,
+  });
+}
+
+export function getSkill(
+  cell: CallableCell,
+  skill_action_hash: ActionHash
+): Promise<Record | null> {
+  return cell.callZome({
+    zome_name: "skills",
+    fn_name: "get_skill",
+    payload: skill_action_hash,
+  });
+}
+
+export function getOrCreateSkill(
+  cell: CallableCell,
+  name: string
+): Promise<ActionHash> {
+  return cell.callZome({
+    zome_name: "skills",
+    fn_name: "get_or_create_skill",
+    payload: name,
+  });
+}
+
+export function getUserSkillsLinks(
+  cell: CallableCell,
+  user_original_action_hash: ActionHash
+): Promise<Link[]> {
+  return cell.callZome({
+    zome_name: "skills",
+    fn_name: "get_user_skills_links",
+    payload: user_original_action_hash,
+  });
+}
+
+export function getSkillUsersLinks(
+  cell: CallableCell,
+  skill_action_hash: ActionHash
+): Promise<Link[]> {
+  return cell.callZome({
+    zome_name: "skills",
+    fn_name: "get_skill_users_links",
+    payload: skill_action_hash,
+  });
+}
+
+export function setSkillAliases(
+  cell: CallableCell,
+  skill: string,
+  aliases: string[]
+): Promise<ActionHash> {
+  return cell.callZome({
+    zome_name: "skills",
+    fn_name: "set_skill_aliases",
+    payload: { skill, aliases },
+  });
+}
+
+export function getCanonicalSkillNames(
+  cell: CallableCell,
+  names: string[]
+): Promise<string[]> {
+  return cell.callZome({
+    zome_name: "skills",
+    fn_name: "get_canonical_skill_names",
+    payload: names,
+  });
+}
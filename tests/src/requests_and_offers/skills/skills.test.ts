@@ -0,0 +1,163 @@
+import { assert, expect, test } from "vitest";
+
+import { Scenario, Player, dhtSync } from "@holochain/tryorama";
+import { Record } from "@holochain/client";
+
+import { runScenarioWithTwoAgents } from "../utils.js";
+import {
+  createUser,
+  getLatestUserProfile,
+  migrateMyUserSkills,
+  sampleUser,
+  updateUser,
+  UserSkill,
+} from "../users/common.js";
+import { registerNetworkAdministrator } from "../administration/common";
+import {
+  getAllSkillsLinks,
+  getCanonicalSkillNames,
+  getOrCreateSkill,
+  getSkillLink,
+  getSkillUsersLinks,
+  getUserSkillsLinks,
+  setSkillAliases,
+} from "./common";
+
+test("skill names resolve to canonical Skills", async () => {
+  await runScenarioWithTwoAgents(
+    async (_scenario: Scenario, alice: Player, bob: Player) => {
+      const aliceUserRecord: Record = await createUser(
+        alice.cells[0],
+        sampleUser({ name: "Alice", skills: ["Rust", "Web  Design"] })
+      );
+      const aliceUserHash = aliceUserRecord.signed_action.hashed.hash;
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      const bobUserRecord: Record = await createUser(
+        bob.cells[0],
+        sampleUser({ name: "Bob", skills: [" rust", "css"] })
+      );
+      const bobUserHash = bobUserRecord.signed_action.hashed.hash;
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      // "Rust" and " rust" are the same skill
+      assert.lengthOf(await getAllSkillsLinks(alice.cells[0]), 3);
+
+      const rustLink = await getSkillLink(alice.cells[0], "RUST");
+      assert.ok(rustLink);
+      assert.equal(new TextDecoder().decode(rustLink!.tag), "rust");
+      assert.deepEqual(
+        await getOrCreateSkill(alice.cells[0], "rust"),
+        rustLink!.target
+      );
+
+      const webDesignLink = await getSkillLink(alice.cells[0], "web design");
+      assert.ok(webDesignLink);
+
+      let rustUsers = await getSkillUsersLinks(
+        alice.cells[0],
+        rustLink!.target
+      );
+      assert.sameDeepMembers(
+        rustUsers.map((link) => link.target),
+        [aliceUserHash, bobUserHash]
+      );
+      assert.lengthOf(await getUserSkillsLinks(bob.cells[0], bobUserHash), 2);
+
+      // Bob drops Rust
+      await updateUser(
+        bob.cells[0],
+        bobUserHash,
+        bobUserHash,
+        sampleUser({ name: "Bob", skills: ["CSS"] })
+      );
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      rustUsers = await getSkillUsersLinks(alice.cells[0], rustLink!.target);
+      assert.lengthOf(rustUsers, 1);
+      assert.deepEqual(rustUsers[0].target, aliceUserHash);
+      assert.lengthOf(await getUserSkillsLinks(alice.cells[0], bobUserHash), 1);
+
+      // Migrating an already linked profile changes nothing
+      const skillHashes = await migrateMyUserSkills(alice.cells[0]);
+      assert.lengthOf(skillHashes, 2);
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      assert.lengthOf(
+        await getUserSkillsLinks(bob.cells[0], aliceUserHash),
+        2
+      );
+    }
+  );
+});
+
+test("administrators merge skills through aliases", async () => {
+  await runScenarioWithTwoAgents(
+    async (_scenario: Scenario, alice: Player, bob: Player) => {
+      const aliceUserRecord: Record = await createUser(
+        alice.cells[0],
+        sampleUser({ name: "Alice", skills: ["javascript"] })
+      );
+      const aliceUserHash = aliceUserRecord.signed_action.hashed.hash;
+      const bobUserRecord: Record = await createUser(
+        bob.cells[0],
+        sampleUser({ name: "Bob", skills: ["JS", "css"] })
+      );
+      const bobUserHash = bobUserRecord.signed_action.hashed.hash;
+
+      await registerNetworkAdministrator(alice.cells[0], aliceUserHash, [
+        alice.agentPubKey,
+      ]);
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      // Only administrators can declare aliases
+      await expect(
+        setSkillAliases(bob.cells[0], "javascript", ["js"])
+      ).rejects.toThrow();
+
+      const javascriptHash = await setSkillAliases(
+        alice.cells[0],
+        "JavaScript",
+        ["js", "Java Script"]
+      );
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      assert.deepEqual(
+        await getCanonicalSkillNames(bob.cells[0], ["JS", "javascript", "Go"]),
+        ["javascript", "go"]
+      );
+
+      // Bob is found and displayed with the canonical skill without editing his profile
+      const bobProfile = await getLatestUserProfile(
+        alice.cells[0],
+        bobUserHash
+      );
+      assert.deepEqual(
+        bobProfile.canonical_skills.map((skill) => skill.name),
+        ["javascript", "css"]
+      );
+      assert.deepEqual(
+        bobProfile.user.skills.map((skill) => (skill as UserSkill).name),
+        ["JS", "css"]
+      );
+
+      const javascriptUsers = await getSkillUsersLinks(
+        alice.cells[0],
+        javascriptHash
+      );
+      assert.sameDeepMembers(
+        javascriptUsers.map((link) => link.target),
+        [aliceUserHash, bobUserHash]
+      );
+
+      const jsLink = await getSkillLink(bob.cells[0], "js");
+      assert.deepEqual(jsLink!.target, javascriptHash);
+    }
+  );
+});
@@ -0,0 +1,122 @@
+import { assert, expect, test } from "vitest";
+
+import { Scenario, Player, dhtSync } from "@holochain/tryorama";
+import { Record } from "@holochain/client";
+
+import {
+  sampleUser,
+  createUser,
+  createAvailability,
+  getUserAvailability,
+  updateAvailability,
+  getUserAvailabilitySlots,
+} from "./common.js";
+import { runScenarioWithTwoAgents } from "../utils.js";
+
+const MINUTE = 60 * 1_000_000;
+const WEEK = 7 * 24 * 60 * MINUTE;
+
+test("create and expand a weekly Availability", async () => {
+  await runScenarioWithTwoAgents(
+    async (_scenario: Scenario, alice: Player, bob: Player) => {
+      const userRecord: Record = await createUser(
+        alice.cells[0],
+        sampleUser({ name: "Alice", time_zone: "UTC+02:00" })
+      );
+      const userHash = userRecord.signed_action.hashed.hash;
+
+      // Alice is available one hour every Tuesday evening
+      const availabilityRecord: Record = await createAvailability(
+        alice.cells[0],
+        {
+          windows: [{ weekday: 1, start_minute: 18 * 60, duration_minutes: 60 }],
+        }
+      );
+      const availabilityHash = availabilityRecord.signed_action.hashed.hash;
+
+      // Alice can only have one Availability
+      await expect(
+        createAvailability(alice.cells[0], { windows: [] })
+      ).rejects.toThrow();
+
+      // Windows must be within a week
+      await expect(
+        updateAvailability(alice.cells[0], availabilityHash, availabilityHash, {
+          windows: [{ weekday: 7, start_minute: 0, duration_minutes: 60 }],
+        })
+      ).rejects.toThrow();
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      const availability = await getUserAvailability(bob.cells[0], userHash);
+      assert.lengthOf(availability.windows, 1);
+
+      // Bob expands Alice's next slots, one week apart
+      const slots = await getUserAvailabilitySlots(bob.cells[0], userHash, 3);
+      assert.lengthOf(slots, 3);
+      assert.equal(slots[0].end - slots[0].start, 60 * MINUTE);
+      assert.equal(slots[1].start - slots[0].start, WEEK);
+      assert.equal(slots[2].start - slots[1].start, WEEK);
+
+      // Tuesday 18:00 in UTC+02:00 is 16:00 UTC
+      const firstSlot = new Date(slots[0].start / 1000);
+      assert.equal(firstSlot.getUTCDay(), 2);
+      assert.equal(firstSlot.getUTCHours(), 16);
+
+      // Bob cannot update Alice's Availability
+      await expect(
+        updateAvailability(bob.cells[0], availabilityHash, availabilityHash, {
+          windows: [],
+        })
+      ).rejects.toThrow();
+
+      // Alice adds a Saturday morning window
+      await updateAvailability(
+        alice.cells[0],
+        availabilityHash,
+        availabilityHash,
+        {
+          windows: [
+            { weekday: 1, start_minute: 18 * 60, duration_minutes: 60 },
+            { weekday: 5, start_minute: 9 * 60, duration_minutes: 120 },
+          ],
+        }
+      );
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      const updatedSlots = await getUserAvailabilitySlots(
+        bob.cells[0],
+        userHash,
+        4
+      );
+      assert.lengthOf(updatedSlots, 4);
+
+      // Bob's profile uses an IANA time zone, whose offset follows daylight saving time
+      const bobUserRecord: Record = await createUser(
+        bob.cells[0],
+        sampleUser({ name: "Bob", time_zone: "Europe/Paris" })
+      );
+      await createAvailability(bob.cells[0], {
+        windows: [{ weekday: 1, start_minute: 18 * 60, duration_minutes: 60 }],
+      });
+
+      await dhtSync([alice, bob], bob.cells[0].cell_id[0]);
+
+      const bobSlots = await getUserAvailabilitySlots(
+        alice.cells[0],
+        bobUserRecord.signed_action.hashed.hash,
+        3
+      );
+      assert.lengthOf(bobSlots, 3);
+      for (const slot of bobSlots) {
+        const parisHour = new Date(slot.start / 1000).toLocaleString("en-GB", {
+          timeZone: "Europe/Paris",
+          hour: "2-digit",
+          hour12: false,
+        });
+        assert.equal(parisHour, "18");
+      }
+    }
+  );
+});
@@ -3,13 +3,22 @@ import { ActionHash, Record, Link, AgentPubKey } from "@holochain/client";
 
 export type UserType = "advocate" | "creator" | "Non Authorized";
 
+export type ProficiencyLevel = "learning" | "intermediate" | "expert";
+
+export type UserSkill = {
+  name: string;
+  proficiency?: ProficiencyLevel | null;
+  years_of_experience?: number | null;
+};
+
 export type User = {
   name: string;
   nickname: string;
   bio: string;
   picture?: Uint8Array;
   user_type: UserType;
-  skills: string[];
+  // Plain names are still accepted, as listed by profiles created before proficiency levels
+  skills: (UserSkill | string)[];
   email: string;
   phone?: string;
   time_zone: string;
@@ -67,6 +76,33 @@ export async function getLatestUser(
   });
 }
 
+export async function getLatestUserEntry(
+  cell: CallableCell,
+  original_action_hash: ActionHash
+): Promise<User> {
+  return cell.callZome({
+    zome_name: "users_organizations",
+    fn_name: "get_latest_user",
+    payload: original_action_hash,
+  });
+}
+
+export type UserProfile = {
+  user: User;
+  canonical_skills: UserSkill[];
+};
+
+export async function getLatestUserProfile(
+  cell: CallableCell,
+  original_action_hash: ActionHash
+): Promise<UserProfile> {
+  return cell.callZome({
+    zome_name: "users_organizations",
+    fn_name: "get_latest_user_profile",
+    payload: original_action_hash,
+  });
+}
+
 export async function getAgentUser(
   cell: CallableCell,
   author: AgentPubKey
@@ -111,3 +147,187 @@ export async function updateUser(
     payload: { original_action_hash, previous_action_hash, updated_user },
   });
 }
+
+export function saveUserDraft(
+  cell: CallableCell,
+  user: User,
+  previous_draft_action_hash?: ActionHash
+): Promise<Record> {
+  return cell.callZome({
+    zome_name: "users_organizations",
+    fn_name: "save_user_draft",
+    payload: { previous_draft_action_hash, user },
+  });
+}
+
+export function getMyUserDrafts(cell: CallableCell): Promise<Record[]> {
+  return cell.callZome({
+    zome_name: "users_organizations",
+    fn_name: "get_my_user_drafts",
+    payload: null,
+  });
+}
+
+export function discardUserDraft(
+  cell: CallableCell,
+  draft_action_hash: ActionHash
+): Promise<ActionHash> {
+  return cell.callZome({
+    zome_name: "users_organizations",
+    fn_name: "discard_user_draft",
+    payload: draft_action_hash,
+  });
+}
+
+export function publishUserDraft(
+  cell: CallableCell,
+  draft_action_hash: ActionHash
+): Promise<Record> {
+  return cell.callZome({
+    zome_name: "users_organizations",
+    fn_name: "publish_user_draft",
+    payload: draft_action_hash,
+  });
+}
+
+export type AvailabilityWindow = {
+  weekday: number;
+  start_minute: number;
+  duration_minutes: number;
+};
+
+export type Availability = {
+  windows: AvailabilityWindow[];
+};
+
+export type AvailabilitySlot = {
+  start: number;
+  end: number;
+};
+
+export function createAvailability(
+  cell: CallableCell,
+  availability: Availability
+): Promise<Record> {
+  return cell.callZome({
+    zome_name: "users_organizations",
+    fn_name: "create_availability",
+    payload: availability,
+  });
+}
+
+export function getUserAvailability(
+  cell: CallableCell,
+  user_original_action_hash: ActionHash
+): Promise<Availability | null> {
+  return cell.callZome({
+    zome_name: "users_organizations",
+    fn_name: "get_user_availability",
+    payload: user_original_action_hash,
+  });
+}
+
+export function updateAvailability(
+  cell: CallableCell,
+  original_action_hash: ActionHash,
+  previous_action_hash: ActionHash,
+  updated_availability: Availability
+): Promise<Record> {
+  return cell.callZome({
+    zome_name: "users_organizations",
+    fn_name: "update_availability",
+    payload: { original_action_hash, previous_action_hash, updated_availability },
+  });
+}
+
+export function getUserAvailabilitySlots(
+  cell: CallableCell,
+  user_original_action_hash: ActionHash,
+  count: number
+): Promise<AvailabilitySlot[]> {
+  return cell.callZome({
+    zome_name: "users_organizations",
+    fn_name: "get_user_availability_slots",
+    payload: { user_original_action_hash, count },
+  });
+}
+
+export type UserSkillsMatch = {
+  user_original_action_hash: ActionHash;
+  user: User;
+  matching_skills: string[];
+};
+
+export function findUsersBySkills(
+  cell: CallableCell,
+  skills: string[]
+): Promise<UserSkillsMatch[]> {
+  return cell.callZome({
+    zome_name: "users_organizations",
+    fn_name: "find_users_by_skills",
+    payload: skills,
+  });
+}
+
+export function migrateMyUserSkills(cell: CallableCell): Promise<ActionHash[]> {
+  return cell.callZome({
+    zome_name: "users_organizations",
+    fn_name: "migrate_my_user_skills",
+    payload: null,
+  });
+}
+
+export type SkillEndorsements = {
+  skill: string;
+  count: number;
+};
+
+export type UserWithEndorsements = {
+  user: User;
+  skill_endorsements: SkillEndorsements[];
+};
+
+export function endorseUserSkill(
+  cell: CallableCell,
+  user_original_action_hash: ActionHash,
+  skill: string
+): Promise<Record> {
+  return cell.callZome({
+    zome_name: "users_organizations",
+    fn_name: "endorse_user_skill",
+    payload: { user_original_action_hash, skill },
+  });
+}
+
+export function getUserEndorsementsLinks(
+  cell: CallableCell,
+  user_original_action_hash: ActionHash
+): Promise<Link[]> {
+  return cell.callZome({
+    zome_name: "users_organizations",
+    fn_name: "get_user_endorsements_links",
+    payload: user_original_action_hash,
+  });
+}
+
+export function getLatestUserWithEndorsements(
+  cell: CallableCell,
+  original_action_hash: ActionHash
+): Promise<UserWithEndorsements> {
+  return cell.callZome({
+    zome_name: "users_organizations",
+    fn_name: "get_latest_user_with_endorsements",
+    payload: original_action_hash,
+  });
+}
+
+export function withdrawEndorsement(
+  cell: CallableCell,
+  endorsement_action_hash: ActionHash
+): Promise<boolean> {
+  return cell.callZome({
+    zome_name: "users_organizations",
+    fn_name: "withdraw_endorsement",
+    payload: endorsement_action_hash,
+  });
+}
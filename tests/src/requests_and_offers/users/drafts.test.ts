@@ -0,0 +1,105 @@
+import { assert, expect, test } from "vitest";
+
+import { Scenario, Player, dhtSync } from "@holochain/tryorama";
+import { Record } from "@holochain/client";
+
+import {
+  User,
+  sampleUser,
+  getAgentUser,
+  getUserStatusLink,
+  saveUserDraft,
+  getMyUserDrafts,
+  discardUserDraft,
+  publishUserDraft,
+} from "./common.js";
+import {
+  Organization,
+  sampleOrganization,
+  getAllOrganizationsLinks,
+  saveOrganizationDraft,
+  getMyOrganizationDrafts,
+  publishOrganizationDraft,
+} from "../organizations/common.js";
+import { getAllUsers } from "../administration/common";
+import { decodeRecords, runScenarioWithTwoAgents } from "../utils.js";
+
+test("save, discard and publish User and Organization drafts", async () => {
+  await runScenarioWithTwoAgents(
+    async (_scenario: Scenario, alice: Player, bob: Player) => {
+      // Alice saves an incomplete User draft and revises it
+      let draftRecord: Record = await saveUserDraft(
+        alice.cells[0],
+        sampleUser({ name: "Alice", email: "" })
+      );
+      draftRecord = await saveUserDraft(
+        alice.cells[0],
+        sampleUser({ name: "Alice" }),
+        draftRecord.signed_action.hashed.hash
+      );
+
+      let drafts = await getMyUserDrafts(alice.cells[0]);
+      assert.lengthOf(drafts, 1);
+      const [draft] = decodeRecords(drafts) as { user: User }[];
+      assert.equal(draft.user.email, sampleUser({}).email);
+
+      // Alice saves and discards a second draft
+      const otherDraftRecord: Record = await saveUserDraft(
+        alice.cells[0],
+        sampleUser({ name: "Other Alice" })
+      );
+      assert.lengthOf(await getMyUserDrafts(alice.cells[0]), 2);
+      await discardUserDraft(
+        alice.cells[0],
+        otherDraftRecord.signed_action.hashed.hash
+      );
+      assert.lengthOf(await getMyUserDrafts(alice.cells[0]), 1);
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      // Nothing is published while Alice only has drafts
+      assert.lengthOf(await getAllUsers(bob.cells[0]), 0);
+
+      // Bob cannot publish Alice's draft
+      await expect(
+        publishUserDraft(bob.cells[0], draftRecord.signed_action.hashed.hash)
+      ).rejects.toThrow();
+
+      // Alice publishes her draft
+      const userRecord: Record = await publishUserDraft(
+        alice.cells[0],
+        draftRecord.signed_action.hashed.hash
+      );
+      const userHash = userRecord.signed_action.hashed.hash;
+      assert.lengthOf(await getMyUserDrafts(alice.cells[0]), 0);
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      assert.lengthOf(await getAllUsers(bob.cells[0]), 1);
+      assert.lengthOf(await getAgentUser(bob.cells[0], alice.agentPubKey), 1);
+      assert.ok(await getUserStatusLink(bob.cells[0], userHash));
+
+      // Alice drafts and publishes an Organization
+      const organizationDraftRecord: Record = await saveOrganizationDraft(
+        alice.cells[0],
+        sampleOrganization({ name: "Org" })
+      );
+      const [organizationDraft] = decodeRecords(
+        await getMyOrganizationDrafts(alice.cells[0])
+      ) as { organization: Organization }[];
+      assert.equal(organizationDraft.organization.name, "Org");
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+      assert.lengthOf(await getAllOrganizationsLinks(bob.cells[0]), 0);
+
+      await publishOrganizationDraft(
+        alice.cells[0],
+        organizationDraftRecord.signed_action.hashed.hash
+      );
+      assert.lengthOf(await getMyOrganizationDrafts(alice.cells[0]), 0);
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+      assert.lengthOf(await getAllOrganizationsLinks(bob.cells[0]), 1);
+    }
+  );
+});
@@ -0,0 +1,152 @@
+import { assert, expect, test } from "vitest";
+
+import { Scenario, Player, dhtSync } from "@holochain/tryorama";
+import { ActionHash, Record } from "@holochain/client";
+
+import {
+  sampleUser,
+  createUser,
+  getUserStatusLink,
+  endorseUserSkill,
+  getUserEndorsementsLinks,
+  getLatestUserWithEndorsements,
+  withdrawEndorsement,
+} from "./common.js";
+import {
+  AdministrationEntity,
+  getLatestStatusRecordForEntity,
+  registerNetworkAdministrator,
+  updateEntityStatus,
+} from "../administration/common";
+import { runScenarioWithTwoAgents } from "../utils.js";
+
+test("accepted Users endorse each other's skills", async () => {
+  await runScenarioWithTwoAgents(
+    async (_scenario: Scenario, alice: Player, bob: Player) => {
+      const aliceUserRecord: Record = await createUser(
+        alice.cells[0],
+        sampleUser({ name: "Alice", skills: ["rust", "Holochain"] })
+      );
+      const aliceUserHash = aliceUserRecord.signed_action.hashed.hash;
+      const bobUserRecord: Record = await createUser(
+        bob.cells[0],
+        sampleUser({ name: "Bob", skills: ["css"] })
+      );
+      const bobUserHash = bobUserRecord.signed_action.hashed.hash;
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      // Pending users cannot endorse skills
+      await expect(
+        endorseUserSkill(bob.cells[0], aliceUserHash, "rust")
+      ).rejects.toThrow();
+
+      await registerNetworkAdministrator(alice.cells[0], aliceUserHash, [
+        alice.agentPubKey,
+      ]);
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      const statusOriginalActionHash: ActionHash = (
+        await getUserStatusLink(alice.cells[0], bobUserHash)
+      ).target;
+      const latestStatusActionHash = (
+        await getLatestStatusRecordForEntity(
+          alice.cells[0],
+          AdministrationEntity.Users,
+          bobUserHash
+        )
+      ).signed_action.hashed.hash;
+      await updateEntityStatus(
+        alice.cells[0],
+        AdministrationEntity.Users,
+        bobUserHash,
+        latestStatusActionHash,
+        statusOriginalActionHash,
+        {
+          status_type: "accepted",
+        }
+      );
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      // Users cannot endorse their own skills or skills Alice does not list
+      await expect(
+        endorseUserSkill(bob.cells[0], bobUserHash, "css")
+      ).rejects.toThrow();
+      await expect(
+        endorseUserSkill(bob.cells[0], aliceUserHash, "css")
+      ).rejects.toThrow();
+
+      const endorsementRecord = await endorseUserSkill(
+        bob.cells[0],
+        aliceUserHash,
+        " Rust"
+      );
+      assert.ok(endorsementRecord);
+
+      // Endorsing the same skill twice is rejected
+      await expect(
+        endorseUserSkill(bob.cells[0], aliceUserHash, "rust")
+      ).rejects.toThrow();
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      assert.lengthOf(
+        await getUserEndorsementsLinks(alice.cells[0], aliceUserHash),
+        1
+      );
+
+      let aliceWithEndorsements = await getLatestUserWithEndorsements(
+        alice.cells[0],
+        aliceUserHash
+      );
+      assert.equal(aliceWithEndorsements.user.name, "Alice");
+      assert.deepEqual(aliceWithEndorsements.skill_endorsements, [
+        { skill: "rust", count: 1 },
+        { skill: "holochain", count: 0 },
+      ]);
+
+      // Alice cannot withdraw Bob's endorsement
+      await expect(
+        withdrawEndorsement(
+          alice.cells[0],
+          endorsementRecord.signed_action.hashed.hash
+        )
+      ).rejects.toThrow();
+
+      await withdrawEndorsement(
+        bob.cells[0],
+        endorsementRecord.signed_action.hashed.hash
+      );
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      aliceWithEndorsements = await getLatestUserWithEndorsements(
+        alice.cells[0],
+        aliceUserHash
+      );
+      assert.deepEqual(aliceWithEndorsements.skill_endorsements, [
+        { skill: "rust", count: 0 },
+        { skill: "holochain", count: 0 },
+      ]);
+
+      // A withdrawn endorsement can be made again
+      await endorseUserSkill(bob.cells[0], aliceUserHash, "rust");
+
+      // Skills listed with another casing are endorsed under their canonical name
+      await endorseUserSkill(bob.cells[0], aliceUserHash, "holochain");
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      aliceWithEndorsements = await getLatestUserWithEndorsements(
+        alice.cells[0],
+        aliceUserHash
+      );
+      assert.deepEqual(aliceWithEndorsements.skill_endorsements, [
+        { skill: "rust", count: 1 },
+        { skill: "holochain", count: 1 },
+      ]);
+    }
+  );
+});
@@ -0,0 +1,157 @@
+import { assert, expect, test } from "vitest";
+
+import { Scenario, Player, dhtSync } from "@holochain/tryorama";
+import { ActionHash, Record } from "@holochain/client";
+
+import {
+  sampleUser,
+  createUser,
+  getAgentUser,
+  getUserStatusLink,
+  updateUser,
+  findUsersBySkills,
+  getLatestUserProfile,
+  UserSkill,
+} from "./common.js";
+import {
+  AdministrationEntity,
+  getLatestStatusRecordForEntity,
+  registerNetworkAdministrator,
+  updateEntityStatus,
+} from "../administration/common";
+import { runScenarioWithTwoAgents } from "../utils.js";
+
+test("find accepted Users by skills", async () => {
+  await runScenarioWithTwoAgents(
+    async (_scenario: Scenario, alice: Player, bob: Player) => {
+      const aliceUserRecord: Record = await createUser(
+        alice.cells[0],
+        sampleUser({ name: "Alice", skills: ["rust", "holochain"] })
+      );
+      const aliceUserHash = aliceUserRecord.signed_action.hashed.hash;
+      const bobUserRecord: Record = await createUser(
+        bob.cells[0],
+        sampleUser({ name: "Bob", skills: ["Rust", "css"] })
+      );
+      const bobUserHash = bobUserRecord.signed_action.hashed.hash;
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      // Pending users are not returned
+      assert.lengthOf(await findUsersBySkills(bob.cells[0], ["rust"]), 0);
+
+      // Alice becomes a network administrator and accepts both users
+      const aliceUserLink = (
+        await getAgentUser(alice.cells[0], alice.agentPubKey)
+      )[0];
+      await registerNetworkAdministrator(alice.cells[0], aliceUserLink.target, [
+        alice.agentPubKey,
+      ]);
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      for (const userHash of [aliceUserHash, bobUserHash]) {
+        const statusOriginalActionHash: ActionHash = (
+          await getUserStatusLink(alice.cells[0], userHash)
+        ).target;
+        const latestStatusActionHash = (
+          await getLatestStatusRecordForEntity(
+            alice.cells[0],
+            AdministrationEntity.Users,
+            userHash
+          )
+        ).signed_action.hashed.hash;
+
+        await updateEntityStatus(
+          alice.cells[0],
+          AdministrationEntity.Users,
+          userHash,
+          latestStatusActionHash,
+          statusOriginalActionHash,
+          {
+            status_type: "accepted",
+          }
+        );
+      }
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      // Users are ranked by the number of matching skills
+      let matches = await findUsersBySkills(bob.cells[0], [
+        "Rust",
+        "holochain",
+      ]);
+      assert.lengthOf(matches, 2);
+      assert.equal(matches[0].user.name, "Alice");
+      assert.sameMembers(matches[0].matching_skills, ["rust", "holochain"]);
+      assert.equal(matches[1].user.name, "Bob");
+      assert.deepEqual(matches[1].matching_skills, ["rust"]);
+
+      // Bob replaces Rust by Holochain
+      await updateUser(
+        bob.cells[0],
+        bobUserHash,
+        bobUserHash,
+        sampleUser({ name: "Bob", skills: ["holochain", "css"] })
+      );
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      matches = await findUsersBySkills(alice.cells[0], ["rust"]);
+      assert.lengthOf(matches, 1);
+      assert.equal(matches[0].user.name, "Alice");
+
+      matches = await findUsersBySkills(alice.cells[0], ["css"]);
+      assert.lengthOf(matches, 1);
+      assert.equal(matches[0].user.name, "Bob");
+    }
+  );
+});
+
+test("User skills carry proficiency levels", async () => {
+  await runScenarioWithTwoAgents(
+    async (_scenario: Scenario, alice: Player, bob: Player) => {
+      const aliceUserRecord: Record = await createUser(
+        alice.cells[0],
+        sampleUser({
+          name: "Alice",
+          skills: [
+            { name: "Rust", proficiency: "expert", years_of_experience: 6 },
+            { name: "Holochain", proficiency: "learning" },
+            // Skills listed as plain names have no proficiency level
+            "CSS",
+          ],
+        })
+      );
+      const aliceUserHash = aliceUserRecord.signed_action.hashed.hash;
+
+      await dhtSync([alice, bob], alice.cells[0].cell_id[0]);
+
+      const aliceProfile = await getLatestUserProfile(
+        bob.cells[0],
+        aliceUserHash
+      );
+      assert.deepEqual(aliceProfile.canonical_skills, [
+        { name: "rust", proficiency: "expert", years_of_experience: 6 },
+        { name: "holochain", proficiency: "learning", years_of_experience: null },
+        { name: "css", proficiency: null, years_of_experience: null },
+      ]);
+
+      // The stored profile keeps the skill names Alice wrote
+      assert.deepEqual(
+        aliceProfile.user.skills.map((skill) => (skill as UserSkill).name),
+        ["Rust", "Holochain", "CSS"]
+      );
+
+      await expect(
+        createUser(
+          bob.cells[0],
+          sampleUser({
+            name: "Bob",
+            skills: [{ name: "Rust", proficiency: "guru" as any }],
+          })
+        )
+      ).rejects.toThrow();
+    }
+  );
+});
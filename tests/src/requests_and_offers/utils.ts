@@ -1,6 +1,7 @@
 import fs from "fs";
 import { Conductor, Player, Scenario, runScenario } from "@holochain/tryorama";
 import {
+  ActionHash,
   AppRoleManifest,
   AppWebsocket,
   Record,
@@ -13,8 +14,15 @@ import { decompressSync } from "fflate";
 const hAppPath = process.cwd() + "/../workdir/requests_and_offers.happ";
 const appSource = { appBundleSource: { path: hAppPath } };
 
+export type OnBehalfOf = {
+  organization_original_action_hash: ActionHash;
+  coordinator_link: ActionHash;
+  user_agent_link: ActionHash;
+};
+
 export type DnaProperties = {
   progenitor_pubkey: string;
+  mutual_credit_limit?: number;
 };
 
 export async function runScenarioWithTwoAgents(
@@ -17,7 +17,10 @@ export async function createMockedUsers(count: number = 1): Promise<UserInDHT[]>
       bio: faker.lorem.paragraphs(getRandomNumber(2, 5)),
       picture: await fetchImageAndConvertToUInt8Array('https://picsum.photos/200/300'),
       user_type: fakedUserType,
-      skills: ['JavaScript', 'Svelte', 'SvelteKit', 'Rust', 'WebAssembly'],
+      skills: ['JavaScript', 'Svelte', 'SvelteKit', 'Rust', 'WebAssembly'].map((name) => ({
+        name,
+        proficiency: 'intermediate' as const
+      })),
       email: faker.internet.email(),
       phone: '123456789',
       time_zone: 'Europe/Paris',
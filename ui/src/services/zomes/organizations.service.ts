@@ -80,14 +80,14 @@ export class OrganizationsService {
     })) as boolean;
   }
 
-  static async addOrganizationCoordinator(
+  static async inviteOrganizationCoordinator(
     organization_original_action_hash: ActionHash,
     user_original_action_hash: ActionHash
-  ): Promise<boolean> {
-    return (await hc.callZome('users_organizations', 'add_coordinator_to_organization', {
+  ): Promise<Record> {
+    return (await hc.callZome('users_organizations', 'invite_coordinator_to_organization', {
       organization_original_action_hash,
       user_original_action_hash
-    })) as boolean;
+    })) as Record;
   }
 
   static async removeOrganizationCoordinator(
@@ -137,18 +137,15 @@ class OrganizationsStore {
     return success;
   }
 
-  async addCoordinator(
+  async inviteCoordinator(
     organization_original_action_hash: ActionHash,
     coordinatorActionHash: ActionHash
   ): Promise<boolean> {
-    const success = await OrganizationsService.addOrganizationCoordinator(
+    const record = await OrganizationsService.inviteOrganizationCoordinator(
       organization_original_action_hash,
       coordinatorActionHash
     );
-    if (success) {
-      await this.refreshOrganization(organization_original_action_hash);
-    }
-    return success;
+    return !!record;
   }
 
   async removeCoordinator(
@@ -7,13 +7,21 @@ export type StatusType =
   | 'suspended temporarily'
   | 'suspended indefinitely';
 
+export type ProficiencyLevel = 'learning' | 'intermediate' | 'expert';
+
+export type UserSkill = {
+  name: string;
+  proficiency?: ProficiencyLevel;
+  years_of_experience?: number;
+};
+
 export type UserInDHT = {
   name: string;
   nickname: string;
   bio?: string;
   picture?: Uint8Array;
   user_type: UserType;
-  skills?: string[];
+  skills?: UserSkill[];
   email: string;
   phone?: string;
   time_zone?: string;